@@ -0,0 +1,132 @@
+#![no_main]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ptr::NonNull;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use urcu::collections::hashmap::raw::{RawMap, RawNode};
+use urcu::prelude::*;
+
+#[derive(Arbitrary, Debug)]
+enum Operation {
+    Insert(u8, u16),
+    Remove(u8),
+    Lookup(u8),
+    Iterate,
+    Count,
+    RemoveIf(u8),
+}
+
+thread_local! {
+    static CONTEXT: RefCell<RcuDefaultContext<true, false>> = RefCell::new(
+        RcuDefaultFlavor::rcu_context_builder()
+            .with_read_context()
+            .register_thread()
+            .unwrap(),
+    );
+}
+
+fuzz_target!(|operations: Vec<Operation>| {
+    CONTEXT.with_borrow_mut(|context| run(context, operations));
+});
+
+fn value_of(node: *const RawNode<u8, u16>) -> Option<u16> {
+    // SAFETY: The node is valid for the duration of the RCU critical section.
+    unsafe { node.as_ref() }.map(|node| *node.as_refs().1)
+}
+
+fn run(context: &mut RcuDefaultContext<true, false>, operations: Vec<Operation>) {
+    let mut map = RawMap::<u8, u16, RcuDefaultFlavor>::new().unwrap();
+    let mut model = HashMap::<u8, u16>::new();
+    let mut retired = Vec::<NonNull<RawNode<u8, u16>>>::new();
+
+    let guard = context.rcu_read_lock();
+
+    for operation in operations {
+        match operation {
+            Operation::Insert(key, value) => {
+                // SAFETY: The RCU read-side lock is taken.
+                let old = unsafe { map.add_replace(key, value) };
+
+                assert_eq!(value_of(old), model.insert(key, value));
+                retired.extend(NonNull::new(old));
+            }
+            Operation::Remove(key) => {
+                // SAFETY: The RCU read-side lock is taken.
+                let mut iter = unsafe { map.lookup(&key) };
+
+                // SAFETY: The RCU read-side lock is taken.
+                let node = NonNull::new(iter.get())
+                    .and_then(|node| NonNull::new(unsafe { map.del(node) }));
+
+                assert_eq!(
+                    node.and_then(|node| value_of(node.as_ptr())),
+                    model.remove(&key)
+                );
+                retired.extend(node);
+            }
+            Operation::Lookup(key) => {
+                // SAFETY: The RCU read-side lock is taken.
+                let mut iter = unsafe { map.lookup(&key) };
+
+                assert_eq!(value_of(iter.get()), model.get(&key).copied());
+            }
+            Operation::Iterate => {
+                let mut visited = HashMap::new();
+
+                // SAFETY: The RCU read-side lock is taken.
+                unsafe {
+                    map.for_each(|key, value| {
+                        assert!(visited.insert(*key, *value).is_none());
+                    })
+                };
+
+                assert_eq!(visited, model);
+
+                // SAFETY: The RCU read-side lock is taken.
+                let mut iter = unsafe { map.iter() };
+                let mut count = 0;
+
+                while value_of(iter.get()).is_some() {
+                    count += 1;
+                    iter.next();
+                }
+
+                assert_eq!(count, model.len());
+            }
+            Operation::Count => {
+                // SAFETY: The RCU read-side lock is taken.
+                assert_eq!(unsafe { map.count() }, model.len() as u64);
+            }
+            Operation::RemoveIf(divisor) => {
+                let divisor = divisor.max(1);
+                let before = model.len();
+
+                // SAFETY: The RCU read-side lock is taken.
+                let nodes = unsafe { map.del_if(|key, _| key % divisor == 0) };
+                model.retain(|key, _| key % divisor != 0);
+
+                assert_eq!(nodes.len(), before - model.len());
+                retired.extend(nodes);
+            }
+        }
+    }
+
+    // SAFETY: The RCU read-side lock is taken.
+    retired.extend(unsafe { map.del_all() });
+
+    drop(guard);
+
+    context.rcu_synchronize();
+
+    for node in retired {
+        // SAFETY: An RCU syncronization barrier was called.
+        drop(unsafe { Box::from_raw(node.as_ptr()) });
+    }
+
+    // SAFETY: The read-side RCU lock is not taken.
+    // SAFETY: We are a registered RCU read-side thread.
+    unsafe { map.destroy() };
+}
@@ -0,0 +1,111 @@
+#![no_main]
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::ptr::NonNull;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use urcu::collections::list::raw::{RawIter, RawList, RawNode};
+use urcu::prelude::*;
+
+#[derive(Arbitrary, Debug)]
+enum Operation {
+    InsertBack(u16),
+    InsertFront(u16),
+    RemoveBack,
+    RemoveFront,
+    PeekBack,
+    PeekFront,
+    IterateForward,
+    IterateReverse,
+}
+
+thread_local! {
+    static CONTEXT: RefCell<RcuDefaultContext<true, false>> = RefCell::new(
+        RcuDefaultFlavor::rcu_context_builder()
+            .with_read_context()
+            .register_thread()
+            .unwrap(),
+    );
+}
+
+fuzz_target!(|operations: Vec<Operation>| {
+    CONTEXT.with_borrow_mut(|context| run(context, operations));
+});
+
+fn value_of(node: *const RawNode<u16>) -> Option<u16> {
+    // SAFETY: The node is valid for the duration of the RCU critical section.
+    unsafe { node.as_ref() }.map(|node| **node)
+}
+
+fn run(context: &mut RcuDefaultContext<true, false>, operations: Vec<Operation>) {
+    // SAFETY: The list is initialized once boxed and emptied before dropped.
+    let mut list = Box::new(unsafe { RawList::<u16>::new() });
+    unsafe { list.init() };
+
+    // The model is ordered like a forward iteration, starting from the back.
+    let mut model = VecDeque::<u16>::new();
+    let mut retired = Vec::<NonNull<RawNode<u16>>>::new();
+
+    let guard = context.rcu_read_lock();
+
+    for operation in operations {
+        // SAFETY: The fuzzer is the only writer.
+        // SAFETY: The RCU read-side lock is taken.
+        match operation {
+            Operation::InsertBack(value) => {
+                unsafe { list.insert_back(RawNode::new(value)) };
+                model.push_front(value);
+            }
+            Operation::InsertFront(value) => {
+                unsafe { list.insert_front(RawNode::new(value)) };
+                model.push_back(value);
+            }
+            Operation::RemoveBack => {
+                let node = unsafe { list.remove_back() };
+                assert_eq!(value_of(node), model.pop_front());
+                retired.extend(NonNull::new(node));
+            }
+            Operation::RemoveFront => {
+                let node = unsafe { list.remove_front() };
+                assert_eq!(value_of(node), model.pop_back());
+                retired.extend(NonNull::new(node));
+            }
+            Operation::PeekBack => {
+                assert_eq!(value_of(unsafe { list.get_back() }), model.front().copied());
+            }
+            Operation::PeekFront => {
+                assert_eq!(value_of(unsafe { list.get_front() }), model.back().copied());
+            }
+            Operation::IterateForward => {
+                let mut iter = unsafe { RawIter::from_back(&list) };
+                let values = std::iter::from_fn(|| value_of(unsafe { iter.next() }));
+
+                assert!(values.eq(model.iter().copied()));
+            }
+            Operation::IterateReverse => {
+                let mut iter = unsafe { RawIter::from_front(&list) };
+                let values = std::iter::from_fn(|| value_of(unsafe { iter.next() }));
+
+                assert!(values.eq(model.iter().rev().copied()));
+            }
+        }
+    }
+
+    // SAFETY: The fuzzer is the only writer.
+    while let Some(node) = NonNull::new(unsafe { list.remove_back() }) {
+        retired.push(node);
+    }
+
+    assert!(list.empty());
+
+    drop(guard);
+
+    context.rcu_synchronize();
+
+    for node in retired {
+        // SAFETY: An RCU syncronization barrier was called.
+        drop(unsafe { Box::from_raw(node.as_ptr()) });
+    }
+}
@@ -0,0 +1,130 @@
+#![no_main]
+
+use std::cell::RefCell;
+use std::ptr::NonNull;
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use urcu::collections::stack::raw::{RawNode, RawStack};
+use urcu::prelude::*;
+
+#[derive(Arbitrary, Debug)]
+enum Operation {
+    Push(bool, u16),
+    Pop(bool),
+    PopIf(bool, u8),
+    PopAll(bool),
+    Peek(bool),
+    Iterate(bool),
+    TakeInto(bool),
+}
+
+thread_local! {
+    static CONTEXT: RefCell<RcuDefaultContext<true, false>> = RefCell::new(
+        RcuDefaultFlavor::rcu_context_builder()
+            .with_read_context()
+            .register_thread()
+            .unwrap(),
+    );
+}
+
+fuzz_target!(|operations: Vec<Operation>| {
+    CONTEXT.with_borrow_mut(|context| run(context, operations));
+});
+
+fn value_of(node: *const RawNode<u16>) -> Option<u16> {
+    // SAFETY: The node is valid for the duration of the RCU critical section.
+    unsafe { node.as_ref() }.map(|node| **node)
+}
+
+fn run(context: &mut RcuDefaultContext<true, false>, operations: Vec<Operation>) {
+    // SAFETY: The stacks are emptied before dropped.
+    let stacks = unsafe { [RawStack::<u16>::new(), RawStack::<u16>::new()] };
+
+    // The models are ordered from the bottom to the top of the stacks.
+    let mut models = [Vec::<u16>::new(), Vec::<u16>::new()];
+    let mut retired = Vec::<NonNull<RawNode<u16>>>::new();
+
+    let guard = context.rcu_read_lock();
+
+    for operation in operations {
+        // SAFETY: The RCU read-side lock is taken.
+        match operation {
+            Operation::Push(which, value) => {
+                stacks[usize::from(which)].push(RawNode::new(value));
+                models[usize::from(which)].push(value);
+            }
+            Operation::Pop(which) => {
+                let node = unsafe { stacks[usize::from(which)].pop() };
+                assert_eq!(value_of(node), models[usize::from(which)].pop());
+                retired.extend(NonNull::new(node));
+            }
+            Operation::PopIf(which, divisor) => {
+                let divisor = u16::from(divisor.max(1));
+                let model = &mut models[usize::from(which)];
+
+                let node =
+                    unsafe { stacks[usize::from(which)].pop_if(|value| value % divisor == 0) };
+                let expected = model.pop_if(|value| *value % divisor == 0);
+
+                assert_eq!(value_of(node), expected);
+                retired.extend(NonNull::new(node));
+            }
+            Operation::PopAll(which) => {
+                let mut iter = unsafe { stacks[usize::from(which)].pop_all() };
+                let model = &mut models[usize::from(which)];
+
+                while let Some(node) = NonNull::new(unsafe { iter.next() }) {
+                    assert_eq!(value_of(node.as_ptr()), model.pop());
+                    retired.push(node);
+                }
+
+                assert!(model.is_empty());
+            }
+            Operation::Peek(which) => {
+                let node = unsafe { stacks[usize::from(which)].head() };
+                assert_eq!(value_of(node), models[usize::from(which)].last().copied());
+            }
+            Operation::Iterate(which) => {
+                let mut iter = unsafe { stacks[usize::from(which)].iter() };
+                let values = std::iter::from_fn(|| value_of(unsafe { iter.next() }));
+
+                assert!(values.eq(models[usize::from(which)].iter().rev().copied()));
+            }
+            Operation::TakeInto(which) => {
+                let (source, dest) = (usize::from(which), usize::from(!which));
+
+                let nodes = unsafe { stacks[source].pop_all() };
+
+                // SAFETY: There are no concurrent pops.
+                unsafe { stacks[dest].push_all(nodes) };
+
+                let moved = std::mem::take(&mut models[source]);
+                models[dest].extend(moved);
+            }
+        }
+
+        for (stack, model) in stacks.iter().zip(&models) {
+            assert_eq!(stack.empty(), model.is_empty());
+        }
+    }
+
+    for stack in &stacks {
+        // SAFETY: The RCU read-side lock is taken.
+        let mut iter = unsafe { stack.pop_all() };
+
+        // SAFETY: The RCU read-side lock is taken.
+        while let Some(node) = NonNull::new(unsafe { iter.next() }) {
+            retired.push(node);
+        }
+    }
+
+    drop(guard);
+
+    context.rcu_synchronize();
+
+    for node in retired {
+        // SAFETY: An RCU syncronization barrier was called.
+        drop(unsafe { Box::from_raw(node.as_ptr()) });
+    }
+}
@@ -27,6 +27,27 @@ fn configure_lto(config: &mut autotools::Config) {
     }
 }
 
+#[cfg(feature = "static")]
+fn configure_host(config: &mut autotools::Config) {
+    let (Ok(host), Ok(target)) = (std::env::var("HOST"), std::env::var("TARGET")) else {
+        return;
+    };
+
+    if host == target {
+        return;
+    }
+
+    // The Rust triples are not always known by `config.sub`.
+    let target = match target.split_once('-') {
+        Some(("riscv64gc", rest)) => format!("riscv64-{}", rest),
+        Some(("i586" | "i686", rest)) => format!("i686-{}", rest),
+        Some((arch, rest)) if arch.starts_with("armv7") => format!("arm-{}", rest),
+        _ => target,
+    };
+
+    config.config_option("host", Some(&target));
+}
+
 #[cfg(feature = "static")]
 fn main() {
     if std::env::var("DOCS_RS").is_ok() {
@@ -46,6 +67,7 @@ fn main() {
     let mut config = autotools::Config::new("vendor");
     configure_opt(&mut config);
     configure_lto(&mut config);
+    configure_host(&mut config);
     config.out_dir(out_dir).reconf("-ivf").build();
 }
 
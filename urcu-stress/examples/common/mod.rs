@@ -0,0 +1,52 @@
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use clap::Args;
+
+/// Defines the options shared by all stress tests.
+#[derive(Args, Debug)]
+pub struct StressArgs {
+    /// Number of publisher threads.
+    #[arg(short, long, default_value = "4")]
+    pub publishers: usize,
+
+    /// Number of consumer threads.
+    #[arg(short, long, default_value = "4")]
+    pub consumers: usize,
+
+    /// Number of reader threads.
+    #[arg(short, long, default_value = "2")]
+    pub readers: usize,
+
+    /// Duration of the test, zero waits for Ctrl+C.
+    #[arg(short, long, default_value = "5s", value_parser = humantime::parse_duration)]
+    pub duration: Duration,
+}
+
+pub struct ExitHandler(Receiver<()>);
+
+impl ExitHandler {
+    pub fn configure() -> Self {
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        ctrlc::set_handler(move || {
+            println!();
+            if tx.send(()).is_err() {
+                log::error!("failed to send Ctrl+C signal");
+            }
+        })
+        .expect("Error setting Ctrl-C handler");
+
+        Self(rx)
+    }
+
+    pub fn wait_for(&self, duration: Duration) {
+        if duration.is_zero() {
+            if self.0.recv().is_err() {
+                log::error!("Ctrl+C handler unexpectedly disconnected");
+            }
+        } else if let Err(RecvTimeoutError::Disconnected) = self.0.recv_timeout(duration) {
+            log::error!("Ctrl+C handler unexpectedly disconnected");
+        }
+    }
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use clap::Parser;
+use urcu_stress::{StressHashMap, StressTest};
+
+use crate::common::{ExitHandler, StressArgs};
+
+/// Run a RCU hashmap stress test using multiple threads.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    #[command(flatten)]
+    stress: StressArgs,
+
+    /// Number of distinct keys.
+    #[arg(short, long, default_value = "20000")]
+    keys: u64,
+}
+
+fn main() {
+    env_logger::init();
+
+    let args = Args::parse();
+    let exit_handler = ExitHandler::configure();
+
+    let report = StressTest::new(StressHashMap::<(u64, u64)>::new(args.keys).unwrap())
+        .with_publishers(args.stress.publishers)
+        .with_consumers(args.stress.consumers)
+        .with_readers(args.stress.readers)
+        .run_until(|| exit_handler.wait_for(args.stress.duration))
+        .unwrap();
+
+    println!("{report}");
+    report.check().unwrap();
+}
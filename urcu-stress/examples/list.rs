@@ -0,0 +1,41 @@
+mod common;
+
+use clap::Parser;
+use urcu::prelude::*;
+use urcu_stress::StressTest;
+
+use crate::common::{ExitHandler, StressArgs};
+
+/// Run a RCU list stress test using multiple threads.
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+struct Args {
+    #[command(flatten)]
+    stress: StressArgs,
+
+    /// Maximum number of elements in the list.
+    #[arg(long)]
+    capacity: Option<usize>,
+}
+
+fn main() {
+    env_logger::init();
+
+    let args = Args::parse();
+    let exit_handler = ExitHandler::configure();
+
+    let list = match args.capacity {
+        Some(capacity) => RcuList::<u32>::with_capacity(capacity),
+        None => RcuList::<u32>::new(),
+    };
+
+    let report = StressTest::new(list)
+        .with_publishers(args.stress.publishers)
+        .with_consumers(args.stress.consumers)
+        .with_readers(args.stress.readers)
+        .run_until(|| exit_handler.wait_for(args.stress.duration))
+        .unwrap();
+
+    println!("{report}");
+    report.check().unwrap();
+}
@@ -0,0 +1,11 @@
+#![doc = include_str!("../README.md")]
+
+mod payload;
+mod report;
+mod runner;
+mod target;
+
+pub use crate::payload::StressPayload;
+pub use crate::report::{StressReport, StressTally};
+pub use crate::runner::StressTest;
+pub use crate::target::{StressContext, StressHashMap, StressTarget};
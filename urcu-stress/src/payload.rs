@@ -0,0 +1,52 @@
+/// This trait defines a payload stored in the containers during a stress test.
+pub trait StressPayload: Send + Sync + 'static {
+    /// Creates the payload for an index.
+    fn generate(index: u64) -> Self;
+
+    /// Returns the value accumulated in the checksums.
+    fn checksum(&self) -> u128;
+
+    /// Returns `true` if the payload was not corrupted.
+    fn verify(&self) -> bool {
+        true
+    }
+}
+
+impl StressPayload for u32 {
+    fn generate(index: u64) -> Self {
+        (index % 1000) as u32
+    }
+
+    fn checksum(&self) -> u128 {
+        u128::from(*self)
+    }
+}
+
+impl StressPayload for u64 {
+    fn generate(index: u64) -> Self {
+        index
+    }
+
+    fn checksum(&self) -> u128 {
+        u128::from(*self)
+    }
+}
+
+/// The second value is derived from the first one to detect corruptions.
+impl StressPayload for (u64, u64) {
+    fn generate(index: u64) -> Self {
+        (index, derive(index))
+    }
+
+    fn checksum(&self) -> u128 {
+        u128::from(self.0)
+    }
+
+    fn verify(&self) -> bool {
+        self.1 == derive(self.0)
+    }
+}
+
+fn derive(value: u64) -> u64 {
+    value.wrapping_mul(value.wrapping_add(1337))
+}
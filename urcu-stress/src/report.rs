@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign};
+
+use anyhow::{ensure, Result};
+
+use crate::payload::StressPayload;
+
+/// Defines the amount and the checksum of a set of payloads.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StressTally {
+    /// The amount of payloads.
+    pub count: u128,
+    /// The sum of the payloads checksum.
+    pub sum: u128,
+    /// The amount of corrupted payloads.
+    pub corrupted: u128,
+}
+
+impl StressTally {
+    /// Accounts a payload.
+    pub fn record<P>(&mut self, payload: &P)
+    where
+        P: StressPayload,
+    {
+        self.count += 1;
+        self.sum += payload.checksum();
+
+        if !payload.verify() {
+            self.corrupted += 1;
+        }
+    }
+
+    /// Returns `true` if both tallies account the same payloads.
+    pub fn matches(&self, other: &Self) -> bool {
+        self.count == other.count && self.sum == other.sum
+    }
+}
+
+impl Add for StressTally {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+            corrupted: self.corrupted + other.corrupted,
+        }
+    }
+}
+
+impl AddAssign for StressTally {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Display for StressTally {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} nodes with a total sum of {}", self.count, self.sum)
+    }
+}
+
+/// Defines the result of a stress test.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StressReport {
+    /// The payloads published in the container.
+    pub published: StressTally,
+    /// The payloads displaced by the publishers (e.g. replaced or evicted).
+    pub displaced: StressTally,
+    /// The payloads removed by the consumers.
+    pub consumed: StressTally,
+    /// The payloads visited by the readers.
+    pub read: StressTally,
+}
+
+impl StressReport {
+    /// Verifies the invariants of the stress test.
+    ///
+    /// * No corrupted payloads were observed.
+    /// * Every published payload was either displaced or consumed exactly once.
+    pub fn check(&self) -> Result<()> {
+        let corrupted = self.corrupted();
+        ensure!(corrupted == 0, "observed {} corrupted payloads", corrupted);
+
+        let removed = self.displaced + self.consumed;
+        ensure!(
+            self.published.matches(&removed),
+            "published {} but removed {}",
+            self.published,
+            removed
+        );
+
+        Ok(())
+    }
+
+    /// Returns the amount of corrupted payloads seen by any thread.
+    pub fn corrupted(&self) -> u128 {
+        (self.published + self.displaced + self.consumed + self.read).corrupted
+    }
+}
+
+impl Display for StressReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "published {}", self.published)?;
+        writeln!(f, "displaced {}", self.displaced)?;
+        writeln!(f, "consumed {}", self.consumed)?;
+        writeln!(f, "read {}", self.read)?;
+        write!(f, "corrupted {} nodes", self.corrupted())
+    }
+}
@@ -0,0 +1,211 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::ScopedJoinHandle;
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use urcu::prelude::*;
+
+use crate::payload::StressPayload;
+use crate::report::{StressReport, StressTally};
+use crate::target::{StressContext, StressTarget};
+
+/// Defines a stress test on a RCU container.
+///
+/// Publishers insert payloads until the test ends, consumers remove payloads until
+/// the publishers are done and the container is empty, and readers traverse the
+/// container until the consumers are done.
+pub struct StressTest<T> {
+    target: Arc<T>,
+    publishers: usize,
+    consumers: usize,
+    readers: usize,
+    duration: Duration,
+}
+
+impl<T> StressTest<T>
+where
+    T: StressTarget,
+{
+    /// Creates a stress test with 4 publishers, 4 consumers and 2 readers for 5 seconds.
+    pub fn new(target: Arc<T>) -> Self {
+        Self {
+            target,
+            publishers: 4,
+            consumers: 4,
+            readers: 2,
+            duration: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the number of publisher threads.
+    pub fn with_publishers(mut self, publishers: usize) -> Self {
+        self.publishers = publishers;
+        self
+    }
+
+    /// Sets the number of consumer threads.
+    pub fn with_consumers(mut self, consumers: usize) -> Self {
+        self.consumers = consumers;
+        self
+    }
+
+    /// Sets the number of reader threads.
+    pub fn with_readers(mut self, readers: usize) -> Self {
+        self.readers = readers;
+        self
+    }
+
+    /// Sets the duration of the publication phase.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Runs the stress test for the configured duration.
+    pub fn run(self) -> Result<StressReport> {
+        let duration = self.duration;
+
+        self.run_until(|| std::thread::sleep(duration))
+    }
+
+    /// Runs the stress test until `wait` returns.
+    ///
+    /// The configured duration is ignored.
+    pub fn run_until<W>(self, wait: W) -> Result<StressReport>
+    where
+        W: FnOnce(),
+    {
+        let exit = AtomicBool::new(false);
+        let publishing = AtomicUsize::new(self.publishers);
+        let consuming = AtomicUsize::new(self.consumers);
+        let this = &self;
+
+        std::thread::scope(|scope| {
+            let publishers = (0..this.publishers)
+                .map(|offset| {
+                    let (exit, publishing) = (&exit, &publishing);
+                    scope.spawn(move || {
+                        let result = this.publish(offset as u64, exit);
+                        publishing.fetch_sub(1, Ordering::Release);
+                        result
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let consumers = (0..this.consumers)
+                .map(|offset| {
+                    let (publishing, consuming) = (&publishing, &consuming);
+                    scope.spawn(move || {
+                        let result = this.consume(offset as u64, publishing);
+                        consuming.fetch_sub(1, Ordering::Release);
+                        result
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let readers = (0..this.readers)
+                .map(|_| {
+                    let consuming = &consuming;
+                    scope.spawn(move || this.read(consuming))
+                })
+                .collect::<Vec<_>>();
+
+            wait();
+            exit.store(true, Ordering::Release);
+
+            let mut report = StressReport::default();
+
+            for (published, displaced) in Self::join(publishers)? {
+                report.published += published;
+                report.displaced += displaced;
+            }
+
+            for consumed in Self::join(consumers)? {
+                report.consumed += consumed;
+            }
+
+            for read in Self::join(readers)? {
+                report.read += read;
+            }
+
+            Ok(report)
+        })
+    }
+
+    fn join<R>(handles: Vec<ScopedJoinHandle<'_, Result<R>>>) -> Result<Vec<R>> {
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .map_err(|_| anyhow!("stress test thread panicked"))?
+            })
+            .collect()
+    }
+
+    fn context() -> Result<StressContext> {
+        Ok(RcuDefaultFlavor::rcu_context_builder()
+            .with_read_context()
+            .with_defer_context()
+            .register_thread()?)
+    }
+
+    fn publish(&self, offset: u64, exit: &AtomicBool) -> Result<(StressTally, StressTally)> {
+        let mut context = Self::context()?;
+        let mut published = StressTally::default();
+        let mut displaced = StressTally::default();
+        let mut index = offset;
+
+        while !exit.load(Ordering::Acquire) {
+            context.rcu_quiescent_state();
+
+            let payload = T::Payload::generate(index);
+            published.record(&payload);
+            displaced += self.target.publish(payload, index, &mut context)?;
+
+            index = index.wrapping_add(self.publishers as u64);
+        }
+
+        Ok((published, displaced))
+    }
+
+    fn consume(&self, offset: u64, publishing: &AtomicUsize) -> Result<StressTally> {
+        let mut context = Self::context()?;
+        let mut consumed = StressTally::default();
+        let mut index = offset;
+
+        loop {
+            context.rcu_quiescent_state();
+
+            let done = publishing.load(Ordering::Acquire) == 0;
+
+            match self.target.consume(index, &mut context)? {
+                Some(tally) => consumed += tally,
+                None if done && self.target.is_empty(&context) => break,
+                None => (),
+            }
+
+            index = index.wrapping_add(self.consumers as u64);
+        }
+
+        Ok(consumed)
+    }
+
+    fn read(&self, consuming: &AtomicUsize) -> Result<StressTally> {
+        let mut context = Self::context()?;
+        let mut read = StressTally::default();
+
+        loop {
+            context.rcu_quiescent_state();
+
+            if consuming.load(Ordering::Acquire) == 0 {
+                break;
+            }
+
+            read += self.target.read(&context);
+        }
+
+        Ok(read)
+    }
+}
@@ -0,0 +1,265 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use urcu::prelude::*;
+
+use crate::payload::StressPayload;
+use crate::report::StressTally;
+
+/// Defines the RCU context of the stress test threads.
+pub type StressContext = RcuDefaultContext<true, true>;
+
+/// This trait defines the operations executed on a container during a stress test.
+pub trait StressTarget: Send + Sync + 'static {
+    /// Defines the payload stored in the container.
+    type Payload: StressPayload;
+
+    /// Publishes a payload, returning the payloads displaced from the container.
+    fn publish(
+        &self,
+        payload: Self::Payload,
+        index: u64,
+        context: &mut StressContext,
+    ) -> Result<StressTally>;
+
+    /// Consumes a payload, if any.
+    ///
+    /// The index is a hint for containers where removals need a key.
+    fn consume(&self, index: u64, context: &mut StressContext) -> Result<Option<StressTally>>;
+
+    /// Visits the payloads of the container.
+    fn read(&self, context: &StressContext) -> StressTally;
+
+    /// Returns `true` if the container is empty.
+    ///
+    /// The default implementation relies on [`StressTarget::consume`] returning [`None`]
+    /// when the container is empty.
+    fn is_empty(&self, context: &StressContext) -> bool {
+        let _ = context;
+
+        true
+    }
+}
+
+/// Reclaims a reference with a cleanup strategy chosen by the index.
+fn reclaim<R>(reference: R, index: u64, context: &mut StressContext)
+where
+    R: RcuRef<RcuDefaultFlavor> + Send + 'static,
+{
+    match index % 3 {
+        0 => reference.safe_cleanup(),
+        1 => reference.call_cleanup(&*context),
+        _ => reference.defer_cleanup(context),
+    }
+}
+
+impl<P> StressTarget for RcuList<P>
+where
+    P: StressPayload,
+{
+    type Payload = P;
+
+    fn publish(&self, payload: P, index: u64, context: &mut StressContext) -> Result<StressTally> {
+        let mut tally = StressTally::default();
+
+        let evicted = if index % 2 == 1 {
+            self.push_front_bounded(payload)
+        } else {
+            self.push_back_bounded(payload)
+        };
+
+        if let Some(evicted) = evicted {
+            tally.record(&*evicted);
+            reclaim(evicted, index, context);
+        }
+
+        Ok(tally)
+    }
+
+    fn consume(&self, index: u64, context: &mut StressContext) -> Result<Option<StressTally>> {
+        let value = if index % 2 == 1 {
+            self.pop_front()
+        } else {
+            self.pop_back()
+        };
+
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        let mut tally = StressTally::default();
+        tally.record(&*value);
+        reclaim(value, index, context);
+
+        Ok(Some(tally))
+    }
+
+    fn read(&self, context: &StressContext) -> StressTally {
+        let guard = context.rcu_read_lock();
+
+        self.iter_forward(&guard)
+            .fold(StressTally::default(), |mut tally, value| {
+                tally.record(value);
+                tally
+            })
+    }
+
+    fn is_empty(&self, _context: &StressContext) -> bool {
+        RcuList::is_empty(self)
+    }
+}
+
+impl<P> StressTarget for RcuQueue<P>
+where
+    P: StressPayload,
+{
+    type Payload = P;
+
+    fn publish(&self, payload: P, _index: u64, context: &mut StressContext) -> Result<StressTally> {
+        let guard = context.rcu_read_lock();
+        self.push(payload, &guard);
+
+        Ok(StressTally::default())
+    }
+
+    fn consume(&self, index: u64, context: &mut StressContext) -> Result<Option<StressTally>> {
+        let guard = context.rcu_read_lock();
+        let value = self.pop(&guard);
+        drop(guard);
+
+        Ok(value.map(|value| {
+            let mut tally = StressTally::default();
+            tally.record(&*value);
+            reclaim(value, index, context);
+            tally
+        }))
+    }
+
+    fn read(&self, _context: &StressContext) -> StressTally {
+        // The queue cannot be traversed.
+        StressTally::default()
+    }
+}
+
+impl<P> StressTarget for RcuStack<P>
+where
+    P: StressPayload,
+{
+    type Payload = P;
+
+    fn publish(
+        &self,
+        payload: P,
+        _index: u64,
+        _context: &mut StressContext,
+    ) -> Result<StressTally> {
+        self.push(payload);
+
+        Ok(StressTally::default())
+    }
+
+    fn consume(&self, index: u64, context: &mut StressContext) -> Result<Option<StressTally>> {
+        let guard = context.rcu_read_lock();
+        let value = self.pop(&guard);
+        drop(guard);
+
+        Ok(value.map(|value| {
+            let mut tally = StressTally::default();
+            tally.record(&*value);
+            reclaim(value, index, context);
+            tally
+        }))
+    }
+
+    fn read(&self, context: &StressContext) -> StressTally {
+        let guard = context.rcu_read_lock();
+
+        self.iter(&guard)
+            .fold(StressTally::default(), |mut tally, value| {
+                tally.record(value);
+                tally
+            })
+    }
+
+    fn is_empty(&self, _context: &StressContext) -> bool {
+        RcuStack::is_empty(self)
+    }
+}
+
+/// Defines a [`RcuHashMap`] target where payloads are keyed by their index.
+pub struct StressHashMap<P>
+where
+    P: StressPayload,
+{
+    map: Arc<RcuHashMap<u64, P>>,
+    keys: u64,
+}
+
+impl<P> StressHashMap<P>
+where
+    P: StressPayload,
+{
+    /// Creates a target where the indexes are folded into `keys` keys.
+    pub fn new(keys: u64) -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            map: RcuHashMap::new()?,
+            keys: keys.max(1),
+        }))
+    }
+
+    /// Returns the hashmap.
+    pub fn map(&self) -> &Arc<RcuHashMap<u64, P>> {
+        &self.map
+    }
+}
+
+impl<P> StressTarget for StressHashMap<P>
+where
+    P: StressPayload,
+{
+    type Payload = P;
+
+    fn publish(&self, payload: P, index: u64, context: &mut StressContext) -> Result<StressTally> {
+        let guard = context.rcu_read_lock();
+        let replaced = self.map.insert(index % self.keys, payload, &guard);
+        drop(guard);
+
+        let mut tally = StressTally::default();
+        if let Some(replaced) = replaced {
+            tally.record(replaced.value());
+            reclaim(replaced, index, context);
+        }
+
+        Ok(tally)
+    }
+
+    fn consume(&self, index: u64, context: &mut StressContext) -> Result<Option<StressTally>> {
+        let guard = context.rcu_read_lock();
+        let value = self.map.remove(&(index % self.keys), &guard);
+        drop(guard);
+
+        Ok(value.map(|value| {
+            let mut tally = StressTally::default();
+            tally.record(value.value());
+            reclaim(value, index, context);
+            tally
+        }))
+    }
+
+    fn read(&self, context: &StressContext) -> StressTally {
+        let guard = context.rcu_read_lock();
+
+        self.map
+            .iter(&guard)
+            .fold(StressTally::default(), |mut tally, (_, value)| {
+                tally.record(value);
+                tally
+            })
+    }
+
+    fn is_empty(&self, context: &StressContext) -> bool {
+        let guard = context.rcu_read_lock();
+
+        self.map.iter(&guard).next().is_none()
+    }
+}
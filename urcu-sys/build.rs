@@ -23,7 +23,10 @@ fn main() {
         metadeps::probe().unwrap();
     }
 
-    build_config.cargo_link("urcu");
+    if std::env::var("CARGO_FEATURE_LINK").is_ok() {
+        build_config.cargo_link("urcu");
+    }
+
     build_config
         .default_bindgen()
         .header("src/header.h")
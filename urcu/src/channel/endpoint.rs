@@ -0,0 +1,249 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread::Thread;
+
+use crate::collections::wfqueue::container::Dequeue;
+use crate::collections::wfqueue::raw::{RawNode, RawQueue};
+use crate::utility::*;
+
+/// Defines the state shared by the endpoints of a channel.
+struct Shared<T> {
+    queue: RawQueue<T>,
+    senders: AtomicUsize,
+    connected: AtomicBool,
+    /// The receiver thread, if it is waiting for a message.
+    waiting: AtomicBool,
+    receiver: Mutex<Option<Thread>>,
+}
+
+impl<T> Shared<T> {
+    fn wake_receiver(&self) {
+        // Either the sender observes the waiting receiver, or the receiver observes the
+        // new message or the disconnection.
+        std::sync::atomic::fence(Ordering::SeqCst);
+
+        if self.waiting.load(Ordering::Relaxed) {
+            // The handle is replaced in a single store, so a poisoned lock still holds a valid one.
+            let receiver = self.receiver.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(thread) = receiver.as_ref() {
+                thread.unpark();
+            }
+        }
+    }
+}
+
+/// #### Safety
+///
+/// The shared state can be used to send `T` to another thread.
+unsafe impl<T: Send> Send for Shared<T> {}
+
+/// #### Safety
+///
+/// The shared state can be used to send `T` between threads through removals.
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+/// Creates a multi-producer single-consumer channel.
+///
+/// The channel is unbounded and backed by a wait-free concurrent queue. It can be used
+/// from threads that are not registered with RCU.
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let mut shared = Arc::new(Shared {
+        // SAFETY: Initialisation is properly called.
+        queue: unsafe { RawQueue::new() },
+        senders: AtomicUsize::new(1),
+        connected: AtomicBool::new(true),
+        waiting: AtomicBool::new(false),
+        receiver: Mutex::new(None),
+    });
+
+    // SAFETY: Initialisation occurs when raw queue is in a stable memory location.
+    unsafe { Arc::<Shared<T>>::get_mut(&mut shared).unwrap().queue.init() };
+
+    let sender = Sender {
+        shared: shared.clone(),
+    };
+
+    let receiver = Receiver {
+        shared,
+        _unsync: PhantomData,
+    };
+
+    (sender, receiver)
+}
+
+/// Defines the sending half of a channel.
+///
+/// The sender can be cloned to send messages from multiple threads.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends a message to the receiver.
+    ///
+    /// Returns the message if the receiver has been dropped.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn send(&self, data: T) -> Result<(), T>
+    where
+        T: Send,
+    {
+        if !self.shared.connected.load(Ordering::Acquire) {
+            return Err(data);
+        }
+
+        self.shared.queue.enqueue(RawNode::new(data));
+        self.shared.wake_receiver();
+
+        Ok(())
+    }
+
+    /// Returns `true` if the receiver has been dropped.
+    pub fn is_disconnected(&self) -> bool {
+        !self.shared.connected.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.senders.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        if self.shared.senders.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.shared.wake_receiver();
+        }
+    }
+}
+
+/// Defines the receiving half of a channel.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives a message, waiting until one is available.
+    ///
+    /// Returns `None` once all the senders have been dropped and the channel is empty.
+    ///
+    /// #### Note
+    ///
+    /// This operation blocks.
+    pub fn recv(&self) -> Option<T>
+    where
+        T: Send,
+    {
+        loop {
+            if let Some(data) = self.dequeue() {
+                return Some(data);
+            }
+
+            if self.shared.senders.load(Ordering::Acquire) == 0 {
+                // A message may have been sent just before the last sender was dropped.
+                return self.dequeue();
+            }
+
+            self.park();
+        }
+    }
+
+    /// Receives a message if one is available.
+    ///
+    /// Returns `None` if the channel is empty or if a concurrent sender has not finished
+    /// linking the first message.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn try_recv(&self) -> Option<T>
+    where
+        T: Send,
+    {
+        match self.shared.queue.dequeue_nonblocking() {
+            Dequeue::Item(node) => Some(node.into_data()),
+            Dequeue::Empty | Dequeue::Busy => None,
+        }
+    }
+
+    /// Receives all the available messages at once.
+    ///
+    /// The messages are moved out of the channel with a single splice operation.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn recv_all(&self) -> Vec<T>
+    where
+        T: Send,
+    {
+        // SAFETY: Initialisation is properly called.
+        let mut local = Box::new(unsafe { RawQueue::new() });
+
+        // SAFETY: Initialisation occurs when raw queue is in a stable memory location.
+        unsafe { local.init() };
+
+        local.splice_blocking(&self.shared.queue);
+
+        std::iter::from_fn(|| local.dequeue_blocking())
+            .map(|node| node.into_data())
+            .collect()
+    }
+
+    /// Returns `true` if all the senders have been dropped.
+    pub fn is_disconnected(&self) -> bool {
+        self.shared.senders.load(Ordering::Acquire) == 0
+    }
+
+    /// Returns `true` if there is no message in the channel.
+    pub fn is_empty(&self) -> bool {
+        self.shared.queue.empty()
+    }
+
+    fn dequeue(&self) -> Option<T> {
+        self.shared
+            .queue
+            .dequeue_blocking()
+            .map(|node| node.into_data())
+    }
+
+    fn park(&self) {
+        // The handle is replaced in a single store, so a poisoned lock still holds a valid one.
+        let mut receiver = self
+            .shared
+            .receiver
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *receiver = Some(std::thread::current());
+        drop(receiver);
+
+        self.shared.waiting.store(true, Ordering::Relaxed);
+        std::sync::atomic::fence(Ordering::SeqCst);
+
+        if self.shared.queue.empty() && self.shared.senders.load(Ordering::Relaxed) != 0 {
+            std::thread::park();
+        }
+
+        self.shared.waiting.store(false, Ordering::Relaxed);
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.connected.store(false, Ordering::Release);
+    }
+}
+
+/// #### Safety
+///
+/// A [`Receiver`] can be used to send `T` to another thread.
+unsafe impl<T: Send> Send for Receiver<T> {}
@@ -0,0 +1,39 @@
+//! Channel types.
+
+pub(crate) mod endpoint;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::channel::endpoint::{channel, Receiver, Sender};
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::channel::{Receiver, Sender};
+    use crate::utility::asserts::*;
+
+    mod sender {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(Sender<NotSendNotSync>: Send);
+        assert_not_impl_all!(Sender<NotSendNotSync>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(Sender<SendButNotSync>: Send);
+        assert_impl_all!(Sender<SendButNotSync>: Sync);
+    }
+
+    mod receiver {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(Receiver<NotSendNotSync>: Send);
+        assert_not_impl_all!(Receiver<NotSendNotSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(Receiver<SendAndSync>: Send);
+        assert_not_impl_all!(Receiver<SendAndSync>: Sync);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::channel::channel;
+
+#[test]
+fn simple() {
+    let (sender, receiver) = channel::<u32>();
+    assert!(receiver.is_empty());
+    assert_eq!(receiver.try_recv(), None);
+
+    sender.send(10).unwrap();
+    sender.send(20).unwrap();
+    sender.send(30).unwrap();
+
+    assert_eq!(receiver.try_recv(), Some(10));
+    assert_eq!(receiver.recv(), Some(20));
+    assert_eq!(receiver.recv_all(), vec![30]);
+    assert!(receiver.recv_all().is_empty());
+
+    drop(sender);
+    assert!(receiver.is_disconnected());
+    assert_eq!(receiver.recv(), None);
+}
+
+#[test]
+fn disconnected() {
+    let (sender, receiver) = channel::<u32>();
+
+    drop(receiver);
+    assert!(sender.is_disconnected());
+    assert_eq!(sender.send(10), Err(10));
+}
+
+#[test]
+fn producers() {
+    let (sender, receiver) = channel::<u32>();
+
+    std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let sender = sender.clone();
+
+            scope.spawn(move || {
+                for value in 0..100 {
+                    sender.send(thread * 100 + value).unwrap();
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut values = std::iter::from_fn(|| receiver.recv()).collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, (0..400).collect::<Vec<_>>());
+    });
+}
@@ -0,0 +1,175 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::atomic::{fence, AtomicUsize, Ordering};
+
+use crate::rcu::callback::RcuCallFn;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+
+struct RcuArcInner<T> {
+    strong: AtomicUsize,
+    data: T,
+}
+
+/// Defines the allocation of an [`RcuArc`] waiting to be reclaimed.
+struct RcuArcReclaim<T>(NonNull<RcuArcInner<T>>);
+
+impl<T> RcuArcReclaim<T> {
+    /// #### Safety
+    ///
+    /// There must not be any reader of the allocation.
+    unsafe fn reclaim(self) {
+        // SAFETY: The allocation was created by `RcuArc::new`.
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+/// #### Safety
+///
+/// The allocation is only dropped by the receiving thread if `T` is [`Send`].
+unsafe impl<T: Send> Send for RcuArcReclaim<T> {}
+
+/// Defines a reference-counted pointer with RCU-deferred destruction.
+///
+/// When the last [`RcuArc`] is dropped, the value is dropped after a RCU grace period
+/// instead of immediately. A reader inside a RCU critical section can therefore borrow
+/// the value with [`RcuArc::get`] for the whole critical section, without increasing
+/// the reference count.
+///
+/// #### Note
+///
+/// The destruction is handed off to the RCU cleanup thread of the flavor, which queues
+/// it with `call_rcu`. Dropping an [`RcuArc`] never blocks and does not require the
+/// thread to be registered with RCU.
+pub struct RcuArc<T, F = RcuDefaultFlavor>
+where
+    T: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    ptr: NonNull<RcuArcInner<T>>,
+    _flavor: PhantomData<(RcuArcInner<T>, F)>,
+}
+
+impl<T, F> RcuArc<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    /// Creates a new reference-counted value.
+    pub fn new(data: T) -> Self {
+        let inner = Box::new(RcuArcInner {
+            strong: AtomicUsize::new(1),
+            data,
+        });
+
+        Self {
+            // SAFETY: The pointer of a box is never null.
+            ptr: unsafe { NonNull::new_unchecked(Box::into_raw(inner)) },
+            _flavor: PhantomData,
+        }
+    }
+
+    fn inner(&self) -> &RcuArcInner<T> {
+        // SAFETY: The allocation is valid while there is a strong reference.
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// Returns a reference to the value for the duration of the RCU critical section.
+    ///
+    /// The reference remains valid even if every [`RcuArc`] is dropped before the end
+    /// of the critical section.
+    pub fn get<'guard, G>(&self, guard: &'guard G) -> &'guard T
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The allocation is reclaimed after a RCU grace period following the drop
+        // SAFETY: of the last strong reference, which happens after this borrow.
+        unsafe { &self.ptr.as_ref().data }
+    }
+
+    /// Returns the amount of strong references to the value.
+    pub fn strong_count(this: &Self) -> usize {
+        this.inner().strong.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if both pointers point to the same allocation.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+}
+
+impl<T, F> Clone for RcuArc<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    fn clone(&self) -> Self {
+        self.inner().strong.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            ptr: self.ptr,
+            _flavor: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Deref for RcuArc<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner().data
+    }
+}
+
+impl<T, F> Drop for RcuArc<T, F>
+where
+    T: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    fn drop(&mut self) {
+        if self.inner().strong.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+
+        // Every access of the other strong references happens before the reclamation.
+        fence(Ordering::Acquire);
+
+        let reclaim = RcuArcReclaim(self.ptr);
+
+        F::rcu_cleanup(Box::new(move |context| {
+            context.rcu_call(RcuCallFn::new(move || {
+                // SAFETY: The callback is executed after a RCU grace period.
+                unsafe { reclaim.reclaim() };
+            }));
+        }));
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuArc`] can be used to send and share `T` between threads.
+unsafe impl<T, F> Send for RcuArc<T, F>
+where
+    T: Send + Sync,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuArc`] can be used to send and share `T` between threads.
+unsafe impl<T, F> Sync for RcuArc<T, F>
+where
+    T: Send + Sync,
+    F: RcuFlavor,
+{
+}
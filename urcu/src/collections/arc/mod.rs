@@ -0,0 +1,24 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::arc::container::RcuArc;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    mod rcu_arc {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_not_impl_all!(RcuArc<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuArc<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuArc<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuArc<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+}
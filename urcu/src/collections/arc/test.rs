@@ -0,0 +1,47 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::collections::arc::container::RcuArc;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+
+struct DropFlag(Arc<AtomicBool>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+#[test]
+fn deferred_drop() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let dropped = Arc::new(AtomicBool::new(false));
+    let arc = RcuArc::<_>::new((4861, DropFlag(dropped.clone())));
+    let clone = arc.clone();
+
+    assert_eq!(RcuArc::strong_count(&arc), 2);
+    assert!(RcuArc::ptr_eq(&arc, &clone));
+    assert_eq!(clone.0, 4861);
+
+    let guard = context.rcu_read_lock();
+    let value = arc.get(&guard);
+    drop(arc);
+    drop(clone);
+
+    assert_eq!(value.0, 4861);
+    assert!(!dropped.load(Ordering::Acquire));
+    drop(guard);
+
+    let deadline = Instant::now() + Duration::from_secs(10);
+    while !dropped.load(Ordering::Acquire) {
+        assert!(Instant::now() < deadline, "value was never dropped");
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
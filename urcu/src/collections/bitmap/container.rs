@@ -0,0 +1,325 @@
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicPtr, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::rcu::callback::RcuCallFn;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::utility::*;
+
+/// The amount of words in a chunk.
+const CHUNK_WORDS: usize = 64;
+
+/// The amount of bits in a chunk.
+const CHUNK_BITS: usize = CHUNK_WORDS * u64::BITS as usize;
+
+type Chunk = [AtomicU64; CHUNK_WORDS];
+
+/// Defines the chunks of a bitmap, replaced as a whole when the bitmap grows.
+///
+/// The chunks are shared with the previous directories, so a bit updated through an old
+/// directory is never lost.
+struct Directory(Box<[NonNull<Chunk>]>);
+
+impl Directory {
+    fn bits(&self) -> usize {
+        self.0.len() * CHUNK_BITS
+    }
+
+    fn word(&self, index: usize) -> Option<&AtomicU64> {
+        let chunk = self.0.get(index / CHUNK_BITS)?;
+
+        // SAFETY: The chunks are never freed before the bitmap.
+        let chunk = unsafe { chunk.as_ref() };
+
+        Some(&chunk[(index % CHUNK_BITS) / u64::BITS as usize])
+    }
+}
+
+/// #### Safety
+///
+/// The directory only holds pointers to atomic words.
+unsafe impl Send for Directory {}
+
+/// Returns the mask of a bit in its word.
+fn mask(index: usize) -> u64 {
+    1 << (index % u64::BITS as usize)
+}
+
+/// Defines a RCU bitmap that can grow.
+///
+/// The bits are set and cleared atomically without locking. Growing the bitmap takes an
+/// internal lock and publishes a new array of chunks, the previous array being reclaimed
+/// after a RCU grace period. This is suited for identifier allocators.
+///
+/// # Limitations
+///
+/// ##### Consistency
+///
+/// Iterating or counting the bits reads the words one after the other. Bits updated
+/// concurrently may or may not be observed.
+///
+/// ##### Capacity
+///
+/// The capacity is rounded up to a multiple of 4096 bits and the bitmap never shrinks.
+pub struct RcuBitmap<F = RcuDefaultFlavor> {
+    directory: AtomicPtr<Directory>,
+    mutex: Mutex<()>,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<F> RcuBitmap<F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU bitmap with at least `bits` clear bits.
+    pub fn new(bits: usize) -> Arc<Self> {
+        let directory = Directory(Self::allocate(bits.div_ceil(CHUNK_BITS)).collect());
+
+        Arc::new(Self {
+            directory: AtomicPtr::new(Box::into_raw(Box::new(directory))),
+            mutex: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn allocate(chunks: usize) -> impl Iterator<Item = NonNull<Chunk>> {
+        (0..chunks).map(|_| {
+            let chunk = Box::new(std::array::from_fn(|_| AtomicU64::new(0)));
+
+            // SAFETY: The pointer of a box is never null.
+            unsafe { NonNull::new_unchecked(Box::into_raw(chunk)) }
+        })
+    }
+
+    fn directory<'me, 'guard, G>(&'me self, guard: &'guard G) -> &'guard Directory
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The underlying pointer is never null.
+        // SAFETY: The directory is reclaimed after a RCU grace period.
+        unsafe { self.directory.load(Ordering::Acquire).as_ref_unchecked() }
+    }
+
+    fn word<'me, 'guard, G>(&'me self, index: usize, guard: &'guard G) -> &'guard AtomicU64
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let directory = self.directory(guard);
+
+        match directory.word(index) {
+            Some(word) => word,
+            None => panic!(
+                "bit index {} is out of range (capacity is {})",
+                index,
+                directory.bits()
+            ),
+        }
+    }
+
+    /// Returns the amount of bits in the bitmap.
+    pub fn capacity<G>(&self, guard: &G) -> usize
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.directory(guard).bits()
+    }
+
+    /// Sets a bit, returning its previous state.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn set<G>(&self, index: usize, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.word(index, guard)
+            .fetch_or(mask(index), Ordering::AcqRel)
+            & mask(index)
+            != 0
+    }
+
+    /// Clears a bit, returning its previous state.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn clear<G>(&self, index: usize, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.word(index, guard)
+            .fetch_and(!mask(index), Ordering::AcqRel)
+            & mask(index)
+            != 0
+    }
+
+    /// Returns the state of a bit.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn get<G>(&self, index: usize, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.word(index, guard).load(Ordering::Acquire) & mask(index) != 0
+    }
+
+    /// Sets the first clear bit, returning its index.
+    ///
+    /// Returns `None` if every bit is set.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn set_first_clear<G>(&self, guard: &G) -> Option<usize>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let directory = self.directory(guard);
+
+        for base in (0..directory.bits()).step_by(u64::BITS as usize) {
+            let word = directory.word(base).unwrap();
+            let mut current = word.load(Ordering::Relaxed);
+
+            while current != u64::MAX {
+                let bit = current.trailing_ones() as usize;
+
+                match word.compare_exchange_weak(
+                    current,
+                    current | mask(bit),
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => return Some(base + bit),
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the amount of set bits.
+    pub fn count_ones<G>(&self, guard: &G) -> usize
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_words(guard)
+            .map(|(_, word)| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns an iterator over the indexes of the set bits.
+    ///
+    /// The iterator yields the indexes in ascending order.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> impl Iterator<Item = usize> + 'guard
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_words(guard).flat_map(|(base, mut word)| {
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    base + bit
+                })
+            })
+        })
+    }
+
+    fn iter_words<'me, 'guard, G>(
+        &'me self,
+        guard: &'guard G,
+    ) -> impl Iterator<Item = (usize, u64)> + 'guard
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let directory = self.directory(guard);
+
+        (0..directory.bits())
+            .step_by(u64::BITS as usize)
+            .map(|base| (base, directory.word(base).unwrap().load(Ordering::Acquire)))
+    }
+
+    /// Grows the bitmap to at least `bits` bits.
+    ///
+    /// The new bits are clear. The previous array of chunks is reclaimed by the RCU
+    /// cleanup thread.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn grow(&self, bits: usize)
+    where
+        F: 'static,
+    {
+        // The directory is swapped with a single store, so it is still consistent if a
+        // writer panics while holding the lock.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // SAFETY: The underlying pointer is never null.
+        // SAFETY: There is mutual exclusion between writers.
+        let current = unsafe { self.directory.load(Ordering::Relaxed).as_ref_unchecked() };
+
+        let chunks = bits.div_ceil(CHUNK_BITS);
+        if chunks <= current.0.len() {
+            return;
+        }
+
+        let directory = current
+            .0
+            .iter()
+            .copied()
+            .chain(Self::allocate(chunks - current.0.len()))
+            .collect();
+
+        let directory = Box::into_raw(Box::new(Directory(directory)));
+        let old = self.directory.swap(directory, Ordering::AcqRel);
+        drop(guard);
+
+        // SAFETY: The directory was allocated by the bitmap and is no longer reachable.
+        let old = unsafe { Box::from_raw(old) };
+
+        F::rcu_cleanup(Box::new(move |context| {
+            // The directory is dropped after a RCU grace period.
+            context.rcu_call(RcuCallFn::new(move || drop(old)));
+        }));
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuBitmap`] only holds atomic words.
+unsafe impl<F> Send for RcuBitmap<F> where F: RcuFlavor {}
+
+/// #### Safety
+///
+/// An [`RcuBitmap`] only holds atomic words.
+unsafe impl<F> Sync for RcuBitmap<F> where F: RcuFlavor {}
+
+impl<F> Drop for RcuBitmap<F> {
+    fn drop(&mut self) {
+        // SAFETY: The directory was allocated by the bitmap.
+        // SAFETY: The current directory holds every chunk ever allocated.
+        let directory = unsafe { Box::from_raw(*self.directory.get_mut()) };
+
+        for chunk in directory.0.iter() {
+            // SAFETY: The chunks were allocated by the bitmap.
+            drop(unsafe { Box::from_raw(chunk.as_ptr()) });
+        }
+    }
+}
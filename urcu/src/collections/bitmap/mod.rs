@@ -0,0 +1,14 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::assert_impl_all;
+
+    use crate::collections::bitmap::container::RcuBitmap;
+    use crate::rcu::default::RcuDefaultFlavor;
+
+    assert_impl_all!(RcuBitmap<RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuBitmap<RcuDefaultFlavor>: Sync);
+}
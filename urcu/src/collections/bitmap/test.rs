@@ -0,0 +1,55 @@
+use crate::collections::bitmap::container::RcuBitmap;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+
+#[test]
+fn grow() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let bitmap = RcuBitmap::<RcuDefaultFlavor>::new(100);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(bitmap.capacity(&guard), 4096);
+    assert!(!bitmap.set(3, &guard));
+    assert!(bitmap.set(3, &guard));
+    assert!(!bitmap.set(70, &guard));
+    assert!(bitmap.get(70, &guard));
+    assert_eq!(bitmap.iter(&guard).collect::<Vec<_>>(), vec![3, 70]);
+
+    // The bits set through the previous chunks are kept.
+    bitmap.grow(5000);
+    assert_eq!(bitmap.capacity(&guard), 4096);
+    drop(guard);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(bitmap.capacity(&guard), 8192);
+    assert!(!bitmap.set(5000, &guard));
+    assert!(bitmap.clear(3, &guard));
+    assert!(!bitmap.clear(3, &guard));
+    assert_eq!(bitmap.iter(&guard).collect::<Vec<_>>(), vec![70, 5000]);
+    assert_eq!(bitmap.count_ones(&guard), 2);
+}
+
+#[test]
+fn set_first_clear() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let bitmap = RcuBitmap::<RcuDefaultFlavor>::new(1);
+    let guard = context.rcu_read_lock();
+
+    for index in 0..4096 {
+        assert_eq!(bitmap.set_first_clear(&guard), Some(index));
+    }
+
+    assert_eq!(bitmap.set_first_clear(&guard), None);
+
+    bitmap.clear(1234, &guard);
+    assert_eq!(bitmap.set_first_clear(&guard), Some(1234));
+}
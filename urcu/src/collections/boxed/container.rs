@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::atomic::{AtomicPtr, AtomicU64, Ordering};
 use std::sync::Arc;
 
 use crate::collections::boxed::reference::Ref;
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
 use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
 use crate::utility::{PhantomUnsend, PhantomUnsync};
 
 /// Defines a RCU-enabled [`Box`].
@@ -21,6 +22,19 @@ use crate::utility::{PhantomUnsend, PhantomUnsync};
 ///
 /// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
 ///
+/// ##### Initialization
+///
+/// A box always holds data. Use [`RcuOnceCell`] for data that is lazily initialized.
+///
+/// [`RcuOnceCell`]: crate::collections::cell::once::RcuOnceCell
+///
+/// ##### Versions
+///
+/// Each call to [`RcuBox::replace`] bumps a version counter. Because the counter is
+/// updated after the data is published, a reader might observe a newer data than the
+/// version returned by [`RcuBox::read_versioned`]. In that case, [`RcuBox::has_changed`]
+/// reports a change even if the reader already has the latest data.
+///
 /// # Safety
 ///
 /// It is safe to send an `Arc<RcuBox<T>>` to a non-registered RCU thread. A non-registered
@@ -28,6 +42,8 @@ use crate::utility::{PhantomUnsend, PhantomUnsync};
 /// prevent any other thread from accessing a RCU reference.
 pub struct RcuBox<T, F = RcuDefaultFlavor> {
     ptr: AtomicPtr<T>,
+    version: AtomicU64,
+    refs: RcuRefTracker,
     _unsend: PhantomUnsend<F>,
     _unsync: PhantomUnsync<F>,
 }
@@ -40,6 +56,8 @@ where
     pub fn new(data: T) -> Arc<Self> {
         Arc::new(Self {
             ptr: AtomicPtr::new(Box::into_raw(Box::new(data))),
+            version: AtomicU64::new(0),
+            refs: RcuRefTracker::new::<F>("RcuBox"),
             _unsend: PhantomData,
             _unsync: PhantomData,
         })
@@ -57,6 +75,25 @@ where
         unsafe { self.ptr.load(Ordering::Acquire).as_ref_unchecked() }
     }
 
+    /// Returns the current version and an immutable reference to the data.
+    ///
+    /// The data is always at least as recent as the returned version.
+    pub fn read_versioned<'me, 'guard, G>(&'me self, guard: &'guard G) -> (u64, &'guard T)
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // The version must be loaded before the data.
+        let version = self.version.load(Ordering::Acquire);
+
+        (version, self.get(guard))
+    }
+
+    /// Returns `true` if the data was replaced since the given version.
+    pub fn has_changed(&self, since: u64) -> bool {
+        self.version.load(Ordering::Acquire) != since
+    }
+
     /// Replaces the underlying data atomically.
     pub fn replace(&self, data: T) -> Ref<T, F>
     where
@@ -64,7 +101,11 @@ where
     {
         let new_ptr = Box::into_raw(Box::new(data));
         let old_ptr = self.ptr.swap(new_ptr, Ordering::Release);
-        Ref::new(old_ptr)
+
+        // The version must be bumped after the data is published.
+        self.version.fetch_add(1, Ordering::Release);
+
+        Ref::new(old_ptr).tracked(&self.refs)
     }
 }
 
@@ -80,6 +121,8 @@ unsafe impl<T, F> Sync for RcuBox<T, F> where T: Sync {}
 
 impl<T, F> Drop for RcuBox<T, F> {
     fn drop(&mut self) {
+        self.refs.report();
+
         // SAFETY: The underlying pointer is never null.
         unsafe {
             let _ = Box::from_raw(self.ptr.load(Ordering::Relaxed));
@@ -1,5 +1,9 @@
 pub(crate) mod container;
 pub(crate) mod reference;
+pub(crate) mod string;
+
+#[cfg(test)]
+mod test;
 
 pub use crate::collections::boxed::reference::*;
 
@@ -32,6 +36,15 @@ mod asserts {
         assert_impl_all!(RcuBox<SendAndSync, RcuDefaultFlavor>: Sync);
     }
 
+    mod rcu_str {
+        use super::*;
+
+        use crate::collections::boxed::string::RcuStr;
+
+        assert_impl_all!(RcuStr<RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuStr<RcuDefaultFlavor>: Sync);
+    }
+
     mod rcu_box_ref {
         use super::*;
 
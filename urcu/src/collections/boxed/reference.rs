@@ -2,9 +2,13 @@ use std::marker::PhantomData;
 use std::ops::Deref;
 
 use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::metrics::RcuRemoval;
 use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::{RcuRefTrack, RcuRefTracker};
 
-/// A RCU reference to a element removed from a [`RcuBox`].
+/// A RCU reference to a element removed from a [`RcuBox`], a [`RcuCell`], an intrusive
+/// container, a [`RcuPerCpu`], a [`RcuProtected`], a [`RcuRingBuffer`], a [`RcuSlab`],
+/// a [`RcuStr`] or a [`RcuVec`].
 ///
 /// #### Note
 ///
@@ -16,12 +20,21 @@ use crate::rcu::reference::RcuRef;
 /// `T` must be [`Send`] because [`Drop::drop`] might execute cleanup in another thread.
 ///
 /// [`RcuBox`]: crate::collections::boxed::container::RcuBox
+/// [`RcuCell`]: crate::collections::cell::container::RcuCell
+/// [`RcuPerCpu`]: crate::collections::percpu::container::RcuPerCpu
+/// [`RcuProtected`]: crate::collections::protected::container::RcuProtected
+/// [`RcuRingBuffer`]: crate::collections::ring::container::RcuRingBuffer
+/// [`RcuSlab`]: crate::collections::slab::container::RcuSlab
+/// [`RcuStr`]: crate::collections::boxed::string::RcuStr
+/// [`RcuVec`]: crate::collections::vec::container::RcuVec
 pub struct Ref<T, F>
 where
     T: Send + 'static,
     F: RcuFlavor + 'static,
 {
     ptr: *mut T,
+    removal: RcuRemoval,
+    track: RcuRefTrack,
     context: PhantomData<F>,
 }
 
@@ -33,9 +46,17 @@ where
     pub fn new(ptr: *mut T) -> Self {
         Self {
             ptr,
+            removal: RcuRemoval::now(),
+            track: RcuRefTrack::default(),
             context: PhantomData,
         }
     }
+
+    /// Accounts the reference in the tracker of the container that produced it.
+    pub(crate) fn tracked(mut self, tracker: &RcuRefTracker) -> Self {
+        self.track = tracker.track();
+        self
+    }
 }
 
 /// #### Safety
@@ -54,6 +75,8 @@ where
 
         // SAFETY: We don't want to cleanup when dropping `self`.
         self.ptr = std::ptr::null_mut();
+        self.removal.reclaimed();
+        self.track.release();
 
         output
     }
@@ -78,6 +101,8 @@ where
         if !self.ptr.is_null() {
             Self {
                 ptr: self.ptr,
+                removal: self.removal,
+                track: std::mem::take(&mut self.track),
                 context: PhantomData,
             }
             .safe_cleanup();
@@ -0,0 +1,102 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Arc;
+
+use crate::collections::boxed::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::{PhantomUnsend, PhantomUnsync};
+
+/// Defines a RCU-enabled string slot.
+///
+/// The string is published as an immutable [`Box<str>`] snapshot. Readers borrow the
+/// current snapshot as a [`str`] for the lifetime of their guard while writers publish
+/// new snapshots.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuStr>` to a non-registered RCU thread. A non-registered
+/// thread may drop an `RcuStr` without calling any RCU primitives since lifetime rules
+/// prevent any other thread from accessing a RCU reference.
+pub struct RcuStr<F = RcuDefaultFlavor> {
+    ptr: AtomicPtr<Box<str>>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<F> RcuStr<F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU string.
+    pub fn new<S>(value: S) -> Arc<Self>
+    where
+        S: Into<Box<str>>,
+    {
+        Arc::new(Self {
+            ptr: AtomicPtr::new(Box::into_raw(Box::new(value.into()))),
+            refs: RcuRefTracker::new::<F>("RcuStr"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    /// Returns the current string.
+    pub fn read<'me, 'guard, G>(&'me self, guard: &'guard G) -> &'guard str
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The underlying pointer is never null.
+        unsafe { self.ptr.load(Ordering::Acquire).as_ref_unchecked() }
+    }
+
+    /// Replaces the string atomically, returning the previous one.
+    pub fn swap<S>(&self, value: S) -> Ref<Box<str>, F>
+    where
+        S: Into<Box<str>>,
+    {
+        let new_ptr = Box::into_raw(Box::new(value.into()));
+        let old_ptr = self.ptr.swap(new_ptr, Ordering::AcqRel);
+
+        Ref::new(old_ptr).tracked(&self.refs)
+    }
+
+    /// Replaces the string atomically.
+    ///
+    /// #### Note
+    ///
+    /// The previous string is freed in the RCU cleanup thread after a grace period.
+    pub fn set<S>(&self, value: S)
+    where
+        S: Into<Box<str>>,
+        F: 'static,
+    {
+        self.swap(value).safe_cleanup();
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuStr`] only holds a [`Box<str>`], which can be sent to another thread.
+unsafe impl<F> Send for RcuStr<F> {}
+
+/// #### Safety
+///
+/// An [`RcuStr`] only holds a [`Box<str>`], which can be shared between threads.
+unsafe impl<F> Sync for RcuStr<F> {}
+
+impl<F> Drop for RcuStr<F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The underlying pointer is never null.
+        drop(unsafe { Box::from_raw(self.ptr.load(Ordering::Relaxed)) });
+    }
+}
@@ -0,0 +1,53 @@
+use crate::collections::boxed::container::RcuBox;
+use crate::collections::boxed::string::RcuStr;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn read_versioned() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let rcu_box = RcuBox::<u32>::new(4127);
+    let guard = context.rcu_read_lock();
+
+    let (version, value) = rcu_box.read_versioned(&guard);
+    assert_eq!(version, 0);
+    assert_eq!(value, &4127);
+    assert!(!rcu_box.has_changed(version));
+
+    rcu_box.replace(8053).call_cleanup(&context);
+    assert!(rcu_box.has_changed(version));
+
+    let (version, value) = rcu_box.read_versioned(&guard);
+    assert_eq!(version, 1);
+    assert_eq!(value, &8053);
+    assert!(!rcu_box.has_changed(version));
+}
+
+#[test]
+fn rcu_str() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let string = RcuStr::<RcuDefaultFlavor>::new("alpha");
+    let guard = context.rcu_read_lock();
+    let before = string.read(&guard);
+    assert_eq!(before, "alpha");
+
+    let old = string.swap(String::from("beta"));
+    assert_eq!(before, "alpha");
+    assert_eq!(string.read(&guard), "beta");
+
+    string.set("gamma");
+    assert_eq!(string.read(&guard), "gamma");
+    drop(guard);
+
+    assert_eq!(&**old.take_ownership(&mut context), "alpha");
+}
@@ -0,0 +1,182 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Arc;
+
+use crate::collections::boxed::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::{PhantomUnsend, PhantomUnsync};
+
+/// Defines a RCU-protected slot holding an optional value.
+///
+/// Unlike [`RcuBox`], the slot may be empty at any time. Writers publish a new value
+/// or clear the slot atomically and readers always observe a complete value.
+///
+/// # Limitations
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing the value, it is impossible to get
+/// a mutable references to the value inside the cell. You should design the type stored
+/// in the cell with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuCell<T>>` to a non-registered RCU thread. A non-registered
+/// thread may drop an `RcuCell<T>` without calling any RCU primitives since lifetime rules
+/// prevent any other thread from accessing a RCU reference.
+///
+/// [`RcuBox`]: crate::collections::boxed::container::RcuBox
+pub struct RcuCell<T, F = RcuDefaultFlavor> {
+    ptr: AtomicPtr<T>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuCell<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU cell holding a value.
+    pub fn new(data: T) -> Arc<Self> {
+        Self::with_ptr(Self::into_ptr(Some(data)))
+    }
+
+    /// Creates a new empty RCU cell.
+    pub fn empty() -> Arc<Self> {
+        Self::with_ptr(std::ptr::null_mut())
+    }
+
+    fn with_ptr(ptr: *mut T) -> Arc<Self> {
+        Arc::new(Self {
+            ptr: AtomicPtr::new(ptr),
+            refs: RcuRefTracker::new::<F>("RcuCell"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn into_ptr(data: Option<T>) -> *mut T {
+        data.map(|data| Box::into_raw(Box::new(data)))
+            .unwrap_or(std::ptr::null_mut())
+    }
+
+    fn removed(&self, ptr: *mut T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+    }
+
+    /// Returns an immutable reference to the value, or `None` if the cell is empty.
+    pub fn load<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The underlying pointer is either null or valid.
+        unsafe { self.ptr.load(Ordering::Acquire).as_ref() }
+    }
+
+    /// Returns `true` if the cell holds no value.
+    pub fn is_empty(&self) -> bool {
+        self.ptr.load(Ordering::Acquire).is_null()
+    }
+
+    /// Replaces the value of the cell.
+    ///
+    /// The replaced value is cleaned up after a RCU grace period.
+    pub fn store(&self, data: Option<T>)
+    where
+        T: Send + 'static,
+        F: 'static,
+    {
+        self.swap(data).safe_cleanup();
+    }
+
+    /// Replaces the value of the cell, returning the replaced value.
+    pub fn swap(&self, data: Option<T>) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        let old_ptr = self.ptr.swap(Self::into_ptr(data), Ordering::AcqRel);
+
+        self.removed(old_ptr)
+    }
+
+    /// Removes the value of the cell, returning it.
+    pub fn take(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.swap(None)
+    }
+
+    /// Replaces the value of the cell if it is still `current`.
+    ///
+    /// The values are compared by address, so `current` must be obtained from this
+    /// cell with [`RcuCell::load`]. On success, the replaced value is returned. On failure,
+    /// the new value is given back.
+    pub fn compare_exchange<'me, 'guard, G>(
+        &'me self,
+        current: Option<&'guard T>,
+        data: Option<T>,
+        guard: &'guard G,
+    ) -> Result<Option<Ref<T, F>>, Option<T>>
+    where
+        'me: 'guard,
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        let current_ptr = current
+            .map(|current| current as *const T as *mut T)
+            .unwrap_or(std::ptr::null_mut());
+        let new_ptr = Self::into_ptr(data);
+
+        // The guard prevents `current` from being reclaimed, so its address cannot
+        // be reused by another value.
+        match self
+            .ptr
+            .compare_exchange(current_ptr, new_ptr, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(old_ptr) => Ok(self.removed(old_ptr)),
+            Err(_) => Err(
+                // SAFETY: The pointer was never published.
+                (!new_ptr.is_null()).then(|| *unsafe { Box::from_raw(new_ptr) }),
+            ),
+        }
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuCell`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuCell<T, F> where T: Send {}
+
+/// #### Safety
+///
+/// An [`RcuCell`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuCell<T, F> where T: Sync {}
+
+impl<T, F> Drop for RcuCell<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        let ptr = self.ptr.load(Ordering::Relaxed);
+
+        // SAFETY: The underlying pointer is either null or valid.
+        if !ptr.is_null() {
+            drop(unsafe { Box::from_raw(ptr) });
+        }
+    }
+}
@@ -0,0 +1,54 @@
+pub(crate) mod container;
+pub(crate) mod once;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::cell::container::RcuCell;
+    use crate::collections::cell::once::RcuOnceCell;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    mod rcu_cell {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuCell<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuCell<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuCell<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuCell<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuCell<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuCell<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuCell<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuCell<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_once_cell {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuOnceCell<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuOnceCell<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuOnceCell<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuOnceCell<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuOnceCell<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuOnceCell<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuOnceCell<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuOnceCell<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+}
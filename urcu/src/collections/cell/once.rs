@@ -0,0 +1,132 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::utility::{PhantomUnsend, PhantomUnsync};
+
+/// Defines a RCU cell initialized at most once.
+///
+/// The value can be initialized from any thread with [`RcuOnceCell::get_or_init`]. Once
+/// published, it is never replaced, so reading it never takes a lock.
+///
+/// #### Note
+///
+/// Many threads may initialize the cell concurrently, but only one of the values is
+/// published. The other values are dropped before returning.
+///
+/// # Safety
+///
+/// It is safe to send an `RcuOnceCell<T>` to a non-registered RCU thread. A non-registered
+/// thread may drop an `RcuOnceCell<T>` without calling any RCU primitives since lifetime
+/// rules prevent any other thread from accessing a RCU reference.
+pub struct RcuOnceCell<T, F = RcuDefaultFlavor> {
+    ptr: AtomicPtr<T>,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuOnceCell<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new uninitialized cell.
+    pub const fn new() -> Self {
+        Self {
+            ptr: AtomicPtr::new(std::ptr::null_mut()),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value, or `None` if the cell is not initialized.
+    pub fn get<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The underlying pointer is either null or valid until the cell is dropped.
+        unsafe { self.ptr.load(Ordering::Acquire).as_ref() }
+    }
+
+    /// Returns a reference to the value, initializing it with `init` if the cell is
+    /// not initialized.
+    pub fn get_or_init<'me, 'guard, G, I>(&'me self, init: I, guard: &'guard G) -> &'guard T
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+        I: FnOnce() -> T,
+    {
+        if let Some(data) = self.get(guard) {
+            return data;
+        }
+
+        match self.publish(init()) {
+            // SAFETY: The pointer is never null once the cell is initialized.
+            Ok(ptr) | Err((ptr, _)) => unsafe { ptr.as_ref_unchecked() },
+        }
+    }
+
+    /// Initializes the cell with a value.
+    ///
+    /// If the cell is already initialized, the value is given back.
+    pub fn set(&self, data: T) -> Result<(), T> {
+        self.publish(data).map(|_| ()).map_err(|(_, data)| data)
+    }
+
+    /// Returns `true` if the cell is initialized.
+    pub fn is_initialized(&self) -> bool {
+        !self.ptr.load(Ordering::Acquire).is_null()
+    }
+
+    /// Publishes a value, returning the published pointer.
+    ///
+    /// If the cell is already initialized, the value is given back with the current pointer.
+    fn publish(&self, data: T) -> Result<*mut T, (*mut T, T)> {
+        let new_ptr = Box::into_raw(Box::new(data));
+
+        match self.ptr.compare_exchange(
+            std::ptr::null_mut(),
+            new_ptr,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => Ok(new_ptr),
+            // SAFETY: The pointer was never published.
+            Err(current_ptr) => Err((current_ptr, *unsafe { Box::from_raw(new_ptr) })),
+        }
+    }
+}
+
+impl<T, F> Default for RcuOnceCell<T, F>
+where
+    F: RcuFlavor,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuOnceCell`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuOnceCell<T, F> where T: Send {}
+
+/// #### Safety
+///
+/// An [`RcuOnceCell`] can be used to send and share `T` between threads.
+unsafe impl<T, F> Sync for RcuOnceCell<T, F> where T: Send + Sync {}
+
+impl<T, F> Drop for RcuOnceCell<T, F> {
+    fn drop(&mut self) {
+        let ptr = self.ptr.load(Ordering::Relaxed);
+
+        // SAFETY: The underlying pointer is either null or valid.
+        if !ptr.is_null() {
+            drop(unsafe { Box::from_raw(ptr) });
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::collections::cell::container::RcuCell;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn simple() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let cell = RcuCell::<u32>::empty();
+    let guard = context.rcu_read_lock();
+
+    assert!(cell.is_empty());
+    assert_eq!(cell.load(&guard), None);
+    assert!(cell.swap(Some(5821)).is_none());
+    assert_eq!(cell.load(&guard), Some(&5821));
+
+    cell.store(Some(3390));
+    assert_eq!(cell.load(&guard), Some(&3390));
+
+    let taken = cell.take().unwrap();
+    assert!(cell.is_empty());
+    assert!(cell.take().is_none());
+    drop(guard);
+
+    assert_eq!(*taken.take_ownership(&mut context), 3390);
+}
+
+#[test]
+fn compare_exchange() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let cell = RcuCell::<u32>::new(7012);
+    let guard = context.rcu_read_lock();
+
+    let current = cell.load(&guard);
+    assert_eq!(
+        cell.compare_exchange(None, Some(1), &guard).err(),
+        Some(Some(1))
+    );
+
+    let replaced = cell.compare_exchange(current, Some(2264), &guard).unwrap();
+    assert_eq!(cell.load(&guard), Some(&2264));
+    assert_eq!(
+        cell.compare_exchange(current, None, &guard).err(),
+        Some(None)
+    );
+    drop(guard);
+
+    assert_eq!(*replaced.unwrap().take_ownership(&mut context), 7012);
+}
+
+#[test]
+fn once() {
+    use crate::collections::cell::once::RcuOnceCell;
+
+    static CELL: RcuOnceCell<u32> = RcuOnceCell::new();
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    assert!(!CELL.is_initialized());
+    assert_eq!(CELL.get(&guard), None);
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| CELL.set(4410));
+    });
+
+    assert_eq!(CELL.set(9173), Err(9173));
+    assert_eq!(CELL.get_or_init(|| 9173, &guard), &4410);
+    assert_eq!(CELL.get(&guard), Some(&4410));
+    assert!(CELL.is_initialized());
+}
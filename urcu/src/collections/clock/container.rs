@@ -0,0 +1,302 @@
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use anyhow::Result;
+
+use crate::collections::hashmap::builder::RcuHashMapBuilder;
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::hashmap::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::RcuGuard;
+
+/// Defines a value stored in an [`RcuClockCache`].
+pub struct ClockEntry<V> {
+    value: V,
+    id: u64,
+    referenced: AtomicBool,
+}
+
+impl<V> ClockEntry<V> {
+    fn new(value: V, id: u64) -> Self {
+        Self {
+            value,
+            id,
+            referenced: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    fn touch(&self) {
+        // Avoid bouncing the cache line between readers of a popular entry.
+        if !self.referenced.load(Ordering::Relaxed) {
+            self.referenced.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+impl<V> Deref for ClockEntry<V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Defines the state of the writers of an [`RcuClockCache`].
+struct ClockState<K> {
+    /// The keys in insertion order, with the identifier of their entry.
+    ///
+    /// Replaced and removed keys stay queued until they are visited or compacted.
+    order: VecDeque<(K, u64)>,
+    next_id: u64,
+    len: usize,
+}
+
+/// Defines a RCU bounded cache with CLOCK eviction.
+///
+/// Lookups are lock-free and only mark the entry as recently used. Insertions and
+/// removals take an internal lock. When the cache is full, an insertion evicts an entry
+/// that was not used recently.
+///
+/// # Limitations
+///
+/// ##### Eviction
+///
+/// The cache does not track the exact least recently used entry, which would require
+/// every lookup to move its entry under the lock. Entries are visited in insertion order
+/// and an entry used since the last visit gets a second chance.
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a value, it is impossible to get a
+/// mutable references to the values inside the cache. You should design the type stored
+/// in the cache with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuClockCache<K, V>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuClockCache<K, V>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing an
+/// RCU reference.
+pub struct RcuClockCache<K, V, F = RcuDefaultFlavor>
+where
+    K: Send + 'static,
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    map: RcuHashMap<K, ClockEntry<V>, F>,
+    state: Mutex<ClockState<K>>,
+    capacity: usize,
+}
+
+impl<K, V, F> RcuClockCache<K, V, F>
+where
+    K: Send,
+    V: Send,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU cache holding at most `capacity` entries.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Result<Arc<Self>> {
+        assert!(capacity > 0, "capacity of the cache must be non-zero");
+
+        Ok(Arc::new(Self {
+            map: RcuHashMap::create(RcuHashMapBuilder::new())?,
+            state: Mutex::new(ClockState {
+                order: VecDeque::with_capacity(capacity),
+                next_id: 0,
+                len: 0,
+            }),
+            capacity,
+        }))
+    }
+
+    /// Returns the maximum amount of entries in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The entry is marked as recently used.
+    pub fn get<'me, 'guard, G>(&'me self, key: &K, guard: &'guard G) -> Option<&'guard V>
+    where
+        'me: 'guard,
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.get(key, guard).map(|entry| {
+            entry.touch();
+            entry.value()
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// Unlike [`RcuClockCache::get`], the entry is not marked as recently used.
+    pub fn peek<'me, 'guard, G>(&'me self, key: &K, guard: &'guard G) -> Option<&'guard V>
+    where
+        'me: 'guard,
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.get(key, guard).map(ClockEntry::value)
+    }
+
+    /// Returns `true` if the cache contains a value for the specified key.
+    pub fn contains<G>(&self, key: &K, guard: &G) -> bool
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.contains(key, guard)
+    }
+
+    /// Inserts a key-value pair in the cache.
+    ///
+    /// The replaced entry and the entries evicted to make room are returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn insert<G>(&self, key: K, value: V, guard: &G) -> Vec<Ref<K, ClockEntry<V>, F>>
+    where
+        K: Clone + Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.with_state(|state| {
+            let mut refs = Vec::new();
+
+            if !self.map.contains(&key, guard) {
+                while state.len >= self.capacity {
+                    match self.evict(state, guard) {
+                        Some(evicted) => refs.push(evicted),
+                        None => break,
+                    }
+                }
+            }
+
+            let id = state.next_id;
+            state.next_id += 1;
+            state.order.push_back((key.clone(), id));
+
+            match self.map.insert(key, ClockEntry::new(value, id), guard) {
+                Some(replaced) => refs.push(replaced),
+                None => state.len += 1,
+            }
+
+            if state.order.len() > 2 * self.capacity {
+                self.compact(state, guard);
+            }
+
+            refs
+        })
+    }
+
+    /// Removes a key from the cache, returning the key-value pair if successful.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn remove<G>(&self, key: &K, guard: &G) -> Option<Ref<K, ClockEntry<V>, F>>
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.with_state(|state| {
+            let removed = self.map.remove(key, guard);
+
+            if removed.is_some() {
+                state.len -= 1;
+            }
+
+            removed
+        })
+    }
+
+    /// Returns the amount of entries in the cache.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn len(&self) -> usize {
+        self.with_state(|state| state.len)
+    }
+
+    /// Returns `true` if the cache is empty.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Evicts the next entry that was not used recently.
+    fn evict<G>(&self, state: &mut ClockState<K>, guard: &G) -> Option<Ref<K, ClockEntry<V>, F>>
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        while let Some((key, id)) = state.order.pop_front() {
+            // The key was replaced or removed since it was queued.
+            let Some(entry) = self.map.get(&key, guard).filter(|entry| entry.id == id) else {
+                continue;
+            };
+
+            if entry.referenced.swap(false, Ordering::Relaxed) {
+                state.order.push_back((key, id));
+                continue;
+            }
+
+            if let Some(evicted) = self.map.remove(&key, guard) {
+                state.len -= 1;
+                return Some(evicted);
+            }
+        }
+
+        None
+    }
+
+    /// Drops the queued keys that were replaced or removed since they were queued.
+    ///
+    /// Every entry of the cache is queued once, so the queue is bounded by the capacity
+    /// after compacting it.
+    fn compact<G>(&self, state: &mut ClockState<K>, guard: &G)
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        state.order.retain(|(key, id)| {
+            self.map
+                .get(key, guard)
+                .is_some_and(|entry| entry.id == *id)
+        });
+    }
+
+    fn with_state<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce(&mut ClockState<K>) -> R,
+    {
+        // The queued keys are checked against the hashmap before eviction and the length
+        // follows every hashmap update, so the cache is still usable if a writer panics
+        // while holding the lock.
+        let mut guard = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback(&mut guard);
+        drop(guard);
+        result
+    }
+}
@@ -0,0 +1,21 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::clock::container::ClockEntry;
+
+mod asserts {
+    use static_assertions::assert_impl_all;
+
+    use crate::collections::clock::container::RcuClockCache;
+    use crate::utility::asserts::*;
+
+    mod rcu_clock_cache {
+        use super::*;
+
+        // T: Send + Sync
+        assert_impl_all!(RcuClockCache<SendAndSync, SendAndSync>: Send);
+        assert_impl_all!(RcuClockCache<SendAndSync, SendAndSync>: Sync);
+    }
+}
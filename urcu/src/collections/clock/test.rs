@@ -0,0 +1,83 @@
+use crate::collections::clock::container::RcuClockCache;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn eviction() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let cache = RcuClockCache::<u32, u32>::new(2).unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert!(cache.insert(1, 10, &guard).is_empty());
+    assert!(cache.insert(2, 20, &guard).is_empty());
+    assert_eq!(cache.len(), 2);
+
+    // The first entry is used, so the second one is evicted.
+    assert_eq!(cache.get(&1, &guard), Some(&10));
+    let evicted = cache.insert(3, 30, &guard);
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&2, &guard));
+    assert_eq!(cache.peek(&1, &guard), Some(&10));
+    assert_eq!(cache.peek(&3, &guard), Some(&30));
+
+    // Replacing an entry does not evict anything.
+    let replaced = cache.insert(3, 31, &guard);
+    assert_eq!(cache.len(), 2);
+
+    let removed = cache.remove(&1, &guard).unwrap();
+    assert!(cache.remove(&1, &guard).is_none());
+    assert_eq!(cache.len(), 1);
+    drop(guard);
+
+    let evicted = evicted.take_ownership(&mut context);
+    assert_eq!(evicted.len(), 1);
+    assert_eq!((*evicted[0].key(), **evicted[0].value()), (2, 20));
+
+    let replaced = replaced.take_ownership(&mut context);
+    assert_eq!((*replaced[0].key(), **replaced[0].value()), (3, 30));
+
+    let removed = removed.take_ownership(&mut context);
+    assert_eq!((*removed.key(), **removed.value()), (1, 10));
+}
+
+#[test]
+fn compaction() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let cache = RcuClockCache::<u32, u32>::new(2).unwrap();
+    let guard = context.rcu_read_lock();
+    let mut refs = Vec::new();
+
+    // Replacing and removing entries queues stale keys that must be compacted.
+    for value in 0..100 {
+        refs.extend(cache.insert(1, value, &guard));
+        refs.extend(cache.insert(2, value, &guard));
+        refs.extend(cache.remove(&2, &guard));
+    }
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.peek(&1, &guard), Some(&99));
+
+    // The compaction keeps the live entries in order.
+    refs.extend(cache.insert(3, 30, &guard));
+    let evicted = cache.insert(4, 40, &guard);
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&1, &guard));
+    assert_eq!(cache.peek(&3, &guard), Some(&30));
+    assert_eq!(cache.peek(&4, &guard), Some(&40));
+    drop(guard);
+
+    let evicted = evicted.take_ownership(&mut context);
+    assert_eq!((*evicted[0].key(), **evicted[0].value()), (1, 99));
+
+    refs.take_ownership(&mut context);
+}
@@ -0,0 +1,161 @@
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+
+static NEXT_SHARD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SHARD: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Returns the shard index of the current thread.
+///
+/// Threads are assigned indexes in a round-robin fashion when they first update a counter.
+fn shard_index() -> usize {
+    SHARD.with(|shard| match shard.get() {
+        Some(index) => index,
+        None => {
+            let index = NEXT_SHARD.fetch_add(1, Ordering::Relaxed);
+            shard.set(Some(index));
+            index
+        }
+    })
+}
+
+/// Defines a shard of a counter, padded to avoid false sharing.
+#[repr(align(64))]
+#[derive(Default)]
+struct Shard(AtomicI64);
+
+/// Defines a counter split into per-thread shards.
+///
+/// Each thread updates its own shard, so concurrent updates do not contend on a single
+/// cache line. Readers aggregate all the shards, similar to the per-CPU counters of the
+/// Linux kernel.
+///
+/// # Limitations
+///
+/// ##### Consistency
+///
+/// The shards are read one after the other without any synchronization with the writers.
+/// The aggregated value may miss concurrent updates, but it is exact once all the writers
+/// are done.
+///
+/// ##### Sharding
+///
+/// The amount of shards is fixed when the counter is created. When more threads than
+/// shards update the counter, some threads share a shard.
+pub struct RcuCounter<F = RcuDefaultFlavor> {
+    shards: Box<[Shard]>,
+    _flavor: PhantomData<F>,
+}
+
+impl<F> RcuCounter<F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new counter with a shard per available CPU.
+    pub fn new() -> Self {
+        let shards = std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1);
+
+        Self::with_shards(shards)
+    }
+
+    /// Creates a new counter with at least the specified amount of shards.
+    ///
+    /// The amount of shards is rounded up to the next power of two.
+    pub fn with_shards(shards: usize) -> Self {
+        let shards = shards.max(1).next_power_of_two();
+
+        Self {
+            shards: (0..shards).map(|_| Shard::default()).collect(),
+            _flavor: PhantomData,
+        }
+    }
+
+    /// Adds a value to the shard of the current thread.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn add(&self, value: i64) {
+        self.shard().0.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Subtracts a value from the shard of the current thread.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn sub(&self, value: i64) {
+        self.shard().0.fetch_sub(value, Ordering::Relaxed);
+    }
+
+    /// Increments the shard of the current thread.
+    pub fn increment(&self) {
+        self.add(1);
+    }
+
+    /// Decrements the shard of the current thread.
+    pub fn decrement(&self) {
+        self.sub(1);
+    }
+
+    /// Returns the sum of all the shards.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*s*) time, where *s* is the amount of shards.
+    pub fn sum<G>(&self, guard: &G) -> i64
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        self.shards
+            .iter()
+            .map(|shard| shard.0.load(Ordering::Relaxed))
+            .fold(0, i64::wrapping_add)
+    }
+
+    /// Returns the sum of all the shards and resets them to zero.
+    ///
+    /// #### Note
+    ///
+    /// Each shard is swapped atomically, so no concurrent update is lost.
+    pub fn take<G>(&self, guard: &G) -> i64
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        self.shards
+            .iter()
+            .map(|shard| shard.0.swap(0, Ordering::Relaxed))
+            .fold(0, i64::wrapping_add)
+    }
+
+    /// Returns the amount of shards.
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn shard(&self) -> &Shard {
+        &self.shards[shard_index() & (self.shards.len() - 1)]
+    }
+}
+
+impl<F> Default for RcuCounter<F>
+where
+    F: RcuFlavor,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
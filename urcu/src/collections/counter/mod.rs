@@ -0,0 +1,14 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::assert_impl_all;
+
+    use crate::collections::counter::container::RcuCounter;
+    use crate::rcu::default::RcuDefaultFlavor;
+
+    assert_impl_all!(RcuCounter<RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuCounter<RcuDefaultFlavor>: Sync);
+}
@@ -0,0 +1,34 @@
+use crate::collections::counter::container::RcuCounter;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+
+#[test]
+fn sharded() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let counter = RcuCounter::<RcuDefaultFlavor>::with_shards(3);
+    assert_eq!(counter.shards(), 4);
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..1000 {
+                    counter.increment();
+                }
+
+                counter.sub(100);
+            });
+        }
+    });
+
+    counter.decrement();
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(counter.sum(&guard), 7199);
+    assert_eq!(counter.take(&guard), 7199);
+    assert_eq!(counter.sum(&guard), 0);
+}
@@ -0,0 +1,106 @@
+use std::mem::MaybeUninit;
+
+use anyhow::{bail, Result};
+use urcu_cds_sys::lfht;
+
+/// Defines the thread attributes of the resize workers of an [`RcuHashMap`].
+///
+/// When a large hash table is resized, `liburcu` partitions the work between multiple
+/// threads created with these attributes. This can be used to change their stack size
+/// or their scheduling attributes.
+///
+/// #### Note
+///
+/// The resize worker shared by every hash table is not affected by these attributes.
+/// This worker is paused by the `pthread_atfork` handlers of `liburcu` when the process
+/// forks and it is restarted in both the parent and the child.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+pub struct RcuHashMapWorkerAttr(Box<libc::pthread_attr_t>);
+
+impl RcuHashMapWorkerAttr {
+    /// Creates default thread attributes.
+    pub fn new() -> Result<Self> {
+        let mut attr = Box::new(MaybeUninit::<libc::pthread_attr_t>::uninit());
+
+        // SAFETY: The pointer is valid for writes.
+        let result = unsafe { libc::pthread_attr_init(attr.as_mut_ptr()) };
+        if result != 0 {
+            bail!("failed to initialize thread attributes (error {})", result);
+        }
+
+        // SAFETY: The attributes are initialized by `pthread_attr_init`.
+        Ok(Self(unsafe { Box::from_raw(Box::into_raw(attr).cast()) }))
+    }
+
+    /// Sets the stack size of the worker threads.
+    pub fn with_stack_size(mut self, size: usize) -> Result<Self> {
+        // SAFETY: The attributes are initialized.
+        let result = unsafe { libc::pthread_attr_setstacksize(self.as_mut_ptr(), size) };
+        if result != 0 {
+            bail!("failed to set stack size to {} (error {})", size, result);
+        }
+
+        Ok(self)
+    }
+
+    /// Returns a raw pointer to the underlying attributes.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must only modify the attributes with the `pthread_attr_*` functions.
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut libc::pthread_attr_t {
+        &mut *self.0
+    }
+}
+
+/// #### Safety
+///
+/// The attributes are only read by `pthread_create` once handed to `liburcu`.
+unsafe impl Send for RcuHashMapWorkerAttr {}
+
+/// #### Safety
+///
+/// The attributes are only read by `pthread_create` once handed to `liburcu`.
+unsafe impl Sync for RcuHashMapWorkerAttr {}
+
+impl Drop for RcuHashMapWorkerAttr {
+    fn drop(&mut self) {
+        // SAFETY: The attributes are initialized and no longer used by `liburcu`.
+        unsafe { libc::pthread_attr_destroy(&mut *self.0) };
+    }
+}
+
+/// Defines how the buckets of an [`RcuHashMap`] are allocated.
+///
+/// By default, `liburcu` uses [`RcuHashMapMemory::Order`] when the maximum number of
+/// buckets is unbounded and [`RcuHashMapMemory::Chunk`] otherwise.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RcuHashMapMemory {
+    /// Allocates a new array of buckets for each order of the table size.
+    ///
+    /// The lookups need an additional bit scan to find the array of a bucket.
+    Order,
+    /// Allocates the buckets in chunks of the same size.
+    ///
+    /// The chunk index is preallocated for the maximum number of buckets, which must be
+    /// bounded.
+    Chunk,
+    /// Reserves the address space for the maximum number of buckets up-front.
+    ///
+    /// The pages are only populated as the table grows. This is best suited for huge
+    /// tables, but it requires a bounded maximum number of buckets.
+    Mmap,
+}
+
+impl RcuHashMapMemory {
+    pub(crate) fn as_ptr(self) -> *const lfht::MemoryType {
+        match self {
+            Self::Order => &raw const lfht::MM_ORDER,
+            Self::Chunk => &raw const lfht::MM_CHUNK,
+            Self::Mmap => &raw const lfht::MM_MMAP,
+        }
+    }
+}
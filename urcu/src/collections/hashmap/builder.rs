@@ -0,0 +1,177 @@
+use std::ffi::{c_int, c_ulong};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use urcu_cds_sys::lfht;
+
+use crate::collections::hashmap::attr::{RcuHashMapMemory, RcuHashMapWorkerAttr};
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::hashmap::expiring::RcuExpiringHashMap;
+use crate::collections::hashmap::matcher::RcuDefaultKeyMatcher;
+use crate::collections::hashmap::raw::RawMapBuckets;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+
+/// Defines a builder for an [`RcuHashMap`].
+pub struct RcuHashMapBuilder<K, V, F = RcuDefaultFlavor, M = RcuDefaultKeyMatcher> {
+    pub(crate) dedicated_cleaner: bool,
+    pub(crate) worker_attr: Option<RcuHashMapWorkerAttr>,
+    pub(crate) buckets: RawMapBuckets,
+    pub(crate) memory: Option<RcuHashMapMemory>,
+    pub(crate) auto_resize: bool,
+    pub(crate) accounting: bool,
+    _phantom: PhantomData<(K, V, F, M)>,
+}
+
+impl<K, V, F, M> RcuHashMapBuilder<K, V, F, M>
+where
+    K: Send + 'static,
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+    M: 'static,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            dedicated_cleaner: false,
+            worker_attr: None,
+            buckets: Default::default(),
+            memory: None,
+            auto_resize: true,
+            accounting: true,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Uses a dedicated cleanup thread for the hashmap.
+    ///
+    /// The reclamation of the hashmap (e.g. resizes and teardown) is executed by this
+    /// thread instead of the cleanup thread shared by every user of the flavor.
+    ///
+    /// #### Note
+    ///
+    /// The references removed from the hashmap are still cleaned up by the shared
+    /// cleanup thread when they are dropped.
+    pub fn with_dedicated_cleaner(mut self) -> Self {
+        self.dedicated_cleaner = true;
+        self
+    }
+
+    /// Uses custom thread attributes for the resize workers of the hashmap.
+    ///
+    /// See [`RcuHashMapWorkerAttr`] for the threads affected by these attributes.
+    pub fn with_worker_attr(mut self, attr: RcuHashMapWorkerAttr) -> Self {
+        self.worker_attr = Some(attr);
+        self
+    }
+
+    /// Uses custom bucket bounds for the hashmap.
+    ///
+    /// The hashmap starts with `init` buckets, never shrinks below `min` allocated buckets
+    /// and never grows above `max` buckets. A `max` of zero means no upper bound. Every
+    /// bound is rounded up to the next power of two.
+    ///
+    /// #### Note
+    ///
+    /// By default, the hashmap starts with a single bucket and grows as entries are added.
+    /// Starting with enough buckets avoids the resizes for workloads of known size.
+    pub fn with_buckets(mut self, init: usize, min: usize, max: usize) -> Self {
+        let round = |count: usize| count.max(1).next_power_of_two() as c_ulong;
+
+        self.buckets = RawMapBuckets {
+            init: round(init),
+            min_alloc: round(min),
+            max: if max == 0 { 0 } else { round(max) },
+        };
+
+        self
+    }
+
+    /// Uses a custom memory allocation strategy for the buckets of the hashmap.
+    ///
+    /// See [`RcuHashMapMemory`] for the available strategies.
+    pub fn with_memory(mut self, memory: RcuHashMapMemory) -> Self {
+        self.memory = Some(memory);
+        self
+    }
+
+    /// Enables or disables the automatic resizes of the hashmap.
+    ///
+    /// By default, the hashmap grows when the bucket chains get too long. A fixed-size
+    /// hashmap is only resized by [`RcuHashMap::reserve`] and [`RcuHashMap::shrink_to`].
+    pub fn with_auto_resize(mut self, enabled: bool) -> Self {
+        self.auto_resize = enabled;
+        self
+    }
+
+    /// Enables or disables the accounting of the entries of the hashmap.
+    ///
+    /// By default, each insertion and removal updates per-CPU counters. They are used to
+    /// grow and shrink the hashmap and to report the approximate numbers of
+    /// [`RcuHashMap::len_approx`], which are always zero without accounting.
+    pub fn with_accounting(mut self, enabled: bool) -> Self {
+        self.accounting = enabled;
+        self
+    }
+
+    pub(crate) fn flags(&self) -> c_int {
+        let mut flags = 0;
+
+        if self.auto_resize {
+            flags |= lfht::AUTO_RESIZE as c_int;
+        }
+
+        if self.accounting {
+            flags |= lfht::ACCOUNTING as c_int;
+        }
+
+        flags
+    }
+
+    /// Uses a custom key matcher for the hashmap.
+    ///
+    /// See [`RcuKeyMatcher`] for the requirements of a matcher.
+    ///
+    /// [`RcuKeyMatcher`]: crate::collections::hashmap::matcher::RcuKeyMatcher
+    pub fn with_key_matcher<N>(self) -> RcuHashMapBuilder<K, V, F, N> {
+        RcuHashMapBuilder {
+            dedicated_cleaner: self.dedicated_cleaner,
+            worker_attr: self.worker_attr,
+            buckets: self.buckets,
+            memory: self.memory,
+            auto_resize: self.auto_resize,
+            accounting: self.accounting,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates the RCU hashmap.
+    pub fn build(self) -> Result<Arc<RcuHashMap<K, V, F, M>>> {
+        RcuHashMap::from_builder(self)
+    }
+
+    pub(crate) fn cast<W>(self) -> RcuHashMapBuilder<K, W, F, M> {
+        RcuHashMapBuilder {
+            dedicated_cleaner: self.dedicated_cleaner,
+            worker_attr: self.worker_attr,
+            buckets: self.buckets,
+            memory: self.memory,
+            auto_resize: self.auto_resize,
+            accounting: self.accounting,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, V, F> RcuHashMapBuilder<K, V, F>
+where
+    K: Send + 'static,
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    /// Creates a RCU hashmap where entries expire after `ttl`.
+    pub fn build_expiring(self, ttl: Duration) -> Result<Arc<RcuExpiringHashMap<K, V, F>>> {
+        RcuExpiringHashMap::from_builder(self, ttl)
+    }
+}
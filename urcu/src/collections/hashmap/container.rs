@@ -1,14 +1,22 @@
-use std::hash::Hash;
+use std::pin::Pin;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
-use crate::collections::hashmap::iterator::Iter;
-use crate::collections::hashmap::raw::RawMap;
-use crate::collections::hashmap::reference::Ref;
+use crate::collections::hashmap::attr::{RcuHashMapMemory, RcuHashMapWorkerAttr};
+use crate::collections::hashmap::builder::RcuHashMapBuilder;
+use crate::collections::hashmap::iterator::{Duplicates, Iter};
+use crate::collections::hashmap::matcher::{RcuDefaultKeyMatcher, RcuKeyMatcher};
+use crate::collections::hashmap::raw::{RawMap, RawMapBuckets, RawNode};
+use crate::collections::hashmap::reference::{Ref, RefValue};
+use crate::collections::hashmap::stats::{RcuHashMapMemoryUsage, RcuHashMapStats};
+use crate::rcu::cleanup::RcuCleanupThread;
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::DebugWith;
 use crate::{RcuGuard, RcuReadContext, RcuRef};
 
 /// Defines a RCU lock-free hashmap.
@@ -33,13 +41,32 @@ use crate::{RcuGuard, RcuReadContext, RcuRef};
 /// non-registered thread may drop an `RcuHashMap<T>` without calling any RCU
 /// primitives since lifetime rules prevent any other thread from accessing an
 /// RCU reference.
-pub struct RcuHashMap<K, V, F = RcuDefaultFlavor>(RawMap<K, V, F>)
+///
+/// ##### Teardown
+///
+/// Dropping the hashmap never blocks. The remaining key-value pairs are removed and
+/// the hash table is destroyed later by the cleanup thread. Use
+/// [`RcuHashMap::close_blocking`] to wait for the teardown to complete.
+pub struct RcuHashMap<K, V, F = RcuDefaultFlavor, M = RcuDefaultKeyMatcher>
 where
     K: Send + 'static,
     V: Send + 'static,
-    F: RcuFlavor + 'static;
+    F: RcuFlavor + 'static,
+    M: 'static,
+{
+    raw: RawMap<K, V, F, M>,
+    cleaner: Option<RcuCleanupThread<F>>,
+    worker_attr: Option<RcuHashMapWorkerAttr>,
+    refs: RcuRefTracker,
+    resize_requests: AtomicU64,
+    requested_buckets: AtomicU64,
+    buckets: RawMapBuckets,
+    memory: RcuHashMapMemory,
+    auto_resize: bool,
+    blocking_drop: bool,
+}
 
-impl<K, V, F> RcuHashMap<K, V, F>
+impl<K, V, F, M> RcuHashMap<K, V, F, M>
 where
     K: Send,
     V: Send,
@@ -47,7 +74,71 @@ where
 {
     /// Creates a new RCU hashmap.
     pub fn new() -> Result<Arc<Self>> {
-        Ok(Arc::new(Self(RawMap::new()?)))
+        Self::builder().build()
+    }
+
+    /// Creates a new RCU hashmap with custom bucket bounds.
+    ///
+    /// See [`RcuHashMapBuilder::with_buckets`] for the meaning of the bounds.
+    pub fn with_capacity(init: usize, min: usize, max: usize) -> Result<Arc<Self>> {
+        Self::builder().with_buckets(init, min, max).build()
+    }
+
+    /// Creates a builder for a RCU hashmap.
+    pub fn builder() -> RcuHashMapBuilder<K, V, F, M> {
+        RcuHashMapBuilder::new()
+    }
+
+    pub(crate) fn from_builder(builder: RcuHashMapBuilder<K, V, F, M>) -> Result<Arc<Self>> {
+        Self::create(builder).map(Arc::new)
+    }
+
+    pub(crate) fn create(mut builder: RcuHashMapBuilder<K, V, F, M>) -> Result<Self> {
+        let buckets = builder.buckets;
+        if buckets.max != 0 && (buckets.init > buckets.max || buckets.min_alloc > buckets.max) {
+            bail!(
+                "bucket bounds are invalid (init: {}, min: {}, max: {})",
+                buckets.init,
+                buckets.min_alloc,
+                buckets.max
+            );
+        }
+
+        let bounded = matches!(
+            builder.memory,
+            Some(RcuHashMapMemory::Chunk | RcuHashMapMemory::Mmap)
+        );
+        if bounded && buckets.max == 0 {
+            bail!("chunk and mmap memory allocations require a maximum number of buckets");
+        }
+
+        let memory = builder
+            .memory
+            .map_or(std::ptr::null(), RcuHashMapMemory::as_ptr);
+
+        let attr = match &mut builder.worker_attr {
+            // SAFETY: The attributes are only modified by `pthread_create`.
+            Some(attr) => unsafe { attr.as_mut_ptr() },
+            None => std::ptr::null_mut(),
+        };
+
+        Ok(Self {
+            // SAFETY: The memory type is null or one of `liburcu-cds`.
+            // SAFETY: The attributes are destroyed after the hash table.
+            raw: unsafe { RawMap::with_attr(buckets, builder.flags(), memory, attr) }?,
+            cleaner: builder.dedicated_cleaner.then(F::rcu_cleanup_thread),
+            worker_attr: builder.worker_attr,
+            refs: RcuRefTracker::new::<F>("RcuHashMap"),
+            resize_requests: AtomicU64::new(0),
+            requested_buckets: AtomicU64::new(0),
+            buckets,
+            memory: builder.memory.unwrap_or(match buckets.max {
+                0 => RcuHashMapMemory::Order,
+                _ => RcuHashMapMemory::Chunk,
+            }),
+            auto_resize: builder.auto_resize,
+            blocking_drop: false,
+        })
     }
 
     /// Inserts a key-value pair in the hashmap.
@@ -55,7 +146,8 @@ where
     /// If the hashmap did not have this key present, [`None`] is returned.
     pub fn insert<G>(&self, key: K, value: V, guard: &G) -> Option<Ref<K, V, F>>
     where
-        K: Send + Eq + Hash,
+        K: Send,
+        M: RcuKeyMatcher<K>,
         V: Send,
         G: RcuGuard<Flavor = F>,
     {
@@ -63,62 +155,599 @@ where
 
         // SAFETY: The read-side RCU lock is taken.
         // SAFETY: The RCU grace period is enforced through the RcuRef.
-        let node = unsafe { self.0.add_replace(key, value) };
+        let node = unsafe { self.raw.add_replace(key, value) };
+
+        NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+    }
+
+    /// Inserts a key-value pair in the hashmap.
+    ///
+    /// The displaced key-value pair, if any, is cleaned up with [`RcuRef::call_cleanup`].
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn insert_with<C>(&self, context: &C, key: K, value: V)
+    where
+        M: RcuKeyMatcher<K>,
+        C: RcuReadContext<Flavor = F> + 'static,
+    {
+        let guard = context.rcu_read_lock();
+        let displaced = self.insert(key, value, &guard);
+        drop(guard);
+
+        displaced.call_cleanup(context);
+    }
+
+    /// Replaces the value of a key present in the hashmap.
+    ///
+    /// If the hashmap did not have this key present, nothing is inserted and [`None`] is
+    /// returned. Otherwise, the old key-value pair is returned.
+    pub fn replace<G>(&self, key: K, value: V, guard: &G) -> Option<Ref<K, V, F>>
+    where
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        // SAFETY: The RCU grace period is enforced through the RcuRef.
+        let node = unsafe { self.raw.replace(key, value) };
 
-        NonNull::new(node).map(Ref::new)
+        NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+    }
+
+    /// Updates the value of a key present in the hashmap.
+    ///
+    /// The closure computes the new value from the current one, or returns `None` to
+    /// leave the value unchanged. If the value is concurrently replaced or removed, the
+    /// closure is called again with the latest value.
+    ///
+    /// Returns the old key-value pair if the value was updated.
+    pub fn update<G, C>(&self, key: K, callback: C, guard: &G) -> Option<Ref<K, V, F>>
+    where
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+        C: FnMut(&V) -> Option<V>,
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        // SAFETY: The RCU grace period is enforced through the RcuRef.
+        let node = unsafe { self.raw.update(key, callback) };
+
+        NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+    }
+
+    /// Inserts a key-value pair in the hashmap if the key is not present.
+    ///
+    /// Returns a reference to the inserted value. If the hashmap already had this key
+    /// present, nothing is updated and the key-value pair is returned back.
+    pub fn try_insert<'me, 'guard, G>(
+        &'me self,
+        key: K,
+        value: V,
+        guard: &'guard G,
+    ) -> std::result::Result<&'guard V, (K, V)>
+    where
+        'me: 'guard,
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        match unsafe { self.raw.add_unique(key, value) } {
+            // SAFETY: The node pointer is non-null.
+            // SAFETY: The node is valid for the duration of the RCU critical section.
+            (node, None) => Ok(unsafe { &node.as_ref_unchecked().value }),
+            (_, Some(pair)) => Err(pair),
+        }
+    }
+
+    /// Returns a reference to the value of a key, inserting the value returned by `init`
+    /// if the key is not present.
+    ///
+    /// #### Note
+    ///
+    /// The closure is only called if the key is not present. Many threads may insert the
+    /// same key concurrently, but only one of the values is inserted. The other values
+    /// are dropped before returning.
+    pub fn get_or_insert_with<'me, 'guard, G, I>(
+        &'me self,
+        key: K,
+        init: I,
+        guard: &'guard G,
+    ) -> &'guard V
+    where
+        'me: 'guard,
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+        I: FnOnce() -> V,
+    {
+        if let Some(value) = self.get(&key, guard) {
+            return value;
+        }
+
+        // SAFETY: The RCU read-side lock is taken.
+        let (node, _) = unsafe { self.raw.add_unique(key, init()) };
+
+        // SAFETY: The node pointer is non-null.
+        // SAFETY: The node is valid for the duration of the RCU critical section.
+        unsafe { &node.as_ref_unchecked().value }
     }
 
     /// Returns `true` if the hashmap contains a value for the specified key.
     pub fn contains<G>(&self, key: &K, guard: &G) -> bool
     where
-        K: Eq + Hash,
+        M: RcuKeyMatcher<K>,
         G: RcuGuard<Flavor = F>,
     {
         let _ = guard;
 
         // SAFETY: The RCU read-side lock is taken.
-        let mut iter = unsafe { self.0.lookup(key) };
+        let mut iter = unsafe { self.raw.lookup(key) };
 
         !iter.get().is_null()
     }
 
+    /// Returns `true` if the hashmap contains a value for the specified key.
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn contains_with<C>(&self, context: &C, key: &K) -> bool
+    where
+        M: RcuKeyMatcher<K>,
+        C: RcuReadContext<Flavor = F>,
+    {
+        self.contains(key, &context.rcu_read_lock())
+    }
+
     /// Returns a reference to the value corresponding to the key.
     pub fn get<'me, 'guard, G>(&'me self, key: &K, _guard: &'guard G) -> Option<&'guard V>
     where
         'me: 'guard,
-        K: Eq + Hash,
+        M: RcuKeyMatcher<K>,
         G: RcuGuard<Flavor = F>,
     {
         // SAFETY: The RCU read-side lock is taken.
-        let mut iter = unsafe { self.0.lookup(key) };
+        let mut iter = unsafe { self.raw.lookup(key) };
 
         // SAFETY: The node pointer is convertible to a reference is non-null.
         unsafe { iter.get().as_ref() }.map(|node| &node.value)
     }
 
+    /// Returns the key-value pair corresponding to the key.
+    pub fn get_key_value<'me, 'guard, G>(
+        &'me self,
+        key: &K,
+        _guard: &'guard G,
+    ) -> Option<(&'guard K, &'guard V)>
+    where
+        'me: 'guard,
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU read-side lock is taken.
+        let mut iter = unsafe { self.raw.lookup(key) };
+
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        unsafe { iter.get().as_ref() }.map(|node| node.as_refs())
+    }
+
+    /// Returns an arbitrary key-value pair of the hashmap.
+    ///
+    /// It is cheaper than creating an iterator when any element will do, for example
+    /// when draining the hashmap one element at a time.
+    pub fn first<'me, 'guard, G>(&'me self, _guard: &'guard G) -> Option<(&'guard K, &'guard V)>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU read-side lock is taken.
+        let mut iter = unsafe { self.raw.iter() };
+
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        unsafe { iter.get().as_ref() }.map(|node| node.as_refs())
+    }
+
+    /// Returns a pinned reference to the value corresponding to the key.
+    ///
+    /// #### Note
+    ///
+    /// The values of the hashmap are never moved until they are dropped.
+    pub fn get_pinned<'me, 'guard, G>(
+        &'me self,
+        key: &K,
+        guard: &'guard G,
+    ) -> Option<Pin<&'guard V>>
+    where
+        'me: 'guard,
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The value is boxed and never moved until it is dropped.
+        self.get(key, guard)
+            .map(|value| unsafe { Pin::new_unchecked(value) })
+    }
+
     /// Removes a key from the hashmap, returning the key-value pair if successful.
     pub fn remove<G>(&self, key: &K, guard: &G) -> Option<Ref<K, V, F>>
     where
-        K: Send + Eq + Hash,
+        K: Send,
+        M: RcuKeyMatcher<K>,
+        V: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        // SAFETY: The RCU grace period is enforced through RcuRef.
+        let node = unsafe { self.del_key(key) };
+
+        NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+    }
+
+    /// Removes a key from the hashmap, returning the value if successful.
+    ///
+    /// #### Note
+    ///
+    /// The key type is erased from the returned reference.
+    pub fn remove_value<G>(&self, key: &K, guard: &G) -> Option<RefValue<V, F>>
+    where
+        K: Send,
+        M: RcuKeyMatcher<K>,
         V: Send,
         G: RcuGuard<Flavor = F>,
     {
         let _ = guard;
 
         // SAFETY: The RCU read-side lock is taken.
-        let mut iter = unsafe { self.0.lookup(key) };
+        // SAFETY: The RCU grace period is enforced through RcuRef.
+        let node = unsafe { self.del_key(key) };
+
+        NonNull::new(node).map(|node| RefValue::new(node).tracked(&self.refs))
+    }
+
+    /// Removes all the key-value pairs matching a predicate.
+    ///
+    /// The removed key-value pairs are returned.
+    pub fn split_by<G, P>(&self, predicate: P, guard: &G) -> Vec<Ref<K, V, F>>
+    where
+        P: FnMut(&K, &V) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        // SAFETY: The RCU grace period is enforced through RcuRef.
+        unsafe { self.raw.del_if(predicate) }
+            .into_iter()
+            .map(|node| Ref::new(node).tracked(&self.refs))
+            .collect()
+    }
+
+    /// Moves all the key-value pairs matching a predicate into another hashmap.
+    ///
+    /// Every matching pair is copied into `other` before being removed from this
+    /// hashmap, so a concurrent reader always finds it in at least one of them. The
+    /// key-value pairs removed from this hashmap are returned.
+    ///
+    /// #### Note
+    ///
+    /// The values replaced in `other` are cleaned up. Nothing is moved if both
+    /// hashmaps are the same.
+    pub fn partition_into<G, P>(
+        &self,
+        other: &Self,
+        mut predicate: P,
+        guard: &G,
+    ) -> Vec<Ref<K, V, F>>
+    where
+        K: Clone,
+        M: RcuKeyMatcher<K>,
+        V: Clone,
+        P: FnMut(&K, &V) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        if std::ptr::eq(self, other) {
+            return Vec::new();
+        }
+
+        // SAFETY: The read-side RCU lock is taken.
+        let mut iter = unsafe { self.raw.iter() };
+        let mut refs = Vec::new();
 
         // SAFETY: The node pointer is convertible to a reference is non-null.
-        let node = match unsafe { iter.get().as_ref() } {
-            None => std::ptr::null_mut(),
-            Some(node) => {
-                // SAFETY: The RCU read-side lock is taken.
+        while let Some(node) = unsafe { iter.get().as_ref() } {
+            if predicate(&node.key, &node.value) {
+                other
+                    .insert(node.key.clone(), node.value.clone(), guard)
+                    .safe_cleanup();
+
                 // SAFETY: The RCU grace period is enforced through RcuRef.
-                unsafe { self.0.del(node.into()) }
+                if let Some(node) = NonNull::new(iter.del()) {
+                    refs.push(Ref::new(node).tracked(&self.refs));
+                }
             }
+
+            iter.next();
+        }
+
+        refs
+    }
+
+    /// Returns the number of key-value pairs in the hashmap.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time. The count may not include the
+    /// concurrent insertions and removals.
+    pub fn len<G>(&self, guard: &G) -> usize
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        unsafe { self.raw.count() as usize }
+    }
+
+    /// Returns the approximate number of key-value pairs before counting, the number of
+    /// counted key-value pairs and the approximate number of key-value pairs after counting.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time. The approximate numbers are
+    /// maintained by the hash table without traversal and may be off by a few entries
+    /// per CPU.
+    pub fn len_approx<G>(&self, guard: &G) -> (i64, usize, i64)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        let (before, count, after) = unsafe { self.raw.count_nodes() };
+
+        (before, count as usize, after)
+    }
+
+    /// Returns `true` if the hashmap contains no key-value pairs.
+    pub fn is_empty<G>(&self, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        unsafe { self.raw.iter() }.get().is_null()
+    }
+
+    /// Returns the occupancy statistics of the hashmap.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time.
+    pub fn stats<G>(&self, guard: &G) -> RcuHashMapStats
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let (approx_before, count, approx_after) = self.len_approx(guard);
+
+        RcuHashMapStats {
+            approx_before,
+            count,
+            approx_after,
+            resize_requests: self.resize_requests.load(Ordering::Relaxed),
+            requested_buckets: self.requested_buckets.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Returns the approximate memory usage of the hashmap.
+    ///
+    /// See [`RcuHashMapMemoryUsage`] for how the buckets are estimated.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time.
+    pub fn memory_usage<G>(&self, guard: &G) -> RcuHashMapMemoryUsage
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let nodes = self.len(guard);
+        let size = match self.resize_requests.load(Ordering::Relaxed) {
+            _ if self.auto_resize => nodes.max(1).next_power_of_two(),
+            0 => self.buckets.init as usize,
+            _ => self.requested_buckets.load(Ordering::Relaxed) as usize,
         };
 
-        NonNull::new(node).map(Ref::new)
+        RcuHashMapMemoryUsage::estimate(
+            nodes,
+            size_of::<RawNode<K, V>>(),
+            size,
+            self.buckets,
+            self.memory,
+        )
+    }
+
+    /// Drops the hashmap and waits for its teardown to complete.
+    ///
+    /// If other references to the hashmap exist, nothing is dropped and the hashmap is
+    /// returned back.
+    ///
+    /// #### Note
+    ///
+    /// This call blocks until the remaining key-value pairs are removed and reclaimed and
+    /// the hash table is destroyed. It must not be called from a cleanup callback.
+    ///
+    /// The reclamation waits for a RCU grace period, so a QSBR thread must be offline
+    /// when calling this function.
+    pub fn close_blocking(self: Arc<Self>) -> std::result::Result<(), Arc<Self>> {
+        let mut hashmap = Arc::try_unwrap(self)?;
+        hashmap.blocking_drop = true;
+        drop(hashmap);
+
+        Ok(())
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must hold the RCU read-side lock and wait for a grace period before
+    /// freeing the removed node.
+    unsafe fn del_key(&self, key: &K) -> *mut RawNode<K, V>
+    where
+        M: RcuKeyMatcher<K>,
+    {
+        // SAFETY: The RCU read-side lock is taken.
+        let mut iter = unsafe { self.raw.lookup(key) };
+
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        match unsafe { iter.get().as_ref() } {
+            None => std::ptr::null_mut(),
+            // SAFETY: The RCU read-side lock is taken.
+            Some(node) => unsafe { self.raw.del(node.into()) },
+        }
+    }
+
+    fn request_resize(&self, size: u64) {
+        self.resize_requests.fetch_add(1, Ordering::Relaxed);
+        self.requested_buckets.store(size, Ordering::Relaxed);
+
+        self.rcu_cleanup_raw(move |raw, _| {
+            // SAFETY: The read-side RCU lock is not taken.
+            // SAFETY: We are a registered RCU read-side thread.
+            unsafe { raw.resize(size) };
+        });
+    }
+
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time since it counts the key-value
+    /// pairs of the hashmap.
+    ///
+    /// The hash table cannot be resized inside a RCU critical section. The resize is
+    /// executed asynchronously by the RCU cleanup thread of the hashmap.
+    pub fn reserve<G>(&self, additional: usize, guard: &G)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        let count = unsafe { self.raw.count() };
+        let size = count
+            .saturating_add(additional as u64)
+            .checked_next_power_of_two()
+            .unwrap_or(1 << 63);
+
+        self.request_resize(size);
+    }
+
+    /// Shrinks the capacity of the hashmap with a lower bound.
+    ///
+    /// The capacity will remain at least as large as both the length and the supplied
+    /// value. This is useful after mass deletions, since the hash table is otherwise
+    /// never shrunk.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time since it counts the key-value
+    /// pairs of the hashmap.
+    ///
+    /// The hash table cannot be resized inside a RCU critical section. The resize is
+    /// executed asynchronously by the RCU cleanup thread of the hashmap.
+    pub fn shrink_to<G>(&self, min_capacity: usize, guard: &G)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        let count = unsafe { self.raw.count() };
+        let size = count
+            .max(min_capacity as u64)
+            .max(1)
+            .checked_next_power_of_two()
+            .unwrap_or(1 << 63);
+
+        self.request_resize(size);
+    }
+
+    /// Inserts a key-value pair without replacing the values of the same key.
+    pub(crate) fn insert_duplicate<G>(&self, key: K, value: V, guard: &G)
+    where
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        unsafe { self.raw.add(key, value) };
+    }
+
+    /// Returns an iterator over all the values of a key.
+    pub(crate) fn get_duplicates<'me, 'guard, 'key, G>(
+        &'me self,
+        key: &'key K,
+        guard: &'guard G,
+    ) -> Duplicates<'guard, 'key, K, V, F, M>
+    where
+        'me: 'guard,
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        Duplicates::new(
+            // SAFETY: The read-side RCU lock is taken.
+            unsafe { self.raw.lookup(key) },
+            key,
+        )
+    }
+
+    /// Removes all the values of a key, returning the removed key-value pairs.
+    pub(crate) fn remove_duplicates<G>(&self, key: &K, guard: &G) -> Vec<Ref<K, V, F>>
+    where
+        M: RcuKeyMatcher<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        let mut iter = unsafe { self.raw.lookup(key) };
+        let mut refs = Vec::new();
+
+        while !iter.get().is_null() {
+            // SAFETY: The RCU grace period is enforced through RcuRef.
+            if let Some(node) = NonNull::new(iter.del()) {
+                refs.push(Ref::new(node).tracked(&self.refs));
+            }
+
+            iter.next_duplicate::<M>(key);
+        }
+
+        refs
+    }
+
+    /// Executes a callback on the raw hashmap from the RCU cleanup thread of the hashmap.
+    ///
+    /// The hashmap is destroyed after the callback by the same thread, so the raw hashmap
+    /// is always valid inside the callback.
+    pub(crate) fn rcu_cleanup_raw<C>(&self, callback: C)
+    where
+        C: FnOnce(&RawMap<K, V, F, M>, &mut F::CleanupContext) + Send + 'static,
+    {
+        let raw = self.raw.clone();
+        let callback = Box::new(move |context: &mut F::CleanupContext| callback(&raw, context));
+
+        match &self.cleaner {
+            Some(cleaner) => cleaner.rcu_cleanup(callback),
+            None => F::rcu_cleanup(callback),
+        }
     }
 
     /// Returns an iterator visiting all key-value pairs in arbitrary order.
@@ -131,21 +760,59 @@ where
 
         Iter::new(
             // SAFETY: The read-side RCU lock is taken.
-            unsafe { self.0.iter() },
+            unsafe { self.raw.iter() },
         )
     }
+
+    /// Returns a value formatting the key-value pairs of the hashmap with [`Debug`].
+    ///
+    /// The key-value pairs are formatted in arbitrary order.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn debug<'me, 'guard, G>(&'me self, guard: &'guard G) -> impl std::fmt::Debug + 'guard
+    where
+        'me: 'guard,
+        K: std::fmt::Debug,
+        V: std::fmt::Debug,
+        G: RcuGuard<Flavor = F>,
+    {
+        DebugWith(move |f: &mut std::fmt::Formatter<'_>| {
+            f.debug_map().entries(self.iter(guard)).finish()
+        })
+    }
+
+    /// Calls a closure on each key-value pair in arbitrary order.
+    ///
+    /// #### Note
+    ///
+    /// The iteration is driven internally and is faster than [`RcuHashMap::iter`]
+    /// for full-table scans.
+    pub fn for_each<G, C>(&self, guard: &G, callback: C)
+    where
+        G: RcuGuard<Flavor = F>,
+        C: FnMut(&K, &V),
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        unsafe { self.raw.for_each(callback) };
+    }
 }
 
-impl<K, V, F> Drop for RcuHashMap<K, V, F>
+impl<K, V, F, M> Drop for RcuHashMap<K, V, F, M>
 where
     K: Send + 'static,
     V: Send + 'static,
     F: RcuFlavor + 'static,
+    M: 'static,
 {
     fn drop(&mut self) {
-        let mut raw = self.0.clone();
+        self.refs.report();
 
-        F::rcu_cleanup_and_block(Box::new(move |context| {
+        let mut raw = self.raw.clone();
+        let worker_attr = self.worker_attr.take();
+
+        let teardown = move |context: &F::CleanupContext| {
             let guard = context.rcu_read_lock();
 
             // SAFETY: The read-side RCU lock is taken.
@@ -161,6 +828,28 @@ where
             // SAFETY: The read-side RCU lock is not taken.
             // SAFETY: We are a registered RCU read-side thread.
             unsafe { raw.destroy() };
-        }));
+            drop(worker_attr);
+        };
+
+        match self.cleaner.take() {
+            Some(cleaner) => {
+                cleaner.rcu_cleanup(Box::new(move |context| teardown(context)));
+
+                // The dedicated thread executes the teardown before shutting down.
+                if self.blocking_drop {
+                    drop(cleaner);
+                } else {
+                    cleaner.detach();
+                }
+            }
+            None if self.blocking_drop => F::rcu_cleanup_and_block(Box::new(teardown)),
+            None => F::rcu_cleanup(Box::new(move |context| teardown(context))),
+        }
+
+        // The removed key-value pairs are reclaimed by the shared cleanup thread after
+        // the teardown, so waiting for its next callback waits for the reclamation.
+        if self.blocking_drop {
+            F::rcu_cleanup_and_block(Box::new(|_| {}));
+        }
     }
 }
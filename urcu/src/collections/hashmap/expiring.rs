@@ -0,0 +1,232 @@
+use std::hash::Hash;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+
+use crate::collections::hashmap::builder::RcuHashMapBuilder;
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::hashmap::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::{RcuGuard, RcuReadContext, RcuRef};
+
+/// Defines a value with an expiration deadline.
+pub struct Expiring<V> {
+    value: V,
+    deadline: Instant,
+}
+
+impl<V> Expiring<V> {
+    /// Defines the longest time-to-live, longer durations never expire in practice.
+    const MAX_TTL: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+    fn new(value: V, ttl: Duration) -> Self {
+        Self {
+            value,
+            deadline: Instant::now() + ttl.min(Self::MAX_TTL),
+        }
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Returns the instant after which the value is expired.
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Returns `true` if the value is expired.
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Instant::now())
+    }
+
+    fn is_expired_at(&self, now: Instant) -> bool {
+        self.deadline <= now
+    }
+}
+
+impl<V> Deref for Expiring<V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Defines a RCU lock-free hashmap where entries expire.
+///
+/// Every entry carries a deadline. Reads ignore the expired entries and the hashmap
+/// periodically purges them from its RCU cleanup thread.
+///
+/// #### Note
+///
+/// The purge is scheduled by the writers. The expired entries of a hashmap that is
+/// never written to are only removed by [`RcuExpiringHashMap::purge`].
+pub struct RcuExpiringHashMap<K, V, F = RcuDefaultFlavor>
+where
+    K: Send + 'static,
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    map: RcuHashMap<K, Expiring<V>, F>,
+    ttl: Duration,
+    origin: Instant,
+    next_purge: AtomicU64,
+}
+
+impl<K, V, F> RcuExpiringHashMap<K, V, F>
+where
+    K: Send,
+    V: Send,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU hashmap where entries expire after `ttl`.
+    pub fn new(ttl: Duration) -> Result<Arc<Self>> {
+        RcuHashMapBuilder::<K, V, F>::new().build_expiring(ttl)
+    }
+
+    pub(crate) fn from_builder(
+        builder: RcuHashMapBuilder<K, V, F>,
+        ttl: Duration,
+    ) -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            map: RcuHashMap::create(builder.cast())?,
+            ttl,
+            origin: Instant::now(),
+            next_purge: AtomicU64::new(Self::nanos(ttl)),
+        }))
+    }
+
+    fn nanos(duration: Duration) -> u64 {
+        u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+    }
+
+    /// Returns the default time-to-live of the entries.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Inserts a key-value pair expiring after the default time-to-live.
+    ///
+    /// If the hashmap did not have this key present, [`None`] is returned.
+    pub fn insert<G>(&self, key: K, value: V, guard: &G) -> Option<Ref<K, Expiring<V>, F>>
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.insert_with_ttl(key, value, self.ttl, guard)
+    }
+
+    /// Inserts a key-value pair expiring after `ttl`.
+    ///
+    /// If the hashmap did not have this key present, [`None`] is returned.
+    pub fn insert_with_ttl<G>(
+        &self,
+        key: K,
+        value: V,
+        ttl: Duration,
+        guard: &G,
+    ) -> Option<Ref<K, Expiring<V>, F>>
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        let old = self.map.insert(key, Expiring::new(value, ttl), guard);
+        self.schedule_purge();
+        old
+    }
+
+    /// Returns `true` if the hashmap contains a live value for the specified key.
+    pub fn contains<G>(&self, key: &K, guard: &G) -> bool
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.get(key, guard).is_some()
+    }
+
+    /// Returns a reference to the live value corresponding to the key.
+    pub fn get<'me, 'guard, G>(&'me self, key: &K, guard: &'guard G) -> Option<&'guard V>
+    where
+        'me: 'guard,
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map
+            .get(key, guard)
+            .filter(|entry| !entry.is_expired())
+            .map(Expiring::value)
+    }
+
+    /// Removes a key from the hashmap, returning the key-value pair if successful.
+    ///
+    /// #### Note
+    ///
+    /// An expired entry that was not purged yet is also returned.
+    pub fn remove<G>(&self, key: &K, guard: &G) -> Option<Ref<K, Expiring<V>, F>>
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.remove(key, guard)
+    }
+
+    /// Returns an iterator visiting all live key-value pairs in arbitrary order.
+    pub fn iter<'me, 'guard, G>(
+        &'me self,
+        guard: &'guard G,
+    ) -> impl Iterator<Item = (&'guard K, &'guard V)>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let now = Instant::now();
+
+        self.map
+            .iter(guard)
+            .filter(move |(_, entry)| !entry.is_expired_at(now))
+            .map(|(key, entry)| (key, entry.value()))
+    }
+
+    /// Removes all the expired entries from the RCU cleanup thread of the hashmap.
+    pub fn purge(&self) {
+        self.map.rcu_cleanup_raw(|raw, context| {
+            let now = Instant::now();
+            let guard = context.rcu_read_lock();
+
+            // SAFETY: The read-side RCU lock is taken.
+            let refs = unsafe { raw.del_if(|_, entry| entry.is_expired_at(now)) }
+                .iter()
+                .copied()
+                .map(Ref::<K, Expiring<V>, F>::new)
+                .collect::<Vec<_>>();
+
+            drop(guard);
+
+            refs.safe_cleanup();
+        });
+    }
+
+    fn schedule_purge(&self) {
+        let now = Self::nanos(self.origin.elapsed());
+        let next = self.next_purge.load(Ordering::Relaxed);
+
+        if now < next {
+            return;
+        }
+
+        let following = now.saturating_add(Self::nanos(self.ttl));
+        if self
+            .next_purge
+            .compare_exchange(next, following, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+        {
+            self.purge();
+        }
+    }
+}
@@ -1,3 +1,6 @@
+use std::marker::PhantomData;
+
+use crate::collections::hashmap::matcher::{RcuDefaultKeyMatcher, RcuKeyMatcher};
 use crate::collections::hashmap::raw::RawIter;
 
 /// An iterator over the nodes of an [`RcuHashMap`].
@@ -25,3 +28,41 @@ impl<'guard, K, V, F> Iterator for Iter<'guard, K, V, F> {
         })
     }
 }
+
+/// An iterator over the values of a key in an [`RcuMultiMap`].
+///
+/// [`RcuMultiMap`]: crate::collections::hashmap::multimap::RcuMultiMap
+pub struct Duplicates<'guard, 'key, K, V, F, M = RcuDefaultKeyMatcher>
+where
+    K: 'guard,
+    V: 'guard,
+{
+    raw: RawIter<'guard, K, V, F>,
+    key: &'key K,
+    _matcher: PhantomData<M>,
+}
+
+impl<'guard, 'key, K, V, F, M> Duplicates<'guard, 'key, K, V, F, M> {
+    pub fn new(raw: RawIter<'guard, K, V, F>, key: &'key K) -> Self {
+        Self {
+            raw,
+            key,
+            _matcher: PhantomData,
+        }
+    }
+}
+
+impl<'guard, 'key, K, V, F, M> Iterator for Duplicates<'guard, 'key, K, V, F, M>
+where
+    M: RcuKeyMatcher<K>,
+{
+    type Item = &'guard V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        unsafe { self.raw.get().as_ref() }.map(|entry| {
+            self.raw.next_duplicate::<M>(self.key);
+            &entry.value
+        })
+    }
+}
@@ -0,0 +1,37 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// This trait defines how the keys of an [`RcuHashMap`] are hashed and compared.
+///
+/// A custom implementation can be used to store keys without [`Eq`] and [`Hash`], or to
+/// compare them differently (e.g. case-insensitive strings) without a newtype.
+///
+/// #### Requirements
+///
+/// Two keys matching each other must have the same hash.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+pub trait RcuKeyMatcher<K> {
+    /// Returns the hash of a key.
+    fn hash(key: &K) -> u64;
+
+    /// Returns `true` if both keys match.
+    fn matches(left: &K, right: &K) -> bool;
+}
+
+/// Defines a key matcher using the [`Eq`] and [`Hash`] implementations of the key.
+pub struct RcuDefaultKeyMatcher;
+
+impl<K> RcuKeyMatcher<K> for RcuDefaultKeyMatcher
+where
+    K: Eq + Hash,
+{
+    fn hash(key: &K) -> u64 {
+        let mut s = DefaultHasher::new();
+        key.hash(&mut s);
+        s.finish()
+    }
+
+    fn matches(left: &K, right: &K) -> bool {
+        left == right
+    }
+}
@@ -1,13 +1,28 @@
+pub(crate) mod attr;
+pub(crate) mod builder;
 pub(crate) mod container;
+pub(crate) mod expiring;
 pub(crate) mod iterator;
+pub(crate) mod matcher;
+pub(crate) mod multimap;
+#[cfg(not(feature = "fuzzing"))]
 pub(crate) mod raw;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod raw;
 pub(crate) mod reference;
+pub(crate) mod stats;
 
 #[cfg(test)]
 mod test;
 
+pub use crate::collections::hashmap::attr::*;
+pub use crate::collections::hashmap::builder::*;
+pub use crate::collections::hashmap::expiring::Expiring;
 pub use crate::collections::hashmap::iterator::*;
+pub use crate::collections::hashmap::matcher::*;
 pub use crate::collections::hashmap::reference::*;
+pub use crate::collections::hashmap::stats::*;
 
 mod asserts {
     use super::*;
@@ -15,6 +30,8 @@ mod asserts {
     use static_assertions::{assert_impl_all, assert_not_impl_all};
 
     use crate::collections::hashmap::container::RcuHashMap;
+    use crate::collections::hashmap::expiring::RcuExpiringHashMap;
+    use crate::collections::hashmap::multimap::RcuMultiMap;
     use crate::rcu::default::RcuDefaultFlavor;
     use crate::utility::asserts::*;
 
@@ -26,6 +43,22 @@ mod asserts {
         assert_impl_all!(RcuHashMap<SendAndSync, SendAndSync>: Sync);
     }
 
+    mod rcu_expiring_hashmap {
+        use super::*;
+
+        // T: Send + Sync
+        assert_impl_all!(RcuExpiringHashMap<SendAndSync, SendAndSync>: Send);
+        assert_impl_all!(RcuExpiringHashMap<SendAndSync, SendAndSync>: Sync);
+    }
+
+    mod rcu_multimap {
+        use super::*;
+
+        // T: Send + Sync
+        assert_impl_all!(RcuMultiMap<SendAndSync, SendAndSync>: Send);
+        assert_impl_all!(RcuMultiMap<SendAndSync, SendAndSync>: Sync);
+    }
+
     mod rcu_hashmap_ref {
         use super::*;
 
@@ -58,6 +91,38 @@ mod asserts {
         assert_impl_all!(RefOwned<SendAndSync, SendAndSync>: Sync);
     }
 
+    mod rcu_hashmap_ref_value {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(RefValue<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RefValue<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RefValue<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RefValue<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_hashmap_ref_value_owned {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RefValueOwned<NotSendNotSync>: Send);
+        assert_not_impl_all!(RefValueOwned<NotSendNotSync>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RefValueOwned<SendButNotSync>: Send);
+        assert_not_impl_all!(RefValueOwned<SendButNotSync>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RefValueOwned<NotSendButSync>: Send);
+        assert_impl_all!(RefValueOwned<NotSendButSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RefValueOwned<SendAndSync>: Send);
+        assert_impl_all!(RefValueOwned<SendAndSync>: Sync);
+    }
+
     mod rcu_hashmap_iter {
         use super::*;
 
@@ -77,4 +142,12 @@ mod asserts {
         assert_not_impl_all!(Iter<'_, SendAndSync, SendAndSync, RcuDefaultFlavor>: Send);
         assert_not_impl_all!(Iter<'_, SendAndSync, SendAndSync, RcuDefaultFlavor>: Sync);
     }
+
+    mod rcu_multimap_duplicates {
+        use super::*;
+
+        // T: Send + Sync
+        assert_not_impl_all!(Duplicates<'_, '_, SendAndSync, SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Duplicates<'_, '_, SendAndSync, SendAndSync, RcuDefaultFlavor>: Sync);
+    }
 }
@@ -0,0 +1,124 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::collections::hashmap::builder::RcuHashMapBuilder;
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::hashmap::iterator::{Duplicates, Iter};
+use crate::collections::hashmap::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::RcuGuard;
+
+/// Defines a RCU lock-free hashmap with multiple values per key.
+///
+/// Unlike [`RcuHashMap`], inserting a key-value pair never replaces the values already
+/// present for the same key. It shares the hash table implementation of [`RcuHashMap`].
+///
+/// # Limitations
+///
+/// ##### Ordering
+///
+/// The values of a key are visited in an arbitrary order.
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a node's data, it is impossible
+/// to get a mutable references to the data inside the hashmap. You should design the
+/// type stored in the hashmap with [interior mutabillity] that can be shared between
+/// threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuMultiMap<K, V>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuMultiMap<K, V>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing an
+/// RCU reference.
+pub struct RcuMultiMap<K, V, F = RcuDefaultFlavor>
+where
+    K: Send + 'static,
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    map: RcuHashMap<K, V, F>,
+}
+
+impl<K, V, F> RcuMultiMap<K, V, F>
+where
+    K: Send,
+    V: Send,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU hashmap.
+    pub fn new() -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            map: RcuHashMap::create(RcuHashMapBuilder::new())?,
+        }))
+    }
+
+    /// Inserts a key-value pair in the hashmap.
+    ///
+    /// The values already present for this key are kept.
+    pub fn insert<G>(&self, key: K, value: V, guard: &G)
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.insert_duplicate(key, value, guard);
+    }
+
+    /// Returns `true` if the hashmap contains at least one value for the specified key.
+    pub fn contains<G>(&self, key: &K, guard: &G) -> bool
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.contains(key, guard)
+    }
+
+    /// Returns an iterator over all the values of a key.
+    pub fn get_all<'me, 'guard, 'key, G>(
+        &'me self,
+        key: &'key K,
+        guard: &'guard G,
+    ) -> Duplicates<'guard, 'key, K, V, F>
+    where
+        'me: 'guard,
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.get_duplicates(key, guard)
+    }
+
+    /// Removes all the values of a key, returning the removed key-value pairs.
+    pub fn remove_all<G>(&self, key: &K, guard: &G) -> Vec<Ref<K, V, F>>
+    where
+        K: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.remove_duplicates(key, guard)
+    }
+
+    /// Removes all the key-value pairs matching a predicate.
+    ///
+    /// The removed key-value pairs are returned.
+    pub fn split_by<G, P>(&self, predicate: P, guard: &G) -> Vec<Ref<K, V, F>>
+    where
+        P: FnMut(&K, &V) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.split_by(predicate, guard)
+    }
+
+    /// Returns an iterator visiting all key-value pairs in arbitrary order.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, K, V, F>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.iter(guard)
+    }
+}
@@ -1,5 +1,4 @@
-use std::ffi::{c_int, c_void};
-use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ffi::{c_int, c_long, c_ulong, c_void};
 use std::marker::PhantomData;
 use std::ptr::NonNull;
 
@@ -7,6 +6,7 @@ use anyhow::{bail, Result};
 use container_of::container_of;
 use urcu_cds_sys::lfht;
 
+use crate::collections::hashmap::matcher::{RcuDefaultKeyMatcher, RcuKeyMatcher};
 use crate::rcu::flavor::RcuFlavor;
 use crate::utility::{PhantomUnsend, PhantomUnsync};
 
@@ -14,15 +14,16 @@ use crate::utility::{PhantomUnsend, PhantomUnsync};
 // helper functions //
 //////////////////////
 
-fn hash_of<T: Hash>(t: &T) -> u64 {
-    let mut s = DefaultHasher::new();
-    t.hash(&mut s);
-    s.finish()
+/// Returns the hash of a key.
+///
+/// The table hashes are `unsigned long`, so the hash is truncated on 32-bit targets.
+fn hash_of<K, M: RcuKeyMatcher<K>>(key: &K) -> c_ulong {
+    M::hash(key) as c_ulong
 }
 
-unsafe extern "C" fn key_eq<K, V>(handle_ptr: *mut lfht::Node, key_ptr: *const c_void) -> c_int
+unsafe extern "C" fn key_eq<K, V, M>(handle_ptr: *mut lfht::Node, key_ptr: *const c_void) -> c_int
 where
-    K: Eq,
+    M: RcuKeyMatcher<K>,
 {
     // SAFETY: The pointer is never null.
     // SAFETY: The pointer is valid for the duration of the reference..
@@ -32,7 +33,7 @@ where
     // SAFETY: The pointer is valid for the duration of the reference..
     let key = unsafe { (key_ptr as *const K).as_ref_unchecked() };
 
-    if &node.key == key {
+    if M::matches(&node.key, key) {
         1
     } else {
         0
@@ -46,7 +47,7 @@ where
 pub struct RawNodeHandle {
     handle: *mut lfht::Node,
     key: *const c_void,
-    key_hash: u64,
+    key_hash: c_ulong,
 }
 
 pub struct RawNode<K, V> {
@@ -69,9 +70,9 @@ impl<K, V> RawNode<K, V> {
         node
     }
 
-    fn to_handle(self: Box<Self>) -> RawNodeHandle
+    fn to_handle<M>(self: Box<Self>) -> RawNodeHandle
     where
-        K: Hash,
+        M: RcuKeyMatcher<K>,
     {
         let node = Box::into_raw(self);
         let node = unsafe { node.as_mut_unchecked() };
@@ -79,7 +80,7 @@ impl<K, V> RawNode<K, V> {
         RawNodeHandle {
             handle: &mut node.handle,
             key: &node.key as *const K as *const c_void,
-            key_hash: hash_of(&node.key),
+            key_hash: hash_of::<K, M>(&node.key),
         }
     }
 
@@ -97,18 +98,20 @@ impl<K, V> RawNode<K, V> {
 
 pub struct RawIter<'a, K, V, F> {
     handle: lfht::Iter,
-    map: &'a RawMap<K, V, F>,
+    map: *mut lfht::Handle,
+    _map: PhantomData<&'a ()>,
     _unsend: PhantomUnsend<(K, V, F)>,
     _unsync: PhantomUnsync<(K, V, F)>,
 }
 
 impl<'a, K, V, F> RawIter<'a, K, V, F> {
-    fn new<I>(map: &'a RawMap<K, V, F>, init: I) -> Self
+    fn new<M, I>(map: &'a RawMap<K, V, F, M>, init: I) -> Self
     where
         I: FnOnce(*mut lfht::Iter),
     {
         let mut iterator = Self {
-            map,
+            map: map.handle,
+            _map: PhantomData,
             handle: Default::default(),
             _unsend: PhantomData,
             _unsync: PhantomData,
@@ -133,7 +136,27 @@ impl<'a, K, V, F> RawIter<'a, K, V, F> {
     pub fn next(&mut self) {
         // SAFETY: The hashmap pointer is non-null.
         // SAFETY: The iterator pointer is non-null.
-        unsafe { lfht::next(self.map.handle, &mut self.handle) }
+        unsafe { lfht::next(self.map, &mut self.handle) }
+    }
+
+    /// Moves the iterator to the next node matching the key.
+    ///
+    /// The iterator must be positioned on a node matching the key.
+    pub fn next_duplicate<M>(&mut self, key: &K)
+    where
+        M: RcuKeyMatcher<K>,
+    {
+        // SAFETY: The hashmap pointer is non-null.
+        // SAFETY: The iterator pointer is non-null.
+        // SAFETY: The key pointer is non-null.
+        unsafe {
+            lfht::next_duplicate(
+                self.map,
+                Some(key_eq::<K, V, M>),
+                key as *const K as *const c_void,
+                &mut self.handle,
+            )
+        }
     }
 
     pub fn del(&mut self) -> *mut RawNode<K, V> {
@@ -143,7 +166,7 @@ impl<'a, K, V, F> RawIter<'a, K, V, F> {
         // SAFETY: The iterator pointer is non-null.
         // SAFETY: The node pointer is non-null.
         unsafe {
-            if lfht::del(self.map.handle, node) < 0 {
+            if lfht::del(self.map, node) < 0 {
                 std::ptr::null_mut()
             } else {
                 RawNode::from_handle(node)
@@ -152,30 +175,72 @@ impl<'a, K, V, F> RawIter<'a, K, V, F> {
     }
 }
 
-pub struct RawMap<K, V, F> {
+/// Defines the bucket bounds of a hash table.
+#[derive(Clone, Copy)]
+pub struct RawMapBuckets {
+    pub init: c_ulong,
+    pub min_alloc: c_ulong,
+    pub max: c_ulong,
+}
+
+impl Default for RawMapBuckets {
+    fn default() -> Self {
+        Self {
+            init: 1,
+            min_alloc: 1,
+            max: 0,
+        }
+    }
+}
+
+pub struct RawMap<K, V, F, M = RcuDefaultKeyMatcher> {
     handle: *mut lfht::Handle,
-    _unsend: PhantomUnsend<(K, V, F)>,
-    _unsync: PhantomUnsync<(K, V, F)>,
+    _unsend: PhantomUnsend<(K, V, F, M)>,
+    _unsync: PhantomUnsync<(K, V, F, M)>,
 }
 
-impl<K, V, F> RawMap<K, V, F> {
-    const INIT_FLAGS: i32 = (lfht::ACCOUNTING | lfht::AUTO_RESIZE) as i32;
-    const INIT_SIZE: u64 = 1;
-    const MIN_NR_ALLOC_BUCKETS: u64 = 1;
-    const MAX_NR_BUCKETS: u64 = 0;
+impl<K, V, F, M> RawMap<K, V, F, M> {
+    pub const DEFAULT_FLAGS: c_int = (lfht::ACCOUNTING | lfht::AUTO_RESIZE) as c_int;
 
     pub fn new() -> Result<Self>
     where
         F: RcuFlavor,
     {
+        // SAFETY: The default attributes are used.
+        unsafe {
+            Self::with_attr(
+                Default::default(),
+                Self::DEFAULT_FLAGS,
+                std::ptr::null(),
+                std::ptr::null_mut(),
+            )
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The memory type must be null or one of the memory types of `liburcu-cds`.
+    ///
+    /// The attributes must be null or outlive the hash table.
+    pub unsafe fn with_attr(
+        buckets: RawMapBuckets,
+        flags: c_int,
+        memory: *const lfht::MemoryType,
+        attr: *mut libc::pthread_attr_t,
+    ) -> Result<Self>
+    where
+        F: RcuFlavor,
+    {
+        // SAFETY: A null memory type selects the default of `liburcu-cds`.
         let handle = unsafe {
-            lfht::new_flavor(
-                Self::INIT_SIZE,
-                Self::MIN_NR_ALLOC_BUCKETS,
-                Self::MAX_NR_BUCKETS,
-                Self::INIT_FLAGS,
+            lfht::_new(
+                buckets.init,
+                buckets.min_alloc,
+                buckets.max,
+                flags,
+                memory,
                 F::unchecked_rcu_api(),
-                std::ptr::null_mut(),
+                attr,
             )
         };
 
@@ -197,16 +262,16 @@ impl<K, V, F> RawMap<K, V, F> {
     /// The caller must wait for a RCU grace period before taking ownership of the old value.
     pub unsafe fn add_replace(&self, key: K, value: V) -> *mut RawNode<K, V>
     where
-        K: Eq + Hash,
+        M: RcuKeyMatcher<K>,
     {
-        let node = RawNode::new(key, value).to_handle();
+        let node = RawNode::new(key, value).to_handle::<M>();
 
         // SAFETY: All pointers are non-null.
         let node = unsafe {
             lfht::add_replace(
                 self.handle,
                 node.key_hash,
-                Some(key_eq::<K, V>),
+                Some(key_eq::<K, V, M>),
                 node.key,
                 node.handle,
             )
@@ -220,20 +285,210 @@ impl<K, V, F> RawMap<K, V, F> {
         }
     }
 
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn add(&self, key: K, value: V)
+    where
+        M: RcuKeyMatcher<K>,
+    {
+        let node = RawNode::new(key, value).to_handle::<M>();
+
+        // SAFETY: All pointers are non-null.
+        unsafe { lfht::add(self.handle, node.key_hash, node.handle) };
+    }
+
+    /// Replaces the node matching the key, if any.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    ///
+    /// The caller must wait for a RCU grace period before taking ownership of the old value.
+    pub unsafe fn replace(&self, key: K, value: V) -> *mut RawNode<K, V>
+    where
+        M: RcuKeyMatcher<K>,
+    {
+        let node = RawNode::new(key, value).to_handle::<M>();
+
+        loop {
+            let mut iter = lfht::Iter::default();
+
+            // SAFETY: All pointers are non-null.
+            unsafe {
+                lfht::lookup(
+                    self.handle,
+                    node.key_hash,
+                    Some(key_eq::<K, V, M>),
+                    node.key,
+                    &mut iter,
+                )
+            };
+
+            // SAFETY: The iterator pointer is non-null.
+            let old = unsafe { lfht::iter_get_node(&mut iter) };
+            if old.is_null() {
+                // SAFETY: The node was never published.
+                drop(unsafe { Box::from_raw(RawNode::<K, V>::from_handle(node.handle)) });
+                return std::ptr::null_mut();
+            }
+
+            // SAFETY: All pointers are non-null.
+            let result = unsafe {
+                lfht::replace(
+                    self.handle,
+                    &mut iter,
+                    node.key_hash,
+                    Some(key_eq::<K, V, M>),
+                    node.key,
+                    node.handle,
+                )
+            };
+
+            if result == 0 {
+                // SAFETY: The node pointer is non-null.
+                return unsafe { RawNode::from_handle(old) };
+            }
+
+            // The old node was concurrently removed, look for another one.
+        }
+    }
+
+    /// Replaces the node matching the key with the value returned by `callback`.
+    ///
+    /// The callback is called again if the node is concurrently replaced or removed. No
+    /// node is replaced if the callback returns `None`.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    ///
+    /// The caller must wait for a RCU grace period before taking ownership of the old value.
+    pub unsafe fn update<C>(&self, key: K, mut callback: C) -> *mut RawNode<K, V>
+    where
+        M: RcuKeyMatcher<K>,
+        C: FnMut(&V) -> Option<V>,
+    {
+        let key_hash = hash_of::<K, M>(&key);
+        let mut key = Some(key);
+        let mut pending: Option<Box<RawNode<K, V>>> = None;
+
+        loop {
+            let key_ptr = match (&pending, &key) {
+                (Some(node), _) => &node.key as *const K,
+                (None, Some(key)) => key as *const K,
+                (None, None) => unreachable!(),
+            };
+
+            let mut iter = lfht::Iter::default();
+
+            // SAFETY: All pointers are non-null.
+            unsafe {
+                lfht::lookup(
+                    self.handle,
+                    key_hash,
+                    Some(key_eq::<K, V, M>),
+                    key_ptr as *const c_void,
+                    &mut iter,
+                )
+            };
+
+            // SAFETY: The iterator pointer is non-null.
+            let old = unsafe { lfht::iter_get_node(&mut iter) };
+            if old.is_null() {
+                return std::ptr::null_mut();
+            }
+
+            // SAFETY: The node pointer is non-null.
+            // SAFETY: The node is valid for the duration of the RCU critical section.
+            let old = unsafe { RawNode::<K, V>::from_handle(old) };
+            let Some(value) = callback(unsafe { &old.as_ref_unchecked().value }) else {
+                return std::ptr::null_mut();
+            };
+
+            let node = match pending.take() {
+                None => RawNode::new(key.take().unwrap(), value),
+                Some(mut node) => {
+                    node.value = value;
+                    node
+                }
+            };
+
+            let node = node.to_handle::<M>();
+
+            // SAFETY: All pointers are non-null.
+            let result = unsafe {
+                lfht::replace(
+                    self.handle,
+                    &mut iter,
+                    node.key_hash,
+                    Some(key_eq::<K, V, M>),
+                    node.key,
+                    node.handle,
+                )
+            };
+
+            if result == 0 {
+                return old;
+            }
+
+            // The old node was concurrently replaced or removed, the new node was never
+            // published.
+            pending = Some(unsafe { Box::from_raw(RawNode::from_handle(node.handle)) });
+        }
+    }
+
+    /// Adds a key-value pair if no node matches the key.
+    ///
+    /// Returns the node matching the key. If a node already matched the key, the key-value
+    /// pair is returned back.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn add_unique(&self, key: K, value: V) -> (*mut RawNode<K, V>, Option<(K, V)>)
+    where
+        M: RcuKeyMatcher<K>,
+    {
+        let node = RawNode::new(key, value).to_handle::<M>();
+
+        // SAFETY: All pointers are non-null.
+        let found = unsafe {
+            lfht::add_unique(
+                self.handle,
+                node.key_hash,
+                Some(key_eq::<K, V, M>),
+                node.key,
+                node.handle,
+            )
+        };
+
+        // SAFETY: The node pointers are non-null.
+        let found = unsafe { RawNode::from_handle(found) };
+
+        if found == unsafe { RawNode::from_handle(node.handle) } {
+            (found, None)
+        } else {
+            // SAFETY: The node was never published.
+            let node = unsafe { Box::from_raw(RawNode::<K, V>::from_handle(node.handle)) };
+            (found, Some((node.key, node.value)))
+        }
+    }
+
     /// #### Safety
     ///
     /// The caller must be in a RCU read-side critical section.
     pub unsafe fn lookup(&self, key: &K) -> RawIter<K, V, F>
     where
-        K: Eq + Hash,
+        M: RcuKeyMatcher<K>,
     {
         RawIter::new(self, |iter| {
             // SAFETY: All pointers are non-null.
             unsafe {
                 lfht::lookup(
                     self.handle,
-                    hash_of(key),
-                    Some(key_eq::<K, V>),
+                    hash_of::<K, M>(key),
+                    Some(key_eq::<K, V, M>),
                     key as *const K as *const c_void,
                     iter,
                 );
@@ -251,6 +506,35 @@ impl<K, V, F> RawMap<K, V, F> {
         })
     }
 
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn for_each<C>(&self, mut callback: C)
+    where
+        C: FnMut(&K, &V),
+    {
+        let mut iter = lfht::Iter::default();
+
+        // SAFETY: All pointers are non-null.
+        unsafe { lfht::first(self.handle, &mut iter) };
+
+        loop {
+            // SAFETY: The iterator pointer is non-null.
+            let node = unsafe { lfht::iter_get_node(&mut iter) };
+            if node.is_null() {
+                break;
+            }
+
+            // SAFETY: The node pointer is non-null.
+            // SAFETY: The node is valid for the duration of the RCU critical section.
+            let node = unsafe { RawNode::<K, V>::from_handle(node).as_ref_unchecked() };
+            callback(&node.key, &node.value);
+
+            // SAFETY: All pointers are non-null.
+            unsafe { lfht::next(self.handle, &mut iter) };
+        }
+    }
+
     /// #### Safety
     ///
     /// The caller must be in a RCU read-side critical section.
@@ -292,7 +576,80 @@ impl<K, V, F> RawMap<K, V, F> {
         refs
     }
 
-    pub fn clone(&mut self) -> Self {
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    ///
+    /// The caller must wait for a RCU grace period before taking ownership of the old values.
+    pub unsafe fn del_if<P>(&self, mut predicate: P) -> Vec<NonNull<RawNode<K, V>>>
+    where
+        P: FnMut(&K, &V) -> bool,
+    {
+        let mut iter = self.iter();
+        let mut refs = Vec::new();
+
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        while let Some(node) = unsafe { iter.get().as_ref() } {
+            if predicate(&node.key, &node.value) {
+                NonNull::new(iter.del())
+                    .iter()
+                    .copied()
+                    .for_each(|node| refs.push(node));
+            }
+
+            iter.next();
+        }
+
+        refs
+    }
+
+    /// Returns the approximate count before the traversal, the exact count of the
+    /// traversal and the approximate count after the traversal.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn count_nodes(&self) -> (i64, u64, i64) {
+        let mut approx_before: c_long = 0;
+        let mut count: c_ulong = 0;
+        let mut approx_after: c_long = 0;
+
+        // SAFETY: All pointers are non-null.
+        unsafe {
+            lfht::count_nodes(
+                self.handle,
+                &mut approx_before,
+                &mut count,
+                &mut approx_after,
+            )
+        };
+
+        (approx_before as i64, count as u64, approx_after as i64)
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn count(&self) -> u64 {
+        // SAFETY: The caller is in a RCU read-side critical section.
+        unsafe { self.count_nodes() }.1
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be a read-registered RCU thread.
+    ///
+    /// The caller must not be in a RCU critical section.
+    pub unsafe fn resize(&self, size: u64) {
+        // The largest power of two fitting in an `unsigned long`.
+        let size = c_ulong::try_from(size).unwrap_or(1 << (c_ulong::BITS - 1));
+
+        // SAFETY: The hashmap pointer is non-null.
+        unsafe { lfht::resize(self.handle, size) };
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> Self {
         Self {
             handle: self.handle,
             _unsend: PhantomData,
@@ -313,7 +670,7 @@ impl<K, V, F> RawMap<K, V, F> {
 /// #### Safety
 ///
 /// It is safe to send the wrapper to another thread if the key/value are [`Send`].
-unsafe impl<K, V, F> Send for RawMap<K, V, F>
+unsafe impl<K, V, F, M> Send for RawMap<K, V, F, M>
 where
     K: Send,
     V: Send,
@@ -323,7 +680,7 @@ where
 /// #### Safety
 ///
 /// It is safe to send the wrapper to another thread if the key/value are [`Sync`].
-unsafe impl<K, V, F> Sync for RawMap<K, V, F>
+unsafe impl<K, V, F, M> Sync for RawMap<K, V, F, M>
 where
     K: Sync,
     V: Sync,
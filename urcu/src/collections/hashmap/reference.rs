@@ -1,8 +1,13 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::pin::Pin;
 use std::ptr::NonNull;
 
 use crate::collections::hashmap::raw::RawNode;
 use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::metrics::RcuRemoval;
+use crate::rcu::tracker::{RcuRefTrack, RcuRefTracker};
 use crate::RcuRef;
 
 /// An owned RCU reference to a element removed from an [`RcuHashMap`].
@@ -20,6 +25,95 @@ impl<K, V> RefOwned<K, V> {
     pub fn value(&self) -> &V {
         &self.0.value
     }
+
+    /// Returns a pinned mutable reference to the value of the entry.
+    ///
+    /// The value is never moved out of the reference, it is dropped in place.
+    pub fn value_pin_mut(&mut self) -> Pin<&mut V> {
+        // SAFETY: The value is boxed and never moved until it is dropped.
+        unsafe { Pin::new_unchecked(&mut self.0.value) }
+    }
+
+    /// Moves the key-value pair out of the reference.
+    ///
+    /// #### Note
+    ///
+    /// The value must implement [`Unpin`] since it may have been pinned in the hashmap.
+    pub fn into_inner(self) -> (K, V)
+    where
+        V: Unpin,
+    {
+        let node = *self.0;
+        (node.key, node.value)
+    }
+
+    /// Moves the value out of the reference, dropping the key.
+    ///
+    /// #### Note
+    ///
+    /// The value must implement [`Unpin`] since it may have been pinned in the hashmap.
+    pub fn into_value(self) -> V
+    where
+        V: Unpin,
+    {
+        self.0.value
+    }
+}
+
+impl<K, V> PartialEq for RefOwned<K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.key(), self.value()) == (other.key(), other.value())
+    }
+}
+
+impl<K, V> Eq for RefOwned<K, V>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+impl<K, V> PartialOrd for RefOwned<K, V>
+where
+    K: PartialOrd,
+    V: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.key(), self.value()).partial_cmp(&(other.key(), other.value()))
+    }
+}
+
+impl<K, V> Ord for RefOwned<K, V>
+where
+    K: Ord,
+    V: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.key(), self.value()).cmp(&(other.key(), other.value()))
+    }
+}
+
+impl<K, V> Hash for RefOwned<K, V>
+where
+    K: Hash,
+    V: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.key(), self.value()).hash(state)
+    }
+}
+
+impl<K, V> From<RefOwned<K, V>> for (K, V)
+where
+    V: Unpin,
+{
+    fn from(value: RefOwned<K, V>) -> Self {
+        value.into_inner()
+    }
 }
 
 /// #### Safety
@@ -42,6 +136,8 @@ where
     F: RcuFlavor + 'static,
 {
     ptr: *mut RawNode<K, V>,
+    removal: RcuRemoval,
+    track: RcuRefTrack,
     _context: PhantomData<*const F>,
 }
 
@@ -54,10 +150,18 @@ where
     pub(crate) fn new(ptr: NonNull<RawNode<K, V>>) -> Self {
         Self {
             ptr: ptr.as_ptr(),
+            removal: RcuRemoval::now(),
+            track: RcuRefTrack::default(),
             _context: PhantomData,
         }
     }
 
+    /// Accounts the reference in the tracker of the container that produced it.
+    pub(crate) fn tracked(mut self, tracker: &RcuRefTracker) -> Self {
+        self.track = tracker.track();
+        self
+    }
+
     pub fn key(&self) -> &K {
         // SAFETY: The pointer is never null.
         &unsafe { self.ptr.as_ref_unchecked() }.key
@@ -79,6 +183,8 @@ where
         if !self.ptr.is_null() {
             Self {
                 ptr: self.ptr,
+                removal: self.removal,
+                track: std::mem::take(&mut self.track),
                 _context: Default::default(),
             }
             .safe_cleanup();
@@ -102,6 +208,8 @@ where
 
         // SAFETY: We don't want deferred cleanup when dropping `self`.
         self.ptr = std::ptr::null_mut();
+        self.removal.reclaimed();
+        self.track.release();
 
         output
     }
@@ -114,3 +222,199 @@ where
     F: RcuFlavor,
 {
 }
+
+/// A removed node of an [`RcuHashMap`] whose key type is erased.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+struct ErasedNode<V> {
+    ptr: NonNull<()>,
+    value: NonNull<V>,
+    drop_node: unsafe fn(NonNull<()>),
+    take_value: unsafe fn(NonNull<()>) -> V,
+}
+
+impl<V> ErasedNode<V> {
+    fn new<K>(ptr: NonNull<RawNode<K, V>>) -> Self {
+        unsafe fn drop_node<K, V>(ptr: NonNull<()>) {
+            drop(Box::from_raw(ptr.cast::<RawNode<K, V>>().as_ptr()));
+        }
+
+        unsafe fn take_value<K, V>(ptr: NonNull<()>) -> V {
+            Box::from_raw(ptr.cast::<RawNode<K, V>>().as_ptr()).value
+        }
+
+        Self {
+            ptr: ptr.cast(),
+            // SAFETY: The pointer is never null.
+            value: unsafe { NonNull::new_unchecked(&raw mut (*ptr.as_ptr()).value) },
+            drop_node: drop_node::<K, V>,
+            take_value: take_value::<K, V>,
+        }
+    }
+}
+
+/// An owned RCU reference to the value of an element removed from an [`RcuHashMap`].
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+pub struct RefValueOwned<V>(Option<ErasedNode<V>>);
+
+impl<V> RefValueOwned<V> {
+    fn node(&self) -> &ErasedNode<V> {
+        // SAFETY: The node is only taken when consuming the reference.
+        unsafe { self.0.as_ref().unwrap_unchecked() }
+    }
+
+    /// Returns the value of the entry.
+    pub fn value(&self) -> &V {
+        // SAFETY: The node is owned and alive.
+        unsafe { self.node().value.as_ref() }
+    }
+
+    /// Returns a pinned mutable reference to the value of the entry.
+    ///
+    /// The value is never moved out of the reference, it is dropped in place.
+    pub fn value_pin_mut(&mut self) -> Pin<&mut V> {
+        let mut value = self.node().value;
+
+        // SAFETY: The node is owned and alive.
+        // SAFETY: The value is boxed and never moved until it is dropped.
+        unsafe { Pin::new_unchecked(value.as_mut()) }
+    }
+
+    /// Moves the value out of the reference, dropping the key.
+    ///
+    /// #### Note
+    ///
+    /// The value must implement [`Unpin`] since it may have been pinned in the hashmap.
+    pub fn into_value(mut self) -> V
+    where
+        V: Unpin,
+    {
+        // SAFETY: The node is only taken when consuming the reference.
+        let node = unsafe { self.0.take().unwrap_unchecked() };
+
+        // SAFETY: The node is owned and never used again.
+        unsafe { (node.take_value)(node.ptr) }
+    }
+}
+
+impl<V> Drop for RefValueOwned<V> {
+    fn drop(&mut self) {
+        if let Some(node) = self.0.take() {
+            // SAFETY: The node is owned and never used again.
+            unsafe { (node.drop_node)(node.ptr) };
+        }
+    }
+}
+
+/// #### Safety
+///
+/// It is safe to send to another thread if the underlying `V` is `Send`. The erased
+/// key is always `Send` since it comes from an [`RcuHashMap`].
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+unsafe impl<V: Send> Send for RefValueOwned<V> {}
+
+/// #### Safety
+///
+/// It is safe to have references from multiple threads if the underlying `V` is `Sync`.
+/// The erased key is never borrowed.
+unsafe impl<V: Sync> Sync for RefValueOwned<V> {}
+
+/// An owned RCU reference to the value of an element removed from an [`RcuHashMap`].
+///
+/// Unlike [`Ref`], the key type is erased from the reference.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+pub struct RefValue<V, F>
+where
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    node: Option<ErasedNode<V>>,
+    removal: RcuRemoval,
+    track: RcuRefTrack,
+    _context: PhantomData<*const F>,
+}
+
+impl<V, F> RefValue<V, F>
+where
+    V: Send,
+    F: RcuFlavor,
+{
+    pub(crate) fn new<K>(ptr: NonNull<RawNode<K, V>>) -> Self
+    where
+        K: Send + 'static,
+    {
+        Self {
+            node: Some(ErasedNode::new(ptr)),
+            removal: RcuRemoval::now(),
+            track: RcuRefTrack::default(),
+            _context: PhantomData,
+        }
+    }
+
+    /// Accounts the reference in the tracker of the container that produced it.
+    pub(crate) fn tracked(mut self, tracker: &RcuRefTracker) -> Self {
+        self.track = tracker.track();
+        self
+    }
+
+    pub fn value(&self) -> &V {
+        // SAFETY: The node is only taken when the reference is consumed.
+        let node = unsafe { self.node.as_ref().unwrap_unchecked() };
+
+        // SAFETY: The pointer is never null.
+        unsafe { node.value.as_ref() }
+    }
+}
+
+impl<V, F> Drop for RefValue<V, F>
+where
+    V: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    fn drop(&mut self) {
+        if let Some(node) = self.node.take() {
+            Self {
+                node: Some(node),
+                removal: self.removal,
+                track: std::mem::take(&mut self.track),
+                _context: Default::default(),
+            }
+            .safe_cleanup();
+        }
+    }
+}
+
+/// #### Safety
+///
+/// The memory reclamation upon dropping is properly deferred after the RCU grace period.
+unsafe impl<V, F> RcuRef<F> for RefValue<V, F>
+where
+    V: Send,
+    F: RcuFlavor,
+{
+    type Output = RefValueOwned<V>;
+
+    unsafe fn take_ownership_unchecked(mut self) -> Self::Output {
+        // SAFETY: We don't want deferred cleanup when dropping `self`.
+        let output = RefValueOwned(self.node.take());
+        self.removal.reclaimed();
+        self.track.release();
+
+        output
+    }
+}
+
+/// #### Safety
+///
+/// The erased key is always `Send` since it comes from an [`RcuHashMap`].
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+unsafe impl<V, F> Send for RefValue<V, F>
+where
+    V: Send,
+    F: RcuFlavor,
+{
+}
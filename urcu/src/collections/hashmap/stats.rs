@@ -0,0 +1,88 @@
+use urcu_cds_sys::lfht;
+
+use crate::collections::hashmap::attr::RcuHashMapMemory;
+use crate::collections::hashmap::raw::RawMapBuckets;
+
+/// Defines the occupancy statistics of an [`RcuHashMap`].
+///
+/// #### Note
+///
+/// The number of buckets resized automatically by `liburcu` is not exposed by the
+/// library. Only the resizes requested through [`RcuHashMap::reserve`] and
+/// [`RcuHashMap::shrink_to`] are reported.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+/// [`RcuHashMap::reserve`]: crate::collections::hashmap::container::RcuHashMap::reserve
+/// [`RcuHashMap::shrink_to`]: crate::collections::hashmap::container::RcuHashMap::shrink_to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RcuHashMapStats {
+    /// The approximate number of entries from the split counters before counting.
+    pub approx_before: i64,
+    /// The number of entries counted by traversing the table.
+    pub count: usize,
+    /// The approximate number of entries from the split counters after counting.
+    pub approx_after: i64,
+    /// The number of resizes requested on the table.
+    pub resize_requests: u64,
+    /// The number of buckets of the last requested resize, or zero if none.
+    pub requested_buckets: u64,
+}
+
+/// Defines the approximate memory usage of an [`RcuHashMap`].
+///
+/// #### Note
+///
+/// The number of buckets is estimated since `liburcu` does not expose it. A table
+/// with automatic resizes is assumed to hold about one bucket per entry, otherwise
+/// the initial or last requested number of buckets is used. The address space
+/// reserved by [`RcuHashMapMemory::Mmap`] is not included.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RcuHashMapMemoryUsage {
+    /// The number of nodes counted by traversing the table.
+    pub nodes: usize,
+    /// The bytes allocated for the nodes.
+    pub node_bytes: usize,
+    /// The estimated number of allocated buckets.
+    pub buckets: usize,
+    /// The estimated bytes allocated for the buckets and their index.
+    pub bucket_bytes: usize,
+}
+
+impl RcuHashMapMemoryUsage {
+    pub(crate) fn estimate(
+        nodes: usize,
+        node_size: usize,
+        size: usize,
+        bounds: RawMapBuckets,
+        memory: RcuHashMapMemory,
+    ) -> Self {
+        let min_alloc = bounds.min_alloc.max(1) as usize;
+        let mut buckets = size.max(min_alloc);
+        if bounds.max != 0 {
+            buckets = buckets.min(bounds.max as usize);
+        }
+
+        let mut bucket_bytes = 0;
+        if memory == RcuHashMapMemory::Chunk {
+            // The buckets are allocated by chunks and the chunk index is preallocated.
+            buckets = buckets.div_ceil(min_alloc) * min_alloc;
+            bucket_bytes += (bounds.max as usize / min_alloc) * size_of::<*mut lfht::Node>();
+        }
+
+        bucket_bytes += buckets * size_of::<lfht::Node>();
+
+        Self {
+            nodes,
+            node_bytes: nodes * node_size,
+            buckets,
+            bucket_bytes,
+        }
+    }
+
+    /// Returns the total estimated bytes allocated for the hashmap.
+    pub fn total_bytes(&self) -> usize {
+        self.node_bytes + self.bucket_bytes
+    }
+}
@@ -1,3 +1,6 @@
+use std::sync::Arc;
+
+use crate::collections::hashmap::attr::RcuHashMapMemory;
 use crate::collections::hashmap::container::RcuHashMap;
 use crate::rcu::context::RcuReadContext;
 use crate::rcu::default::RcuDefaultFlavor;
@@ -182,3 +185,761 @@ fn iter() {
     hashmap.remove(&5837, &guard).call_cleanup(&context);
     assert_sorted_eq!(hashmap.iter(&guard).collect::<Vec<_>>(), vec![]);
 }
+
+#[test]
+fn reserve() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    hashmap.insert(4812, 1750, &guard).call_cleanup(&context);
+    hashmap.reserve(1024, &guard);
+
+    for key in 0..1024 {
+        hashmap.insert(key, key * 2, &guard).call_cleanup(&context);
+    }
+
+    assert_eq!(hashmap.get(&4812, &guard), Some(&1750));
+    assert_eq!(hashmap.get(&0, &guard), Some(&0));
+    assert_eq!(hashmap.get(&1023, &guard), Some(&2046));
+    assert_eq!(hashmap.iter(&guard).count(), 1025);
+}
+
+#[test]
+fn shrink_to() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    for key in 0..1024 {
+        hashmap.insert(key, key * 2, &guard).call_cleanup(&context);
+    }
+
+    hashmap
+        .split_by(|key, _| *key >= 16, &guard)
+        .call_cleanup(&context);
+    hashmap.shrink_to(0, &guard);
+
+    assert_eq!(hashmap.get(&15, &guard), Some(&30));
+    assert_eq!(hashmap.get(&16, &guard), None);
+    assert_eq!(hashmap.iter(&guard).count(), 16);
+}
+
+#[test]
+fn for_each() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    let mut entries = Vec::new();
+    hashmap.for_each(&guard, |key, value| entries.push((*key, *value)));
+    assert_sorted_eq!(entries, vec![]);
+
+    hashmap.insert(3129, 8866, &guard).call_cleanup(&context);
+    hashmap.insert(6570, 1093, &guard).call_cleanup(&context);
+    hashmap.insert(4021, 7712, &guard).call_cleanup(&context);
+
+    let mut entries = Vec::new();
+    hashmap.for_each(&guard, |key, value| entries.push((*key, *value)));
+    assert_sorted_eq!(entries, vec![(3129, 8866), (6570, 1093), (4021, 7712)]);
+
+    hashmap.remove(&6570, &guard).call_cleanup(&context);
+
+    let mut entries = Vec::new();
+    hashmap.for_each(&guard, |key, value| entries.push((*key, *value)));
+    assert_sorted_eq!(entries, vec![(3129, 8866), (4021, 7712)]);
+}
+
+#[test]
+fn partition() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let other = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    for key in 0..8 {
+        hashmap.insert(key, key * 10, &guard).call_cleanup(&context);
+    }
+
+    let moved = hashmap.partition_into(&other, |key, _| key % 2 == 1, &guard);
+    assert_eq!(moved.len(), 4);
+    assert!(hashmap
+        .partition_into(&hashmap, |_, _| true, &guard)
+        .is_empty());
+
+    let mut entries = Vec::new();
+    other.for_each(&guard, |key, value| entries.push((*key, *value)));
+    assert_sorted_eq!(entries, vec![(1, 10), (3, 30), (5, 50), (7, 70)]);
+
+    let removed = hashmap.split_by(|_, value| *value >= 40, &guard);
+    assert_eq!(removed.len(), 2);
+
+    let mut entries = Vec::new();
+    hashmap.for_each(&guard, |key, value| entries.push((*key, *value)));
+    assert_sorted_eq!(entries, vec![(0, 0), (2, 20)]);
+
+    drop(guard);
+
+    let mut removed = removed
+        .take_ownership(&mut context)
+        .into_iter()
+        .map(|entry| *entry.key())
+        .collect::<Vec<_>>();
+    removed.sort();
+    assert_eq!(removed, vec![4, 6]);
+
+    moved.call_cleanup(&context);
+}
+
+#[test]
+fn dedicated_cleaner() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::builder()
+        .with_dedicated_cleaner()
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    hashmap.insert(1634, 8104, &guard).call_cleanup(&context);
+    hashmap.insert(5282, 3926, &guard).call_cleanup(&context);
+    hashmap.reserve(64, &guard);
+
+    assert_eq!(hashmap.get(&1634, &guard), Some(&8104));
+    assert_eq!(hashmap.get(&5282, &guard), Some(&3926));
+
+    drop(guard);
+    drop(hashmap);
+}
+
+#[cfg(feature = "flavor-qsbr")]
+#[test]
+fn dedicated_cleaner_qsbr() {
+    use std::time::Duration;
+
+    use crate::rcu::flavor::RcuFlavorQsbr;
+
+    let mut context = RcuFlavorQsbr::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let value = Arc::new(());
+    let hashmap = RcuHashMap::<u32, Arc<()>, RcuFlavorQsbr>::builder()
+        .with_dedicated_cleaner()
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+    hashmap
+        .insert(2913, value.clone(), &guard)
+        .call_cleanup(&context);
+    drop(guard);
+
+    // The dedicated cleaner must not be joined from the shared cleanup thread.
+    drop(hashmap);
+
+    while Arc::strong_count(&value) > 1 {
+        context.rcu_thread_offline(|_| std::thread::sleep(Duration::from_millis(1)));
+    }
+}
+
+#[test]
+fn worker_attr() {
+    use crate::collections::hashmap::attr::RcuHashMapWorkerAttr;
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let attr = RcuHashMapWorkerAttr::new()
+        .unwrap()
+        .with_stack_size(1 << 20)
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::builder()
+        .with_worker_attr(attr)
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    // A large resize is partitioned between multiple worker threads.
+    hashmap.reserve(1 << 16, &guard);
+
+    for key in 0..4096 {
+        hashmap.insert(key, key * 2, &guard).call_cleanup(&context);
+    }
+
+    assert_eq!(hashmap.get(&4095, &guard), Some(&8190));
+
+    drop(guard);
+    drop(hashmap);
+}
+
+#[test]
+fn pinned() {
+    use std::marker::PhantomPinned;
+
+    struct Pinned(u32, PhantomPinned);
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, Pinned>::new().unwrap();
+
+    let guard = context.rcu_read_lock();
+    hashmap
+        .insert(1, Pinned(10, PhantomPinned), &guard)
+        .call_cleanup(&context);
+
+    assert_eq!(
+        hashmap.get_pinned(&1, &guard).map(|value| value.0),
+        Some(10)
+    );
+    assert!(hashmap.get_pinned(&2, &guard).is_none());
+
+    let removed = hashmap.remove(&1, &guard).unwrap();
+    drop(guard);
+
+    let mut removed = removed.take_ownership(&mut context);
+    assert_eq!(removed.value_pin_mut().0, 10);
+}
+
+#[test]
+fn key_matcher() {
+    use crate::collections::hashmap::matcher::{RcuDefaultKeyMatcher, RcuKeyMatcher};
+
+    struct CaseInsensitive;
+
+    impl RcuKeyMatcher<String> for CaseInsensitive {
+        fn hash(key: &String) -> u64 {
+            RcuDefaultKeyMatcher::hash(&key.to_lowercase())
+        }
+
+        fn matches(left: &String, right: &String) -> bool {
+            left.eq_ignore_ascii_case(right)
+        }
+    }
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<String, u32>::builder()
+        .with_key_matcher::<CaseInsensitive>()
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    hashmap
+        .insert("Key".to_owned(), 10, &guard)
+        .call_cleanup(&context);
+
+    assert!(hashmap.contains(&"KEY".to_owned(), &guard));
+    assert_eq!(hashmap.get(&"key".to_owned(), &guard), Some(&10));
+
+    hashmap
+        .insert("kEy".to_owned(), 20, &guard)
+        .call_cleanup(&context);
+
+    assert_eq!(hashmap.iter(&guard).count(), 1);
+    assert_eq!(hashmap.get(&"KEY".to_owned(), &guard), Some(&20));
+}
+
+#[test]
+fn owned_into_inner() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, String>::new().unwrap();
+
+    let guard = context.rcu_read_lock();
+    hashmap
+        .insert(1, "one".to_owned(), &guard)
+        .call_cleanup(&context);
+    hashmap
+        .insert(2, "two".to_owned(), &guard)
+        .call_cleanup(&context);
+    let refs = (
+        hashmap.remove(&1, &guard).unwrap(),
+        hashmap.remove(&2, &guard).unwrap(),
+    );
+    drop(guard);
+
+    let (first, second) = refs.take_ownership(&mut context);
+    assert!(first < second);
+
+    let (key, value) = first.into();
+    assert_eq!((key, value.as_str()), (1, "one"));
+    assert_eq!(second.into_inner(), (2, "two".to_owned()));
+}
+
+#[test]
+fn contains_with() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+
+    assert!(!hashmap.contains_with(&context, &7719));
+
+    let guard = context.rcu_read_lock();
+    hashmap.insert(7719, 2245, &guard).call_cleanup(&context);
+    drop(guard);
+
+    assert!(hashmap.contains_with(&context, &7719));
+    assert!(!hashmap.contains_with(&context, &2245));
+}
+
+#[test]
+fn expiring() {
+    use std::time::Duration;
+
+    use crate::collections::hashmap::expiring::RcuExpiringHashMap;
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuExpiringHashMap::<u32, u32>::new(Duration::from_secs(3600)).unwrap();
+    let guard = context.rcu_read_lock();
+
+    hashmap.insert(4823, 1209, &guard).call_cleanup(&context);
+    hashmap
+        .insert_with_ttl(7130, 5521, Duration::ZERO, &guard)
+        .call_cleanup(&context);
+
+    assert_eq!(hashmap.get(&4823, &guard), Some(&1209));
+    assert_eq!(hashmap.get(&7130, &guard), None);
+    assert!(hashmap.contains(&4823, &guard));
+    assert!(!hashmap.contains(&7130, &guard));
+    assert_eq!(
+        hashmap.iter(&guard).collect::<Vec<_>>(),
+        vec![(&4823, &1209)]
+    );
+    drop(guard);
+
+    hashmap.purge();
+    RcuDefaultFlavor::rcu_cleanup_and_block(Box::new(|_| {}));
+
+    let guard = context.rcu_read_lock();
+    assert!(hashmap.remove(&7130, &guard).is_none());
+    assert!(hashmap.remove(&4823, &guard).is_some());
+    drop(guard);
+}
+
+#[test]
+fn multimap() {
+    use crate::collections::hashmap::multimap::RcuMultiMap;
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuMultiMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    hashmap.insert(3307, 1, &guard);
+    hashmap.insert(3307, 2, &guard);
+    hashmap.insert(3307, 2, &guard);
+    hashmap.insert(8142, 3, &guard);
+
+    assert!(hashmap.contains(&3307, &guard));
+    assert!(!hashmap.contains(&5590, &guard));
+    assert_sorted_eq!(
+        hashmap.get_all(&3307, &guard).copied().collect::<Vec<_>>(),
+        vec![1, 2, 2]
+    );
+    assert_eq!(hashmap.get_all(&5590, &guard).count(), 0);
+    assert_eq!(hashmap.iter(&guard).count(), 4);
+
+    let removed = hashmap.remove_all(&3307, &guard);
+    assert_eq!(removed.len(), 3);
+    assert!(!hashmap.contains(&3307, &guard));
+    assert_eq!(hashmap.get_all(&8142, &guard).collect::<Vec<_>>(), vec![&3]);
+    drop(guard);
+
+    let mut values = removed
+        .take_ownership(&mut context)
+        .into_iter()
+        .map(|pair| *pair.value())
+        .collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(values, vec![1, 2, 2]);
+}
+
+#[test]
+fn len() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert!(hashmap.is_empty(&guard));
+    assert_eq!(hashmap.len(&guard), 0);
+
+    for key in 0..37 {
+        hashmap.insert(key, key, &guard).call_cleanup(&context);
+    }
+
+    hashmap.remove(&12, &guard).call_cleanup(&context);
+
+    assert!(!hashmap.is_empty(&guard));
+    assert_eq!(hashmap.len(&guard), 36);
+    assert_eq!(hashmap.len_approx(&guard).1, 36);
+}
+
+#[test]
+fn with_capacity() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    assert!(RcuHashMap::<u32, u32>::with_capacity(64, 128, 32).is_err());
+
+    let hashmap = RcuHashMap::<u32, u32>::with_capacity(1000, 16, 0).unwrap();
+    let guard = context.rcu_read_lock();
+
+    for key in 0..1000 {
+        hashmap.insert(key, key, &guard).call_cleanup(&context);
+    }
+
+    assert_eq!(hashmap.len(&guard), 1000);
+    assert_eq!(hashmap.get(&647, &guard), Some(&647));
+}
+
+#[test]
+fn with_memory() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    assert!(RcuHashMap::<u32, u32>::builder()
+        .with_memory(RcuHashMapMemory::Mmap)
+        .build()
+        .is_err());
+
+    for memory in [
+        RcuHashMapMemory::Order,
+        RcuHashMapMemory::Chunk,
+        RcuHashMapMemory::Mmap,
+    ] {
+        let hashmap = RcuHashMap::<u32, u32>::builder()
+            .with_buckets(1, 1, 4096)
+            .with_memory(memory)
+            .build()
+            .unwrap();
+
+        let guard = context.rcu_read_lock();
+
+        for key in 0..100 {
+            hashmap.insert(key, key * 2, &guard).call_cleanup(&context);
+        }
+
+        assert_eq!(hashmap.get(&37, &guard), Some(&74));
+        assert_eq!(hashmap.len(&guard), 100);
+    }
+}
+
+#[test]
+fn fixed_size() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::builder()
+        .with_buckets(64, 64, 64)
+        .with_auto_resize(false)
+        .with_accounting(false)
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    for key in 0..500 {
+        hashmap.insert(key, key, &guard).call_cleanup(&context);
+    }
+
+    assert_eq!(hashmap.get(&271, &guard), Some(&271));
+    assert_eq!(hashmap.len_approx(&guard), (0, 500, 0));
+}
+
+#[test]
+fn try_insert() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(hashmap.try_insert(5170, 2384, &guard), Ok(&2384));
+    assert_eq!(hashmap.try_insert(5170, 7709, &guard), Err((5170, 7709)));
+    assert_eq!(hashmap.try_insert(9162, 7709, &guard), Ok(&7709));
+    assert_eq!(hashmap.get(&5170, &guard), Some(&2384));
+}
+
+#[test]
+fn replace() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert!(hashmap.replace(4413, 1, &guard).is_none());
+    assert!(!hashmap.contains(&4413, &guard));
+
+    hashmap.insert(4413, 1, &guard).call_cleanup(&context);
+    let old = hashmap.replace(4413, 2, &guard).unwrap();
+    assert_eq!(hashmap.get(&4413, &guard), Some(&2));
+    drop(guard);
+
+    assert_eq!(old.take_ownership(&mut context).into_inner(), (4413, 1));
+}
+
+#[test]
+fn update() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert!(hashmap
+        .update(6120, |value| Some(value + 1), &guard)
+        .is_none());
+
+    hashmap.insert(6120, 41, &guard).call_cleanup(&context);
+    assert!(hashmap.update(6120, |_| None, &guard).is_none());
+    assert_eq!(hashmap.get(&6120, &guard), Some(&41));
+
+    let old = hashmap
+        .update(6120, |value| Some(value + 1), &guard)
+        .unwrap();
+    assert_eq!(hashmap.get(&6120, &guard), Some(&42));
+    drop(guard);
+
+    assert_eq!(old.take_ownership(&mut context).into_inner(), (6120, 41));
+}
+
+#[test]
+fn debug() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, &str>::new().unwrap();
+    let guard = context.rcu_read_lock();
+    assert_eq!(format!("{:?}", hashmap.debug(&guard)), "{}");
+
+    hashmap.insert(7314, "alpha", &guard).call_cleanup(&context);
+    assert_eq!(format!("{:?}", hashmap.debug(&guard)), "{7314: \"alpha\"}");
+}
+
+#[test]
+fn get_or_insert_with() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(hashmap.get_or_insert_with(2051, || 6690, &guard), &6690);
+    assert_eq!(
+        hashmap.get_or_insert_with(2051, || unreachable!(), &guard),
+        &6690
+    );
+    assert_eq!(hashmap.get(&2051, &guard), Some(&6690));
+}
+
+#[test]
+fn stats() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    for key in 0..20 {
+        hashmap.insert(key, key, &guard).call_cleanup(&context);
+    }
+
+    hashmap.reserve(100, &guard);
+
+    let stats = hashmap.stats(&guard);
+    assert_eq!(stats.count, 20);
+    assert_eq!(stats.resize_requests, 1);
+    assert_eq!(stats.requested_buckets, 128);
+}
+
+#[test]
+fn close_blocking() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let value = Arc::new(());
+    let hashmap = RcuHashMap::<u32, Arc<()>>::builder()
+        .with_dedicated_cleaner()
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+    hashmap
+        .insert(4729, value.clone(), &guard)
+        .call_cleanup(&context);
+    drop(guard);
+
+    let other = hashmap.clone();
+    let hashmap = hashmap.close_blocking().unwrap_err();
+    drop(other);
+
+    assert!(hashmap.close_blocking().is_ok());
+    assert_eq!(Arc::strong_count(&value), 1);
+}
+
+#[test]
+fn remove_value() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, String>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    hashmap
+        .insert(8317, "value".to_owned(), &guard)
+        .call_cleanup(&context);
+
+    let removed = hashmap.remove_value(&8317, &guard).unwrap();
+    assert_eq!(removed.value(), "value");
+    assert!(hashmap.remove_value(&8317, &guard).is_none());
+    assert!(!hashmap.contains(&8317, &guard));
+
+    drop(guard);
+
+    let value = removed.take_ownership(&mut context).into_value();
+    assert_eq!(value, "value");
+}
+
+#[test]
+fn insert_with() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    hashmap.insert_with(&context, 5903, 1187);
+    hashmap.insert_with(&context, 5903, 2641);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(hashmap.get(&5903, &guard), Some(&2641));
+}
+
+#[test]
+fn first() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(hashmap.first(&guard), None);
+
+    for key in 0..4 {
+        hashmap.insert(key, key * 3, &guard).call_cleanup(&context);
+    }
+
+    let mut drained = Vec::new();
+    while let Some((key, value)) = hashmap.first(&guard) {
+        assert_eq!(*value, key * 3);
+        drained.push(*key);
+        hashmap.remove(key, &guard).call_cleanup(&context);
+    }
+
+    drained.sort();
+    assert_eq!(drained, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn memory_usage() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuHashMap::<u32, u64>::builder()
+        .with_buckets(16, 16, 0)
+        .with_auto_resize(false)
+        .build()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    for key in 0..10 {
+        hashmap.insert(key, 0, &guard).call_cleanup(&context);
+    }
+
+    let usage = hashmap.memory_usage(&guard);
+    assert_eq!(usage.nodes, 10);
+    assert_eq!(usage.buckets, 16);
+    assert!(usage.node_bytes >= 10 * (size_of::<u32>() + size_of::<u64>()));
+    assert_eq!(usage.total_bytes(), usage.node_bytes + usage.bucket_bytes);
+
+    hashmap.reserve(100, &guard);
+    assert_eq!(hashmap.memory_usage(&guard).buckets, 128);
+}
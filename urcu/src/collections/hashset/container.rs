@@ -0,0 +1,137 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::collections::hashmap::builder::RcuHashMapBuilder;
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::hashset::iterator::Iter;
+use crate::collections::hashset::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::{RcuGuard, RcuReadContext};
+
+/// Defines a RCU lock-free hashset.
+///
+/// This hashset supports multiple concurrents readers and writers. It is guaranteed
+/// to never block on a call. It shares the hash table implementation of [`RcuHashMap`].
+///
+/// # Limitations
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a node's data, it is impossible
+/// to get a mutable references to the data inside the set. You should design the
+/// type stored in the set with [interior mutabillity] that can be shared between
+/// threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuHashSet<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuHashSet<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing an
+/// RCU reference.
+pub struct RcuHashSet<T, F = RcuDefaultFlavor>
+where
+    T: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    map: RcuHashMap<T, (), F>,
+}
+
+impl<T, F> RcuHashSet<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU hashset.
+    pub fn new() -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            map: RcuHashMap::create(RcuHashMapBuilder::new())?,
+        }))
+    }
+
+    /// Adds a value to the hashset.
+    ///
+    /// If the hashset already had an equal value, it is replaced and returned.
+    pub fn insert<G>(&self, value: T, guard: &G) -> Option<Ref<T, F>>
+    where
+        T: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.insert(value, (), guard).map(Ref::new)
+    }
+
+    /// Returns `true` if the hashset contains the value.
+    pub fn contains<G>(&self, value: &T, guard: &G) -> bool
+    where
+        T: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.contains(value, guard)
+    }
+
+    /// Returns `true` if the hashset contains the value.
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn contains_with<C>(&self, context: &C, value: &T) -> bool
+    where
+        T: Eq + Hash,
+        C: RcuReadContext<Flavor = F>,
+    {
+        self.contains(value, &context.rcu_read_lock())
+    }
+
+    /// Returns a reference to the value in the hashset equal to the given one.
+    pub fn get<'me, 'guard, G>(&'me self, value: &T, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        T: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.get_key_value(value, guard).map(|(value, _)| value)
+    }
+
+    /// Removes a value from the hashset, returning it if successful.
+    pub fn remove<G>(&self, value: &T, guard: &G) -> Option<Ref<T, F>>
+    where
+        T: Eq + Hash,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.remove(value, guard).map(Ref::new)
+    }
+
+    /// Returns the number of values in the hashset.
+    ///
+    /// #### Note
+    ///
+    /// This operation computes linearly in *O*(*n*) time. The count may not include the
+    /// concurrent insertions and removals.
+    pub fn len<G>(&self, guard: &G) -> usize
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.len(guard)
+    }
+
+    /// Returns `true` if the hashset contains no values.
+    pub fn is_empty<G>(&self, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.map.is_empty(guard)
+    }
+
+    /// Returns an iterator visiting all values in arbitrary order.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, T, F>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        Iter::new(self.map.iter(guard))
+    }
+}
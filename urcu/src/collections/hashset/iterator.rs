@@ -0,0 +1,22 @@
+use crate::collections::hashmap;
+
+/// An iterator over the values of an [`RcuHashSet`].
+///
+/// [`RcuHashSet`]: crate::collections::hashset::container::RcuHashSet
+pub struct Iter<'guard, T, F>(hashmap::Iter<'guard, T, (), F>)
+where
+    T: 'guard;
+
+impl<'guard, T, F> Iter<'guard, T, F> {
+    pub(crate) fn new(iter: hashmap::Iter<'guard, T, (), F>) -> Self {
+        Self(iter)
+    }
+}
+
+impl<'guard, T, F> Iterator for Iter<'guard, T, F> {
+    type Item = &'guard T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(value, _)| value)
+    }
+}
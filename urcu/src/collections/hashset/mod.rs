@@ -0,0 +1,59 @@
+pub(crate) mod container;
+pub(crate) mod iterator;
+pub(crate) mod reference;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::hashset::iterator::*;
+pub use crate::collections::hashset::reference::*;
+
+mod asserts {
+    use super::*;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::hashset::container::RcuHashSet;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    mod rcu_hashset {
+        use super::*;
+
+        // T: Send + Sync
+        assert_impl_all!(RcuHashSet<SendAndSync>: Send);
+        assert_impl_all!(RcuHashSet<SendAndSync>: Sync);
+    }
+
+    mod rcu_hashset_ref {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(Ref<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Ref<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(Ref<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Ref<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_hashset_ref_owned {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(RefOwned<SendButNotSync>: Send);
+        assert_not_impl_all!(RefOwned<SendButNotSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RefOwned<SendAndSync>: Send);
+        assert_impl_all!(RefOwned<SendAndSync>: Sync);
+    }
+
+    mod rcu_hashset_iter {
+        use super::*;
+
+        // T: Send + Sync
+        assert_not_impl_all!(Iter<'_, SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Iter<'_, SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+}
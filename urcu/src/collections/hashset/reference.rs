@@ -0,0 +1,74 @@
+use std::ops::Deref;
+
+use crate::collections::hashmap;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+/// An owned RCU reference to a element removed from an [`RcuHashSet`].
+///
+/// [`RcuHashSet`]: crate::collections::hashset::container::RcuHashSet
+pub struct RefOwned<T>(hashmap::RefOwned<T, ()>);
+
+impl<T> RefOwned<T> {
+    /// Moves the value out of the reference.
+    pub fn into_inner(self) -> T {
+        self.0.into_inner().0
+    }
+}
+
+impl<T> Deref for RefOwned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.key()
+    }
+}
+
+/// An RCU reference to a element removed from an [`RcuHashSet`].
+///
+/// #### Requirements
+///
+/// `T` must be [`Send`] because [`Drop::drop`] might execute cleanup in another thread.
+///
+/// [`RcuHashSet`]: crate::collections::hashset::container::RcuHashSet
+pub struct Ref<T, F>(hashmap::Ref<T, (), F>)
+where
+    T: Send + 'static,
+    F: RcuFlavor + 'static;
+
+impl<T, F> Ref<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    pub(crate) fn new(inner: hashmap::Ref<T, (), F>) -> Self {
+        Self(inner)
+    }
+}
+
+/// #### Safety
+///
+/// The memory reclamation is delegated to the hashmap reference.
+unsafe impl<T, F> RcuRef<F> for Ref<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    type Output = RefOwned<T>;
+
+    unsafe fn take_ownership_unchecked(self) -> Self::Output {
+        RefOwned(self.0.take_ownership_unchecked())
+    }
+}
+
+impl<T, F> Deref for Ref<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.key()
+    }
+}
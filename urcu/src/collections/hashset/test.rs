@@ -0,0 +1,38 @@
+use crate::collections::hashset::container::RcuHashSet;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn simple() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let set = RcuHashSet::<u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert!(!set.contains(&10, &guard));
+    assert!(set.insert(10, &guard).is_none());
+    assert!(set.insert(20, &guard).is_none());
+    assert_eq!(set.insert(10, &guard).as_deref(), Some(&10));
+
+    assert!(set.contains(&10, &guard));
+    assert!(set.contains(&20, &guard));
+    assert!(!set.contains(&30, &guard));
+    assert_eq!(set.get(&20, &guard), Some(&20));
+
+    let mut values = set.iter(&guard).copied().collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(values, vec![10, 20]);
+
+    let removed = set.remove(&10, &guard).unwrap();
+    assert!(set.remove(&10, &guard).is_none());
+    assert!(!set.contains(&10, &guard));
+    drop(guard);
+
+    assert_eq!(removed.take_ownership(&mut context).into_inner(), 10);
+    assert!(set.contains_with(&context, &20));
+}
@@ -0,0 +1,211 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::hlist::iterator::Iter;
+use crate::collections::hlist::raw::{RawHList, RawIter, RawNode};
+use crate::collections::hlist::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU singly linked hash list.
+///
+/// This linked list supports multiple concurrents readers at any time, but only a single
+/// writer at a time. The list uses an internal lock for writing operations.
+///
+/// Its head is a single pointer, which makes it suited for small chains such as the
+/// buckets of a hash table.
+///
+/// # Limitations
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a node's data, it is impossible
+/// to get a mutable references to the data inside the linked list. You should design
+/// the type stored in the list with [interior mutabillity] that can be shared between
+/// threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// ##### Traversal
+///
+/// Readers can only traverse the list from front to back. Removing an element other
+/// than the front element is linear in time.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuHList<T>>` to a non-registered RCU thread. A non-registered
+/// thread may drop an `RcuHList<T>` without calling any RCU primitives since lifetime rules
+/// prevent any other thread from accessing a RCU reference.
+pub struct RcuHList<T, F = RcuDefaultFlavor> {
+    raw: RawHList<T>,
+    mutex: Mutex<()>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuHList<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU hash list.
+    pub fn new() -> Arc<Self> {
+        let mut list = Arc::new(RcuHList {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawHList::new() },
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuHList"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw list is in a stable memory location.
+        // SAFETY: All the nodes are removed upon dropping.
+        unsafe { Arc::<Self>::get_mut(&mut list).unwrap().raw.init() };
+
+        list
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce() -> R,
+    {
+        // The nodes are linked and unlinked with a single store, so the list is still
+        // consistent if a writer panics while holding the lock.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback();
+        drop(guard);
+        result
+    }
+
+    /// Adds an element to the front of the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_front(&self, data: T)
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            unsafe { self.raw.insert_front(RawNode::new(data)) };
+        })
+    }
+
+    /// Removes the element at the front of the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_front(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let node = unsafe { self.raw.remove_front() };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Removes the first element matching a predicate.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn remove<P>(&self, predicate: P) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        P: FnMut(&T) -> bool,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let node = unsafe { self.raw.remove_if(predicate) };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Returns `true` if the list contains an element equal to the given value.
+    pub fn contains<G>(&self, x: &T, guard: &G) -> bool
+    where
+        T: PartialEq,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter(guard).any(|item| item == x)
+    }
+
+    /// Returns `true` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*1*) time.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is empty.
+    pub fn front<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The node pointer can be converted to a reference.
+        unsafe { self.raw.get_front().as_ref() }.map(|r| r.deref())
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all items from front to back.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, T, G>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU critical section is enforced.
+        Iter::new(unsafe { RawIter::from_front(&self.raw) }, guard)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuHList`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuHList<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuHList`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuHList<T, F>
+where
+    T: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuHList<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The RCU grace period is not needed because there are no other readers.
+        while let Some(mut ptr) = NonNull::new(unsafe { self.raw.remove_front() }) {
+            drop(unsafe { Box::from_raw(ptr.as_mut()) });
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::ops::Deref;
+
+use crate::collections::hlist::raw::RawIter;
+use crate::rcu::guard::RcuGuard;
+
+/// An iterator over the nodes of an [`RcuHList`].
+///
+/// [`RcuHList`]: crate::collections::hlist::container::RcuHList
+pub struct Iter<'guard, T, G>
+where
+    G: RcuGuard,
+{
+    raw: RawIter<T>,
+    #[allow(dead_code)]
+    guard: &'guard G,
+}
+
+impl<'guard, T, G> Iter<'guard, T, G>
+where
+    G: RcuGuard,
+{
+    pub(crate) fn new(raw: RawIter<T>, guard: &'guard G) -> Self {
+        Self { raw, guard }
+    }
+}
+
+impl<'guard, T, G> Iterator for Iter<'guard, T, G>
+where
+    Self: 'guard,
+    G: RcuGuard,
+{
+    type Item = &'guard T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The RCU critical section is enforced.
+        unsafe { self.raw.next().as_ref() }.map(|node| node.deref())
+    }
+}
@@ -0,0 +1,72 @@
+pub(crate) mod container;
+pub(crate) mod iterator;
+pub(crate) mod raw;
+pub(crate) mod reference;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::hlist::iterator::*;
+pub use crate::collections::hlist::reference::*;
+
+mod asserts {
+    use super::*;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::hlist::container::RcuHList;
+    use crate::rcu::default::{RcuDefaultFlavor, RcuDefaultGuard};
+    use crate::utility::asserts::*;
+
+    mod rcu_hlist {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuHList<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuHList<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuHList<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuHList<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuHList<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuHList<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuHList<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuHList<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_hlist_ref {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(Ref<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Ref<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(Ref<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Ref<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_hlist_ref_owned {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(RefOwned<SendButNotSync>: Send);
+        assert_not_impl_all!(RefOwned<SendButNotSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RefOwned<SendAndSync>: Send);
+        assert_impl_all!(RefOwned<SendAndSync>: Sync);
+    }
+
+    mod rcu_hlist_iter {
+        use super::*;
+
+        // T: Send + Sync
+        assert_not_impl_all!(Iter<'_, SendAndSync, RcuDefaultGuard>: Send);
+        assert_not_impl_all!(Iter<'_, SendAndSync, RcuDefaultGuard>: Sync);
+    }
+}
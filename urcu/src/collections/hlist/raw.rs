@@ -0,0 +1,176 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use container_of::container_of;
+use urcu_cds_sys::hlist;
+
+use crate::utility::*;
+
+pub struct RawNode<T> {
+    handle: hlist::Node,
+    data: T,
+}
+
+impl<T> RawNode<T> {
+    pub fn new(data: T) -> Box<Self> {
+        Box::new(Self {
+            handle: Default::default(),
+            data,
+        })
+    }
+
+    fn into_handle(self: Box<Self>) -> *mut hlist::Node {
+        let node_ptr = Box::into_raw(self);
+        let node = unsafe { node_ptr.as_mut_unchecked() };
+        &mut node.handle
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+impl<T> Deref for RawNode<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+/// #### Safety
+///
+/// It is safe to send a [`RawNode<T>`] to another thread if `T` is [`Send`].
+unsafe impl<T: Send> Send for RawNode<T> {}
+
+/// #### Safety
+///
+/// It is safe to share a [`RawNode<T>`] between threads if `T` is [`Sync`].
+unsafe impl<T: Sync> Sync for RawNode<T> {}
+
+pub struct RawHList<T> {
+    head: hlist::Head,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawHList<T> {
+    /// #### Safety
+    ///
+    /// The caller must call [`RawHList::init`] once [`RawHList`] is in a stable memory location.
+    pub unsafe fn new() -> Self {
+        Self {
+            head: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must ensure [`RawHList`] is in a stable memory location.
+    /// The caller must remove all nodes before dropping this type.
+    pub unsafe fn init(&mut self) {
+        // SAFETY: The pointer is non-null.
+        unsafe { hlist::init_head(&mut self.head) };
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    pub unsafe fn insert_front(&self, node: Box<RawNode<T>>) {
+        let head = &self.head as *const hlist::Head as *mut hlist::Head;
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { hlist::add_head_rcu(node.into_handle(), head) }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove_front(&self) -> *mut RawNode<T> {
+        self.remove_if(|_| true)
+    }
+
+    /// Removes the first node matching a predicate.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove_if<P>(&self, mut predicate: P) -> *mut RawNode<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut handle = self.head.next;
+
+        while !handle.is_null() {
+            let node = container_of!(handle, RawNode<T>, handle);
+
+            // SAFETY: The node is linked, it cannot be freed by another writer.
+            if predicate(unsafe { &(*node).data }) {
+                // SAFETY: The C call safely mutate the state shared between threads.
+                unsafe { hlist::del_rcu(handle) };
+                return node;
+            }
+
+            // SAFETY: The handle pointer is non-null.
+            handle = unsafe { (*handle).next };
+        }
+
+        std::ptr::null_mut()
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get_front(&self) -> *const RawNode<T> {
+        let handle = crate::rcu::dereference(self.head.next);
+
+        if handle.is_null() {
+            std::ptr::null()
+        } else {
+            container_of!(handle, RawNode<T>, handle)
+        }
+    }
+
+    pub fn empty(&self) -> bool {
+        self.head.next.is_null()
+    }
+}
+
+pub struct RawIter<T> {
+    current: *const hlist::Node,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawIter<T> {
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn from_front(list: &RawHList<T>) -> Self {
+        Self {
+            current: crate::rcu::dereference(list.head.next),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn next(&mut self) -> *const RawNode<T> {
+        match self.current.as_ref() {
+            None => std::ptr::null(),
+            Some(handle) => {
+                self.current = crate::rcu::dereference_mut(handle.next);
+
+                container_of!(handle as *const hlist::Node, RawNode<T>, handle)
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use crate::collections::hlist::raw::RawNode;
+use crate::rcu::reference;
+
+/// An owned RCU reference to a element removed from an [`RcuHList`].
+///
+/// [`RcuHList`]: crate::collections::hlist::container::RcuHList
+pub type RefOwned<T> = reference::BoxRefOwned<RawNode<T>>;
+
+impl<T> RefOwned<T> {
+    /// Moves the element out of the reference.
+    pub fn into_inner(self) -> T {
+        self.into_box().into_data()
+    }
+}
+
+/// An RCU reference to a element removed from an [`RcuHList`].
+///
+/// #### Requirements
+///
+/// `T` must be [`Send`] because [`Drop::drop`] might execute cleanup in another thread.
+///
+/// [`RcuHList`]: crate::collections::hlist::container::RcuHList
+pub type Ref<T, F> = reference::RcuRefBox<RawNode<T>, F>;
@@ -0,0 +1,37 @@
+use crate::collections::hlist::container::RcuHList;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn simple() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuHList::<u32>::new();
+    assert!(list.is_empty());
+
+    list.push_front(10);
+    list.push_front(20);
+    list.push_front(30);
+
+    let guard = context.rcu_read_lock();
+    assert!(!list.is_empty());
+    assert_eq!(list.front(&guard), Some(&30));
+    assert_eq!(list.iter(&guard).collect::<Vec<_>>(), vec![&30, &20, &10]);
+    assert!(list.contains(&20, &guard));
+
+    let removed = list.remove(|x| *x == 20).unwrap();
+    assert!(list.remove(|x| *x == 20).is_none());
+    assert_eq!(list.iter(&guard).collect::<Vec<_>>(), vec![&30, &10]);
+
+    let popped = list.pop_front().unwrap();
+    assert_eq!(list.iter(&guard).collect::<Vec<_>>(), vec![&10]);
+    drop(guard);
+
+    assert_eq!(removed.take_ownership(&mut context).into_inner(), 20);
+    assert_eq!(popped.take_ownership(&mut context).into_inner(), 30);
+}
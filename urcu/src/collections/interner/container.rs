@@ -0,0 +1,217 @@
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::collections::hashmap::raw::RawMap;
+use crate::collections::hashmap::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::{RcuReadContext, RcuRef};
+
+/// Defines the reference count of an interned value.
+struct Entry(AtomicUsize);
+
+impl Entry {
+    /// The reference count of an entry being collected.
+    const DEAD: usize = usize::MAX;
+
+    fn new() -> Self {
+        Self(AtomicUsize::new(1))
+    }
+
+    /// Increments the reference count, unless the entry is being collected.
+    fn acquire(&self) -> bool {
+        self.0
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |refs| {
+                (refs != Self::DEAD).then_some(refs + 1)
+            })
+            .is_ok()
+    }
+
+    /// Decrements the reference count, unless it is already zero.
+    fn release(&self) -> bool {
+        self.0
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |refs| {
+                (refs != Self::DEAD && refs != 0).then(|| refs - 1)
+            })
+            .is_ok()
+    }
+
+    /// Marks the entry as being collected if it is not referenced.
+    fn kill(&self) -> bool {
+        self.0
+            .compare_exchange(0, Self::DEAD, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+}
+
+/// Defines a RCU lock-free interning table.
+///
+/// Equal values are stored once. Interning a value returns a reference to the stored
+/// value, which is stable for the lifetime of the guard. Two interned references can be
+/// compared with [`std::ptr::eq`] instead of comparing the values.
+///
+/// # Limitations
+///
+/// ##### Reference Counting
+///
+/// Each call to [`RcuInterner::intern`] increments the reference count of the value and
+/// each call to [`RcuInterner::release`] decrements it. Values are never removed until
+/// [`RcuInterner::collect`] is called, which removes the values without references.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuInterner<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuInterner<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing an
+/// RCU reference.
+pub struct RcuInterner<T, F = RcuDefaultFlavor>
+where
+    T: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    raw: RawMap<T, Entry, F>,
+    refs: RcuRefTracker,
+}
+
+impl<T, F> RcuInterner<T, F>
+where
+    T: Send + Eq + Hash,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU interner.
+    pub fn new() -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            raw: RawMap::new()?,
+            refs: RcuRefTracker::new::<F>("RcuInterner"),
+        }))
+    }
+
+    /// Interns a value, returning a reference to the stored value.
+    ///
+    /// If an equal value is already stored, `value` is dropped.
+    pub fn intern<'me, 'guard, G>(&'me self, value: T, guard: &'guard G) -> &'guard T
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        let mut pair = (value, Entry::new());
+
+        loop {
+            // SAFETY: The RCU read-side lock is taken.
+            let (node, rejected) = unsafe { self.raw.add_unique(pair.0, pair.1) };
+
+            // SAFETY: The node pointer is non-null.
+            // SAFETY: The node is valid for the duration of the RCU critical section.
+            let node = unsafe { node.as_ref_unchecked() };
+
+            match rejected {
+                None => return &node.key,
+                Some(_) if node.value.acquire() => return &node.key,
+                Some(rejected) => {
+                    // The stored value is being collected, it will be removed shortly.
+                    pair = rejected;
+                    std::thread::yield_now();
+                }
+            }
+        }
+    }
+
+    /// Returns a reference to the stored value equal to `value`.
+    pub fn get<'me, 'guard, G>(&'me self, value: &T, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        let mut iter = unsafe { self.raw.lookup(value) };
+
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        unsafe { iter.get().as_ref() }
+            .filter(|node| node.value.0.load(Ordering::Acquire) != Entry::DEAD)
+            .map(|node| &node.key)
+    }
+
+    /// Returns `true` if a value equal to `value` is stored.
+    pub fn contains<G>(&self, value: &T, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.get(value, guard).is_some()
+    }
+
+    /// Decrements the reference count of the stored value equal to `value`.
+    ///
+    /// Returns `false` if no such value is stored or if it has no references.
+    pub fn release<G>(&self, value: &T, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        let mut iter = unsafe { self.raw.lookup(value) };
+
+        // SAFETY: The node pointer is convertible to a reference is non-null.
+        unsafe { iter.get().as_ref() }.is_some_and(|node| node.value.release())
+    }
+
+    /// Removes the stored values without references.
+    ///
+    /// The values are freed in the RCU cleanup thread after a grace period. Returns the
+    /// amount of removed values.
+    pub fn collect<G>(&self, guard: &G) -> usize
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        // SAFETY: The RCU grace period is enforced through the RcuRef.
+        let refs = unsafe { self.raw.del_if(|_, entry| entry.kill()) }
+            .into_iter()
+            .map(|node| Ref::<T, Entry, F>::new(node).tracked(&self.refs))
+            .collect::<Vec<_>>();
+
+        let count = refs.len();
+        refs.safe_cleanup();
+        count
+    }
+}
+
+impl<T, F> Drop for RcuInterner<T, F>
+where
+    T: Send + 'static,
+    F: RcuFlavor + 'static,
+{
+    fn drop(&mut self) {
+        self.refs.report();
+
+        let mut raw = self.raw.clone();
+
+        F::rcu_cleanup_and_block(Box::new(move |context| {
+            let guard = context.rcu_read_lock();
+
+            // SAFETY: The read-side RCU lock is taken.
+            unsafe { raw.del_all() }
+                .into_iter()
+                .map(Ref::<T, Entry, F>::new)
+                .collect::<Vec<_>>()
+                .safe_cleanup();
+
+            drop(guard);
+
+            // SAFETY: The read-side RCU lock is not taken.
+            // SAFETY: We are a registered RCU read-side thread.
+            unsafe { raw.destroy() };
+        }));
+    }
+}
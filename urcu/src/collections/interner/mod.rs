@@ -0,0 +1,20 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::interner::container::RcuInterner;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    // T: Send + !Sync
+    assert_impl_all!(RcuInterner<SendButNotSync, RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuInterner<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+    // T: Send + Sync
+    assert_impl_all!(RcuInterner<SendAndSync, RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuInterner<SendAndSync, RcuDefaultFlavor>: Sync);
+}
@@ -0,0 +1,51 @@
+use crate::collections::interner::container::RcuInterner;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+
+#[test]
+fn intern() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let interner = RcuInterner::<String>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    let first = interner.intern("alpha".to_string(), &guard);
+    let second = interner.intern("alpha".to_string(), &guard);
+    let third = interner.intern("beta".to_string(), &guard);
+
+    assert!(std::ptr::eq(first, second));
+    assert!(!std::ptr::eq(first, third));
+    assert_eq!(interner.get(&"alpha".to_string(), &guard), Some(first));
+    assert!(!interner.contains(&"gamma".to_string(), &guard));
+}
+
+#[test]
+fn collect() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let interner = RcuInterner::<u32>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    interner.intern(4127, &guard);
+    interner.intern(4127, &guard);
+    interner.intern(8053, &guard);
+
+    assert!(interner.release(&4127, &guard));
+    assert!(interner.release(&8053, &guard));
+    assert!(!interner.release(&8053, &guard));
+    assert_eq!(interner.collect(&guard), 1);
+
+    assert!(interner.contains(&4127, &guard));
+    assert!(!interner.contains(&8053, &guard));
+
+    assert!(interner.release(&4127, &guard));
+    assert_eq!(interner.collect(&guard), 1);
+    assert!(!interner.contains(&4127, &guard));
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::collections::boxed::reference::Ref;
+use crate::collections::hashmap::matcher::{RcuDefaultKeyMatcher, RcuKeyMatcher};
+use crate::collections::intrusive::link::{RcuHashMapLink, RcuIntrusive};
+use crate::collections::intrusive::raw::RawMap;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::{RcuGuard, RcuReadContext, RcuRef};
+
+/// This trait defines the key of an element stored in an [`RcuIntrusiveHashMap`].
+pub trait RcuIntrusiveKey {
+    /// The type of the key.
+    type Key;
+
+    /// Returns the key of the element.
+    fn key(&self) -> &Self::Key;
+}
+
+/// Defines a RCU lock-free hashmap of elements embedding their link.
+///
+/// Unlike [`RcuHashMap`], the hashmap does not allocate a node for each element. The
+/// elements embed a [`RcuHashMapLink`], provide their key with [`RcuIntrusiveKey`] and
+/// are inserted as a [`Box<T>`]. Removed elements are returned as a RCU reference to the
+/// same [`Box<T>`].
+///
+/// This hashmap supports multiple concurrents readers and writers. It is guaranteed
+/// to never block on a call.
+///
+/// # Limitations
+///
+/// ##### Mutable Keys
+///
+/// The key of an element must not change while it is in the hashmap. The key is only
+/// borrowed through [`RcuIntrusiveKey::key`], it is never copied.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuIntrusiveHashMap<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuIntrusiveHashMap<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing an
+/// RCU reference.
+///
+/// [`RcuHashMap`]: crate::collections::hashmap::container::RcuHashMap
+pub struct RcuIntrusiveHashMap<T, F = RcuDefaultFlavor, M = RcuDefaultKeyMatcher>
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey + Send + 'static,
+    F: RcuFlavor + 'static,
+    M: RcuKeyMatcher<T::Key> + 'static,
+{
+    raw: RawMap<T, F, M>,
+    refs: RcuRefTracker,
+}
+
+impl<T, F, M> RcuIntrusiveHashMap<T, F, M>
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey + Send,
+    F: RcuFlavor,
+    M: RcuKeyMatcher<T::Key>,
+{
+    /// Creates a new RCU intrusive hashmap.
+    pub fn new() -> Result<Arc<Self>> {
+        Ok(Arc::new(Self {
+            raw: RawMap::new()?,
+            refs: RcuRefTracker::new::<F>("RcuIntrusiveHashMap"),
+        }))
+    }
+
+    /// Inserts an element in the hashmap.
+    ///
+    /// If the hashmap did not have this key present, [`None`] is returned.
+    ///
+    /// If the hashmap did have this key present, the element is replaced and the old
+    /// element is returned.
+    pub fn insert<G>(&self, element: Box<T>, guard: &G) -> Option<Ref<T, F>>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The read-side RCU lock is taken.
+        // SAFETY: The RCU grace period is enforced through the RcuRef.
+        let ptr = unsafe { self.raw.add_replace(element) };
+
+        (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+    }
+
+    /// Returns `true` if the hashmap contains an element for the specified key.
+    pub fn contains<G>(&self, key: &T::Key, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.get(key, guard).is_some()
+    }
+
+    /// Returns a reference to the element corresponding to the key.
+    pub fn get<'me, 'guard, G>(&'me self, key: &T::Key, _guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU read-side lock is taken.
+        // SAFETY: The element pointer is convertible to a reference.
+        unsafe { self.raw.lookup(key).as_ref() }
+    }
+
+    /// Removes an element from the hashmap, returning the element if the key was
+    /// previously in the hashmap.
+    pub fn remove<G>(&self, key: &T::Key, guard: &G) -> Option<Ref<T, F>>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU read-side lock is taken.
+        let element = unsafe { self.raw.lookup(key) };
+        if element.is_null() {
+            return None;
+        }
+
+        // SAFETY: The RCU read-side lock is taken.
+        // SAFETY: The RCU grace period is enforced through RcuRef.
+        let ptr = unsafe { self.raw.del(element) };
+
+        (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuIntrusiveHashMap`] can be used to send `T` to another thread.
+unsafe impl<T, F, M> Send for RcuIntrusiveHashMap<T, F, M>
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey + Send,
+    F: RcuFlavor,
+    M: RcuKeyMatcher<T::Key>,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuIntrusiveHashMap`] can be used to share `T` between threads.
+unsafe impl<T, F, M> Sync for RcuIntrusiveHashMap<T, F, M>
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey + Send + Sync,
+    F: RcuFlavor,
+    M: RcuKeyMatcher<T::Key>,
+{
+}
+
+impl<T, F, M> Drop for RcuIntrusiveHashMap<T, F, M>
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey + Send + 'static,
+    F: RcuFlavor + 'static,
+    M: RcuKeyMatcher<T::Key> + 'static,
+{
+    fn drop(&mut self) {
+        self.refs.report();
+
+        let mut raw = self.raw.clone();
+
+        F::rcu_cleanup_and_block(Box::new(move |context| {
+            let guard = context.rcu_read_lock();
+
+            let mut refs = Vec::new();
+
+            // SAFETY: The read-side RCU lock is taken.
+            unsafe {
+                raw.for_each(|element| {
+                    // SAFETY: The read-side RCU lock is taken.
+                    let ptr = raw.del(element);
+                    if !ptr.is_null() {
+                        refs.push(Ref::<T, F>::new(ptr));
+                    }
+                })
+            };
+
+            refs.safe_cleanup();
+
+            drop(guard);
+
+            // SAFETY: The read-side RCU lock is not taken.
+            // SAFETY: We are a registered RCU read-side thread.
+            unsafe { raw.destroy() };
+        }));
+    }
+}
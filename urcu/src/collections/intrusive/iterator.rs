@@ -0,0 +1,95 @@
+use crate::collections::intrusive::link::{RcuIntrusive, RcuListLink, RcuStackLink};
+use crate::collections::intrusive::raw::{RawList, RawStack};
+use crate::rcu::guard::RcuGuard;
+
+/// An iterator over the elements of an [`RcuIntrusiveList`].
+///
+/// [`RcuIntrusiveList`]: crate::collections::intrusive::list::RcuIntrusiveList
+pub struct ListIter<'guard, T, G>
+where
+    G: RcuGuard,
+{
+    raw: &'guard RawList<T>,
+    current: *const T,
+    #[allow(dead_code)]
+    guard: &'guard G,
+}
+
+impl<'guard, T, G> ListIter<'guard, T, G>
+where
+    T: RcuIntrusive<RcuListLink>,
+    G: RcuGuard,
+{
+    pub(crate) fn new(raw: &'guard RawList<T>, guard: &'guard G) -> Self {
+        Self {
+            raw,
+            // SAFETY: The RCU critical section is enforced.
+            current: unsafe { raw.get_back() },
+            guard,
+        }
+    }
+}
+
+impl<'guard, T, G> Iterator for ListIter<'guard, T, G>
+where
+    T: RcuIntrusive<RcuListLink> + 'guard,
+    G: RcuGuard,
+{
+    type Item = &'guard T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The RCU critical section is enforced.
+        let element = unsafe { self.current.as_ref() }?;
+
+        // SAFETY: The RCU critical section is enforced.
+        self.current = unsafe { self.raw.next(element) };
+
+        Some(element)
+    }
+}
+
+/// An iterator over the elements of an [`RcuIntrusiveStack`].
+///
+/// [`RcuIntrusiveStack`]: crate::collections::intrusive::stack::RcuIntrusiveStack
+pub struct StackIter<'guard, T, G>
+where
+    G: RcuGuard,
+{
+    raw: &'guard RawStack<T>,
+    current: *const T,
+    #[allow(dead_code)]
+    guard: &'guard G,
+}
+
+impl<'guard, T, G> StackIter<'guard, T, G>
+where
+    T: RcuIntrusive<RcuStackLink>,
+    G: RcuGuard,
+{
+    pub(crate) fn new(raw: &'guard RawStack<T>, guard: &'guard G) -> Self {
+        Self {
+            raw,
+            // SAFETY: The RCU critical section is enforced.
+            current: unsafe { raw.head() },
+            guard,
+        }
+    }
+}
+
+impl<'guard, T, G> Iterator for StackIter<'guard, T, G>
+where
+    T: RcuIntrusive<RcuStackLink> + 'guard,
+    G: RcuGuard,
+{
+    type Item = &'guard T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The RCU critical section is enforced.
+        let element = unsafe { self.current.as_ref() }?;
+
+        // SAFETY: The RCU critical section is enforced.
+        self.current = unsafe { self.raw.next(element) };
+
+        Some(element)
+    }
+}
@@ -0,0 +1,152 @@
+use std::mem::MaybeUninit;
+
+use urcu_cds_sys::{lfht, lfs, list};
+
+/// Defines the link embedded in an element of an [`RcuIntrusiveList`].
+///
+/// [`RcuIntrusiveList`]: crate::collections::intrusive::list::RcuIntrusiveList
+#[derive(Default)]
+#[repr(transparent)]
+pub struct RcuListLink(#[allow(dead_code)] list::Head);
+
+/// #### Safety
+///
+/// The link is only accessed by the [`RcuIntrusiveList`] holding the element.
+///
+/// [`RcuIntrusiveList`]: crate::collections::intrusive::list::RcuIntrusiveList
+unsafe impl Send for RcuListLink {}
+
+/// #### Safety
+///
+/// The link is only accessed by the [`RcuIntrusiveList`] holding the element.
+///
+/// [`RcuIntrusiveList`]: crate::collections::intrusive::list::RcuIntrusiveList
+unsafe impl Sync for RcuListLink {}
+
+/// Defines the link embedded in an element of an [`RcuIntrusiveStack`].
+///
+/// [`RcuIntrusiveStack`]: crate::collections::intrusive::stack::RcuIntrusiveStack
+#[repr(transparent)]
+pub struct RcuStackLink(#[allow(dead_code)] lfs::Node);
+
+impl Default for RcuStackLink {
+    fn default() -> Self {
+        let mut handle = MaybeUninit::<lfs::Node>::uninit();
+
+        // SAFETY: We don't need to registered with RCU in any way.
+        unsafe { lfs::node_init(handle.as_mut_ptr()) };
+
+        // SAFETY: Data has been initialised by `lfs::node_init`.
+        Self(unsafe { handle.assume_init() })
+    }
+}
+
+/// #### Safety
+///
+/// The link is only accessed by the [`RcuIntrusiveStack`] holding the element.
+///
+/// [`RcuIntrusiveStack`]: crate::collections::intrusive::stack::RcuIntrusiveStack
+unsafe impl Send for RcuStackLink {}
+
+/// #### Safety
+///
+/// The link is only accessed by the [`RcuIntrusiveStack`] holding the element.
+///
+/// [`RcuIntrusiveStack`]: crate::collections::intrusive::stack::RcuIntrusiveStack
+unsafe impl Sync for RcuStackLink {}
+
+/// Defines the link embedded in an element of an [`RcuIntrusiveHashMap`].
+///
+/// [`RcuIntrusiveHashMap`]: crate::collections::intrusive::hashmap::RcuIntrusiveHashMap
+#[repr(transparent)]
+pub struct RcuHashMapLink(#[allow(dead_code)] lfht::Node);
+
+impl Default for RcuHashMapLink {
+    fn default() -> Self {
+        let mut handle = lfht::Node::default();
+
+        // SAFETY: The pointer is non-null.
+        unsafe { lfht::node_init(&mut handle) };
+
+        Self(handle)
+    }
+}
+
+/// #### Safety
+///
+/// The link is only accessed by the [`RcuIntrusiveHashMap`] holding the element.
+///
+/// [`RcuIntrusiveHashMap`]: crate::collections::intrusive::hashmap::RcuIntrusiveHashMap
+unsafe impl Send for RcuHashMapLink {}
+
+/// #### Safety
+///
+/// The link is only accessed by the [`RcuIntrusiveHashMap`] holding the element.
+///
+/// [`RcuIntrusiveHashMap`]: crate::collections::intrusive::hashmap::RcuIntrusiveHashMap
+unsafe impl Sync for RcuHashMapLink {}
+
+/// This trait defines a type embedding a link of an intrusive container.
+///
+/// The containers find the link of an element, and the element of a link, with the
+/// offset of the link. Use [`rcu_intrusive`] to implement this trait.
+///
+/// #### Safety
+///
+/// [`RcuIntrusive::OFFSET`] must be the offset of a field of type `L` in `Self`.
+///
+/// [`rcu_intrusive`]: crate::rcu_intrusive
+pub unsafe trait RcuIntrusive<L> {
+    /// The offset of the link in bytes.
+    const OFFSET: usize;
+
+    /// Returns a pointer to the link of an element.
+    ///
+    /// #### Safety
+    ///
+    /// The pointer must point to an element of type `Self`.
+    unsafe fn to_link(this: *const Self) -> *mut L
+    where
+        Self: Sized,
+    {
+        // SAFETY: The element is valid and the offset is within the element.
+        unsafe { this.cast::<u8>().add(Self::OFFSET).cast::<L>().cast_mut() }
+    }
+
+    /// Returns a pointer to the element of a link.
+    ///
+    /// #### Safety
+    ///
+    /// The link must be embedded in an element of type `Self`.
+    unsafe fn from_link(link: *const L) -> *mut Self
+    where
+        Self: Sized,
+    {
+        // SAFETY: The link is embedded in an element.
+        unsafe {
+            link.cast::<u8>()
+                .sub(Self::OFFSET)
+                .cast::<Self>()
+                .cast_mut()
+        }
+    }
+}
+
+/// Implements [`RcuIntrusive`] for a field of a type.
+///
+/// The macro takes the type, the name of the field and the type of the link, such as
+/// `rcu_intrusive!(Session, link: RcuListLink)`.
+///
+/// [`RcuIntrusive`]: crate::collections::intrusive::link::RcuIntrusive
+#[macro_export]
+macro_rules! rcu_intrusive {
+    ($type:ty, $field:ident: $link:ty) => {
+        // SAFETY: The offset is computed from a field of the link type.
+        unsafe impl $crate::collections::intrusive::RcuIntrusive<$link> for $type {
+            const OFFSET: usize = {
+                let _: fn(&$type) -> &$link = |this| &this.$field;
+                ::std::mem::offset_of!($type, $field)
+            };
+        }
+    };
+}
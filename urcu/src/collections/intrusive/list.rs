@@ -0,0 +1,221 @@
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::boxed::reference::Ref;
+use crate::collections::intrusive::iterator::ListIter;
+use crate::collections::intrusive::link::{RcuIntrusive, RcuListLink};
+use crate::collections::intrusive::raw::RawList;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU doubly linked list of elements embedding their link.
+///
+/// Unlike [`RcuList`], the list does not allocate a node for each element. The elements
+/// embed a [`RcuListLink`] and are inserted as a [`Box<T>`]. Removed elements are returned
+/// as a RCU reference to the same [`Box<T>`].
+///
+/// The list supports multiple concurrents readers at any time, but only a single writer
+/// at a time. The list uses an internal lock for writing operations.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuIntrusiveList<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuIntrusiveList<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing a RCU reference.
+///
+/// [`RcuList`]: crate::collections::list::container::RcuList
+pub struct RcuIntrusiveList<T, F = RcuDefaultFlavor>
+where
+    T: RcuIntrusive<RcuListLink>,
+{
+    raw: RawList<T>,
+    mutex: Mutex<()>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuIntrusiveList<T, F>
+where
+    T: RcuIntrusive<RcuListLink>,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU intrusive linked list.
+    pub fn new() -> Arc<Self> {
+        let mut list = Arc::new(Self {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawList::new() },
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuIntrusiveList"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw list is in a stable memory location.
+        // SAFETY: All the nodes are removed upon dropping.
+        unsafe { Arc::<Self>::get_mut(&mut list).unwrap().raw.init() };
+
+        list
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce() -> R,
+    {
+        // The nodes are linked and unlinked without calling user code, so the list is
+        // still consistent if a writer panics while holding the lock.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback();
+        drop(guard);
+        result
+    }
+
+    /// Adds an element to the back of the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_back(&self, element: Box<T>)
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            unsafe { self.raw.insert_back(element) }
+        })
+    }
+
+    /// Adds an element to the front of the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_front(&self, element: Box<T>)
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            unsafe { self.raw.insert_front(element) }
+        })
+    }
+
+    /// Removes an element from the back of the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_back(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let ptr = unsafe { self.raw.remove_back() };
+
+            (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+        })
+    }
+
+    /// Removes an element from the front of the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_front(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let ptr = unsafe { self.raw.remove_front() };
+
+            (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+        })
+    }
+
+    /// Returns `true` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*1*) time.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is empty.
+    pub fn back<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The element pointer can be converted to a reference.
+        unsafe { self.raw.get_back().as_ref() }
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is empty.
+    pub fn front<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The element pointer can be converted to a reference.
+        unsafe { self.raw.get_front().as_ref() }
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all items from back to front.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> ListIter<'guard, T, G>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        ListIter::new(&self.raw, guard)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuIntrusiveList`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuIntrusiveList<T, F>
+where
+    T: RcuIntrusive<RcuListLink> + Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuIntrusiveList`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuIntrusiveList<T, F>
+where
+    T: RcuIntrusive<RcuListLink> + Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuIntrusiveList<T, F>
+where
+    T: RcuIntrusive<RcuListLink>,
+{
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The RCU grace period is not needed because there are no other readers.
+        while let Some(ptr) = unsafe { self.raw.remove_back().as_mut() } {
+            drop(unsafe { Box::from_raw(ptr) });
+        }
+    }
+}
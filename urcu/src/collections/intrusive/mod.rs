@@ -0,0 +1,85 @@
+//! Intrusive containers.
+//!
+//! The elements of these containers embed the link of the container, such as a
+//! [`RcuListLink`], instead of being wrapped in a node allocated by the container.
+
+pub(crate) mod hashmap;
+pub(crate) mod iterator;
+pub(crate) mod link;
+pub(crate) mod list;
+pub(crate) mod raw;
+pub(crate) mod stack;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::intrusive::hashmap::*;
+pub use crate::collections::intrusive::iterator::*;
+pub use crate::collections::intrusive::link::*;
+pub use crate::collections::intrusive::list::*;
+pub use crate::collections::intrusive::stack::*;
+
+mod asserts {
+    use super::*;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    struct Element<T> {
+        list: RcuListLink,
+        stack: RcuStackLink,
+        _value: T,
+    }
+
+    // SAFETY: The offset is computed from a field of the link type.
+    unsafe impl<T> RcuIntrusive<RcuListLink> for Element<T> {
+        const OFFSET: usize = std::mem::offset_of!(Element<T>, list);
+    }
+
+    // SAFETY: The offset is computed from a field of the link type.
+    unsafe impl<T> RcuIntrusive<RcuStackLink> for Element<T> {
+        const OFFSET: usize = std::mem::offset_of!(Element<T>, stack);
+    }
+
+    mod rcu_intrusive_list {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuIntrusiveList<Element<NotSendNotSync>, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuIntrusiveList<Element<NotSendNotSync>, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuIntrusiveList<Element<SendButNotSync>, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuIntrusiveList<Element<SendButNotSync>, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuIntrusiveList<Element<NotSendButSync>, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuIntrusiveList<Element<NotSendButSync>, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuIntrusiveList<Element<SendAndSync>, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuIntrusiveList<Element<SendAndSync>, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_intrusive_stack {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuIntrusiveStack<Element<NotSendNotSync>, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuIntrusiveStack<Element<NotSendNotSync>, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuIntrusiveStack<Element<SendButNotSync>, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuIntrusiveStack<Element<SendButNotSync>, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuIntrusiveStack<Element<NotSendButSync>, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuIntrusiveStack<Element<NotSendButSync>, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuIntrusiveStack<Element<SendAndSync>, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuIntrusiveStack<Element<SendAndSync>, RcuDefaultFlavor>: Sync);
+    }
+}
@@ -0,0 +1,443 @@
+use std::ffi::{c_int, c_ulong, c_void};
+use std::marker::PhantomData;
+
+use anyhow::{bail, Result};
+use urcu_cds_sys::{lfht, lfs, list};
+
+use crate::collections::hashmap::matcher::{RcuDefaultKeyMatcher, RcuKeyMatcher};
+use crate::collections::intrusive::hashmap::RcuIntrusiveKey;
+use crate::collections::intrusive::link::{
+    RcuHashMapLink,
+    RcuIntrusive,
+    RcuListLink,
+    RcuStackLink,
+};
+use crate::rcu::flavor::RcuFlavor;
+use crate::utility::*;
+
+//////////
+// list //
+//////////
+
+pub struct RawList<T> {
+    back: list::Head,
+    front: list::Head,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawList<T>
+where
+    T: RcuIntrusive<RcuListLink>,
+{
+    /// #### Safety
+    ///
+    /// The caller must call [`RawList::init`] once [`RawList`] is in a stable memory location.
+    pub unsafe fn new() -> Self {
+        Self {
+            back: Default::default(),
+            front: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must ensure [`RawList`] is in a stable memory location.
+    /// The caller must remove all nodes before dropping this type.
+    pub unsafe fn init(&mut self) {
+        self.back.next = &mut self.front;
+        self.front.prev = &mut self.back;
+    }
+
+    fn handle(element: Box<T>) -> *mut list::Head {
+        // SAFETY: The pointer comes from a boxed element.
+        unsafe { T::to_link(Box::into_raw(element)).cast() }
+    }
+
+    /// #### Safety
+    ///
+    /// The handle must be embedded in an element.
+    unsafe fn element(handle: *const list::Head) -> *mut T {
+        T::from_link(handle.cast())
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    pub unsafe fn insert_back(&self, element: Box<T>) {
+        let back = &self.back as *const list::Head as *mut list::Head;
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { list::add_rcu(Self::handle(element), back) }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    pub unsafe fn insert_front(&self, element: Box<T>) {
+        let front = &self.front as *const list::Head as *mut list::Head;
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { list::add_tail_rcu(Self::handle(element), front) }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the element.
+    pub unsafe fn remove_back(&self) -> *mut T {
+        let handle = self.back.next;
+
+        if handle as *const list::Head != &self.front {
+            // SAFETY: The C call safely mutate the state shared between threads.
+            unsafe { list::del_rcu(handle) };
+            Self::element(handle)
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the element.
+    pub unsafe fn remove_front(&self) -> *mut T {
+        let handle = self.front.prev;
+
+        if handle as *const list::Head != &self.back {
+            // SAFETY: The C call safely mutate the state shared between threads.
+            unsafe { list::del_rcu(handle) };
+            Self::element(handle)
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get_back(&self) -> *const T {
+        let handle = crate::rcu::dereference(self.back.next);
+
+        if handle != &self.front {
+            Self::element(handle)
+        } else {
+            std::ptr::null()
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get_front(&self) -> *const T {
+        let handle = crate::rcu::dereference(self.front.prev);
+
+        if handle != &self.back {
+            Self::element(handle)
+        } else {
+            std::ptr::null()
+        }
+    }
+
+    /// Returns the element after another one, from back to front.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn next(&self, element: *const T) -> *const T {
+        let handle = T::to_link(element).cast::<list::Head>();
+        let handle = crate::rcu::dereference((*handle).next);
+
+        if handle != &self.front {
+            Self::element(handle)
+        } else {
+            std::ptr::null()
+        }
+    }
+
+    pub fn empty(&self) -> bool {
+        self.back.next as *const list::Head == &self.front
+    }
+}
+
+///////////
+// stack //
+///////////
+
+pub struct RawStack<T> {
+    handle: lfs::__Stack,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawStack<T>
+where
+    T: RcuIntrusive<RcuStackLink>,
+{
+    /// #### Safety
+    ///
+    /// The caller must pop all node before dropping this type.
+    pub unsafe fn new() -> Self {
+        let mut handle = std::mem::MaybeUninit::<lfs::__Stack>::uninit();
+
+        // SAFETY: We don't need to registered with RCU in any way.
+        unsafe { lfs::__init(handle.as_mut_ptr()) };
+
+        Self {
+            // SAFETY: Data has been initialised by `lfs::init`.
+            handle: unsafe { handle.assume_init() },
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    fn stack_ptr(&self) -> lfs::StackPtr {
+        lfs::StackPtr {
+            _s: &self.handle as *const lfs::__Stack as *mut lfs::__Stack,
+        }
+    }
+
+    pub fn push(&self, element: Box<T>) {
+        // SAFETY: The pointer comes from a boxed element.
+        let handle = unsafe { T::to_link(Box::into_raw(element)).cast::<lfs::Node>() };
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { lfs::push(self.stack_ptr(), handle) };
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be inside a RCU critical section.
+    ///
+    /// The caller must wait a RCU grace period before freeing the element.
+    pub unsafe fn pop(&self) -> *mut T {
+        // SAFETY: The C call safely mutate the state shared between threads.
+        let node = unsafe { lfs::__pop(self.stack_ptr()) };
+
+        if node.is_null() {
+            std::ptr::null_mut()
+        } else {
+            T::from_link(node.cast())
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be inside a RCU critical section.
+    pub unsafe fn head(&self) -> *const T {
+        let head = crate::rcu::dereference(self.handle.head);
+
+        if head.is_null() {
+            std::ptr::null()
+        } else {
+            T::from_link(&(*head).node as *const lfs::Node as *const RcuStackLink)
+        }
+    }
+
+    /// Returns the element below another one.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be inside a RCU critical section.
+    pub unsafe fn next(&self, element: *const T) -> *const T {
+        let handle = T::to_link(element).cast::<lfs::Node>();
+        let next = crate::rcu::dereference((*handle).next);
+
+        if next.is_null() {
+            std::ptr::null()
+        } else {
+            T::from_link(next.cast())
+        }
+    }
+
+    pub fn empty(&self) -> bool {
+        // SAFETY: The C call does not mutate the shared state.
+        unsafe { lfs::empty(self.stack_ptr()) }
+    }
+}
+
+/////////////
+// hashmap //
+/////////////
+
+unsafe extern "C" fn key_eq<T, M>(handle_ptr: *mut lfht::Node, key_ptr: *const c_void) -> c_int
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey,
+    M: RcuKeyMatcher<T::Key>,
+{
+    // SAFETY: The pointer is never null.
+    // SAFETY: The pointer is valid for the duration of the reference.
+    let element = unsafe { T::from_link(handle_ptr.cast()).as_ref_unchecked() };
+
+    // SAFETY: The pointer is never null.
+    // SAFETY: The pointer is valid for the duration of the reference.
+    let key = unsafe { (key_ptr as *const T::Key).as_ref_unchecked() };
+
+    M::matches(element.key(), key) as c_int
+}
+
+pub struct RawMap<T, F, M = RcuDefaultKeyMatcher> {
+    handle: *mut lfht::Handle,
+    _unsend: PhantomUnsend<(T, F, M)>,
+    _unsync: PhantomUnsync<(T, F, M)>,
+}
+
+impl<T, F, M> RawMap<T, F, M>
+where
+    T: RcuIntrusive<RcuHashMapLink> + RcuIntrusiveKey,
+    M: RcuKeyMatcher<T::Key>,
+{
+    const INIT_FLAGS: i32 = (lfht::ACCOUNTING | lfht::AUTO_RESIZE) as i32;
+
+    pub fn new() -> Result<Self>
+    where
+        F: RcuFlavor,
+    {
+        // SAFETY: The default attributes are used.
+        let handle = unsafe {
+            lfht::new_flavor(
+                1,
+                1,
+                0,
+                Self::INIT_FLAGS,
+                F::unchecked_rcu_api(),
+                std::ptr::null_mut(),
+            )
+        };
+
+        if handle.is_null() {
+            bail!("failed to allocate RCU hash table");
+        }
+
+        Ok(Self {
+            handle,
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn hash_of(key: &T::Key) -> c_ulong {
+        M::hash(key) as c_ulong
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    ///
+    /// The caller must wait for a RCU grace period before freeing the old element.
+    pub unsafe fn add_replace(&self, element: Box<T>) -> *mut T {
+        let element = Box::into_raw(element);
+        let key = (*element).key();
+
+        // SAFETY: All pointers are non-null.
+        let handle = unsafe {
+            lfht::add_replace(
+                self.handle,
+                Self::hash_of(key),
+                Some(key_eq::<T, M>),
+                key as *const T::Key as *const c_void,
+                T::to_link(element).cast(),
+            )
+        };
+
+        if handle.is_null() {
+            std::ptr::null_mut()
+        } else {
+            T::from_link(handle.cast())
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn lookup(&self, key: &T::Key) -> *mut T {
+        let mut iter = lfht::Iter::default();
+
+        // SAFETY: All pointers are non-null.
+        unsafe {
+            lfht::lookup(
+                self.handle,
+                Self::hash_of(key),
+                Some(key_eq::<T, M>),
+                key as *const T::Key as *const c_void,
+                &mut iter,
+            )
+        };
+
+        // SAFETY: The iterator pointer is non-null.
+        let handle = unsafe { lfht::iter_get_node(&mut iter) };
+
+        if handle.is_null() {
+            std::ptr::null_mut()
+        } else {
+            T::from_link(handle.cast())
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    ///
+    /// The caller must wait for a RCU grace period before freeing the element.
+    pub unsafe fn del(&self, element: *mut T) -> *mut T {
+        // SAFETY: The element pointer is non-null.
+        if unsafe { lfht::del(self.handle, T::to_link(element).cast()) } < 0 {
+            std::ptr::null_mut()
+        } else {
+            element
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU read-side critical section.
+    pub unsafe fn for_each<C>(&self, mut callback: C)
+    where
+        C: FnMut(*mut T),
+    {
+        let mut iter = lfht::Iter::default();
+
+        // SAFETY: All pointers are non-null.
+        unsafe { lfht::first(self.handle, &mut iter) };
+
+        loop {
+            // SAFETY: The iterator pointer is non-null.
+            let handle = unsafe { lfht::iter_get_node(&mut iter) };
+            if handle.is_null() {
+                break;
+            }
+
+            callback(T::from_link(handle.cast()));
+
+            // SAFETY: All pointers are non-null.
+            unsafe { lfht::next(self.handle, &mut iter) };
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be a read-registered RCU thread.
+    ///
+    /// The caller must not be in a RCU critical section.
+    pub unsafe fn destroy(&mut self) {
+        unsafe { lfht::destroy(self.handle, std::ptr::null_mut()) };
+    }
+}
+
+/// #### Safety
+///
+/// It is safe to send the wrapper to another thread if the elements are [`Send`].
+unsafe impl<T: Send, F, M> Send for RawMap<T, F, M> {}
@@ -0,0 +1,143 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::collections::boxed::reference::Ref;
+use crate::collections::intrusive::iterator::StackIter;
+use crate::collections::intrusive::link::{RcuIntrusive, RcuStackLink};
+use crate::collections::intrusive::raw::RawStack;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU wait-free stack of elements embedding their link.
+///
+/// Unlike [`RcuStack`], the stack does not allocate a node for each element. The elements
+/// embed a [`RcuStackLink`] and are pushed as a [`Box<T>`]. Popped elements are returned
+/// as a RCU reference to the same [`Box<T>`].
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuIntrusiveStack<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuIntrusiveStack<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing a RCU reference.
+///
+/// [`RcuStack`]: crate::collections::stack::container::RcuStack
+pub struct RcuIntrusiveStack<T, F = RcuDefaultFlavor>
+where
+    T: RcuIntrusive<RcuStackLink>,
+{
+    raw: RawStack<T>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuIntrusiveStack<T, F>
+where
+    T: RcuIntrusive<RcuStackLink>,
+    F: RcuFlavor,
+{
+    /// Creates a new RCU intrusive stack.
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            // SAFETY: All elements are pop'ed before dropping.
+            raw: unsafe { RawStack::new() },
+            refs: RcuRefTracker::new::<F>("RcuIntrusiveStack"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    /// Adds an element to the top of the stack.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn push(&self, element: Box<T>)
+    where
+        T: Send,
+    {
+        self.raw.push(element);
+    }
+
+    /// Removes an element from the top of the stack.
+    pub fn pop<G>(&self, guard: &G) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+        let ptr = unsafe { self.raw.pop() };
+
+        (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+    }
+
+    /// Returns a reference to the element on top of the stack.
+    pub fn peek<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The element pointer can be converted to a reference.
+        unsafe { self.raw.head().as_ref() }
+    }
+
+    /// Returns `true` if the stack is empty.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    /// Returns an iterator over the stack.
+    ///
+    /// The iterator yields all items from top to bottom.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> StackIter<'guard, T, G>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        StackIter::new(&self.raw, guard)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuIntrusiveStack`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuIntrusiveStack<T, F>
+where
+    T: RcuIntrusive<RcuStackLink> + Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuIntrusiveStack`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuIntrusiveStack<T, F>
+where
+    T: RcuIntrusive<RcuStackLink> + Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuIntrusiveStack<T, F>
+where
+    T: RcuIntrusive<RcuStackLink>,
+{
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The RCU read-lock is not needed there are no other writers.
+        // SAFETY: The RCU grace period is not needed there are no other readers.
+        while let Some(ptr) = unsafe { self.raw.pop().as_mut() } {
+            drop(unsafe { Box::from_raw(ptr) });
+        }
+    }
+}
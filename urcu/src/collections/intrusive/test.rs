@@ -0,0 +1,124 @@
+use crate::collections::intrusive::hashmap::{RcuIntrusiveHashMap, RcuIntrusiveKey};
+use crate::collections::intrusive::link::{RcuHashMapLink, RcuListLink, RcuStackLink};
+use crate::collections::intrusive::list::RcuIntrusiveList;
+use crate::collections::intrusive::stack::RcuIntrusiveStack;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[derive(Default)]
+struct Session {
+    id: u32,
+    list: RcuListLink,
+    stack: RcuStackLink,
+    hashmap: RcuHashMapLink,
+}
+
+crate::rcu_intrusive!(Session, list: RcuListLink);
+crate::rcu_intrusive!(Session, stack: RcuStackLink);
+crate::rcu_intrusive!(Session, hashmap: RcuHashMapLink);
+
+impl RcuIntrusiveKey for Session {
+    type Key = u32;
+
+    fn key(&self) -> &Self::Key {
+        &self.id
+    }
+}
+
+fn session(id: u32) -> Box<Session> {
+    Box::new(Session {
+        id,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn list_push_pop() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuIntrusiveList::<Session>::new();
+    assert!(list.is_empty());
+
+    list.push_back(session(1));
+    list.push_back(session(2));
+    list.push_front(session(3));
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(list.back(&guard).map(|session| session.id), Some(2));
+    assert_eq!(list.front(&guard).map(|session| session.id), Some(3));
+    assert_eq!(
+        list.iter(&guard)
+            .map(|session| session.id)
+            .collect::<Vec<_>>(),
+        vec![2, 1, 3]
+    );
+    drop(guard);
+
+    let session = list.pop_back().unwrap();
+    assert_eq!(session.take_ownership(&mut context).id, 2);
+
+    let session = list.pop_front().unwrap();
+    assert_eq!(session.take_ownership(&mut context).id, 3);
+
+    assert!(!list.is_empty());
+}
+
+#[test]
+fn stack_push_pop() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuIntrusiveStack::<Session>::new();
+    assert!(stack.is_empty());
+
+    stack.push(session(1));
+    stack.push(session(2));
+    stack.push(session(3));
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(stack.peek(&guard).map(|session| session.id), Some(3));
+    assert_eq!(
+        stack
+            .iter(&guard)
+            .map(|session| session.id)
+            .collect::<Vec<_>>(),
+        vec![3, 2, 1]
+    );
+
+    let session = stack.pop(&guard).unwrap();
+    drop(guard);
+
+    assert_eq!(session.take_ownership(&mut context).id, 3);
+}
+
+#[test]
+fn hashmap_insert_remove() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let hashmap = RcuIntrusiveHashMap::<Session>::new().unwrap();
+    let guard = context.rcu_read_lock();
+
+    assert!(hashmap.insert(session(1), &guard).is_none());
+    assert!(hashmap.insert(session(2), &guard).is_none());
+    assert!(hashmap.contains(&1, &guard));
+    assert_eq!(hashmap.get(&2, &guard).map(|session| session.id), Some(2));
+    assert!(hashmap.get(&3, &guard).is_none());
+
+    let replaced = hashmap.insert(session(1), &guard).unwrap();
+    let removed = hashmap.remove(&2, &guard).unwrap();
+    assert!(hashmap.remove(&2, &guard).is_none());
+    drop(guard);
+
+    assert_eq!(replaced.take_ownership(&mut context).id, 1);
+    assert_eq!(removed.take_ownership(&mut context).id, 2);
+}
@@ -0,0 +1,455 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::collections::list::iterator::Iter;
+use crate::collections::list::raw::{RawIter, RawList, RawNode};
+use crate::collections::list::reference::ConcurrentRef;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// An element of an [`RcuConcurrentList`] with the lock of its link toward the back.
+///
+/// The flag is set once the element is removed from the list.
+struct Locked<T> {
+    lock: Mutex<bool>,
+    data: T,
+}
+
+impl<T> Deref for Locked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+type Node<T> = RawNode<Locked<T>>;
+
+/// A node removed from an [`RcuConcurrentList`].
+#[repr(transparent)]
+pub struct LockedNode<T>(Node<T>);
+
+impl<T> LockedNode<T> {
+    pub(crate) fn into_data(self) -> T {
+        self.0.into_data().data
+    }
+}
+
+impl<T> Deref for LockedNode<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0.data
+    }
+}
+
+impl<T> DerefMut for LockedNode<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0.data
+    }
+}
+
+/// Defines a RCU doubly linked list with concurrent writers.
+///
+/// Unlike [`RcuList`], there is no lock over the whole list. Every element has its own
+/// lock, protecting its link toward the back of the list. A writer walking the list
+/// locks the next element before releasing the current one, so writers in different
+/// regions of the list proceed in parallel. The readers never take any lock.
+///
+/// # Limitations
+///
+/// ##### Writer Overhead
+///
+/// Every writing operation locks each element it walks over, which costs more than
+/// [`RcuList`] when there is a single writer. The writers also need a RCU guard to make
+/// sure the elements they lock are not freed.
+///
+/// ##### List Length
+///
+/// Because a writer might concurrently modify the list, the amount of node might change
+/// at any moment. To prevent user error (e.g. allocate an array for each node), there is
+/// no `.len()` method.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuConcurrentList<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuConcurrentList<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing a RCU reference.
+///
+/// [`RcuList`]: crate::collections::list::container::RcuList
+pub struct RcuConcurrentList<T, F = RcuDefaultFlavor> {
+    raw: RawList<Locked<T>>,
+    front: Mutex<bool>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuConcurrentList<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU linked list.
+    pub fn new() -> Arc<Self> {
+        let mut list = Arc::new(RcuConcurrentList {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawList::new() },
+            front: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuConcurrentList"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw list is in a stable memory location.
+        // SAFETY: All the nodes are removed upon dropping.
+        unsafe { Arc::<Self>::get_mut(&mut list).unwrap().raw.init() };
+
+        list
+    }
+
+    /// Locks the link of a node toward the back of the list.
+    ///
+    /// A null node locks the link of the front of the list.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    ///
+    /// The node must be null or have been inserted in the list.
+    unsafe fn lock(&self, node: *mut Node<T>) -> MutexGuard<'_, bool> {
+        let lock = match node.as_ref() {
+            None => &self.front,
+            Some(node) => &node.lock,
+        };
+
+        // The links are never left inconsistent by a panicking writer.
+        lock.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Walks from the front of the list until the predicate matches the next node.
+    ///
+    /// Returns the last node before the match and its locked link. The node is null
+    /// and the link is the front of the list if the first node matches. The next node
+    /// is null if no node matches.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    unsafe fn walk<P>(&self, mut predicate: P) -> (*mut Node<T>, MutexGuard<'_, bool>)
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut node = std::ptr::null_mut();
+        let mut lock = self.lock(node);
+
+        loop {
+            // SAFETY: The link is protected by the lock of the node.
+            let next = unsafe { self.raw.node_backward(node) };
+
+            // SAFETY: The next node can't be removed while the link is locked.
+            match unsafe { next.as_ref() } {
+                Some(element) if !predicate(element) => {
+                    lock = self.lock(next);
+                    node = next;
+                }
+                _ => return (node, lock),
+            }
+        }
+    }
+
+    /// Adds an element to the back of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block on the writer modifying the back of the list.
+    pub fn push_back<G>(&self, data: T, guard: &G)
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+        let node = RawNode::new(Locked {
+            lock: Default::default(),
+            data,
+        });
+
+        loop {
+            // SAFETY: The RCU critical section is enforced.
+            let last = unsafe { self.raw.get_back() } as *mut Node<T>;
+
+            // SAFETY: The RCU critical section is enforced.
+            let lock = unsafe { self.lock(last) };
+
+            // SAFETY: The link is protected by the lock of the node.
+            if *lock || !unsafe { self.raw.node_backward(last) }.is_null() {
+                continue;
+            }
+
+            // SAFETY: The link is protected by the lock of the node.
+            unsafe { self.raw.insert_forward(std::ptr::null_mut(), node) };
+            return;
+        }
+    }
+
+    /// Adds an element to the front of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block on the writer modifying the front of the list.
+    pub fn push_front<G>(&self, data: T, guard: &G)
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+        let node = RawNode::new(Locked {
+            lock: Default::default(),
+            data,
+        });
+
+        // SAFETY: The RCU critical section is enforced.
+        let _lock = unsafe { self.lock(std::ptr::null_mut()) };
+
+        // SAFETY: The link is protected by the lock of the front.
+        unsafe { self.raw.insert_backward(std::ptr::null_mut(), node) };
+    }
+
+    /// Inserts an element in front of the first greater element, scanning from the front.
+    ///
+    /// The list stays sorted from front to back if it was already sorted.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time. The writers
+    /// inserting in different regions of the list don't wait for each other.
+    pub fn insert_sorted<G>(&self, data: T, guard: &G)
+    where
+        T: Send + Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        let (node, _lock) = unsafe { self.walk(|element| element > &data) };
+
+        let element = RawNode::new(Locked {
+            lock: Default::default(),
+            data,
+        });
+
+        // SAFETY: The link is protected by the lock of the node.
+        unsafe { self.raw.insert_backward(node, element) };
+    }
+
+    /// Removes an element from the back of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block on the writer modifying the back of the list.
+    pub fn pop_back<G>(&self, guard: &G) -> Option<ConcurrentRef<T, F>>
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        loop {
+            // SAFETY: The RCU critical section is enforced.
+            let mut iter = unsafe { RawIter::<Locked<T>, true>::from_back(&self.raw) };
+
+            // SAFETY: The RCU critical section is enforced.
+            let last = NonNull::new(unsafe { iter.next() } as *mut Node<T>)?;
+
+            // SAFETY: The RCU critical section is enforced.
+            let prev = unsafe { iter.next() } as *mut Node<T>;
+
+            // SAFETY: The RCU critical section is enforced.
+            // SAFETY: The locks are always taken from the front to the back.
+            let prev_lock = unsafe { self.lock(prev) };
+            let mut last_lock = unsafe { self.lock(last.as_ptr()) };
+
+            // SAFETY: The links are protected by the locks of the nodes.
+            if *prev_lock
+                || *last_lock
+                || unsafe { self.raw.node_backward(prev) } != last.as_ptr()
+                || !unsafe { self.raw.node_backward(last.as_ptr()) }.is_null()
+            {
+                continue;
+            }
+
+            // SAFETY: The links are protected by the locks of the nodes.
+            // SAFETY: The RCU grace period is enforced using `ConcurrentRef<T, F>`.
+            unsafe { self.raw.remove(last) };
+            *last_lock = true;
+
+            return Some(ConcurrentRef::new(last.cast::<LockedNode<T>>()).tracked(&self.refs));
+        }
+    }
+
+    /// Removes an element from the front of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block on the writer modifying the front of the list.
+    pub fn pop_front<G>(&self, guard: &G) -> Option<ConcurrentRef<T, F>>
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.remove_first_where(|_| true, guard)
+    }
+
+    /// Removes the first element matching the predicate, scanning from the front.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time. The writers
+    /// modifying different regions of the list don't wait for each other.
+    pub fn remove_first_where<P, G>(
+        &self,
+        mut predicate: P,
+        guard: &G,
+    ) -> Option<ConcurrentRef<T, F>>
+    where
+        T: Send,
+        P: FnMut(&T) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        let (node, _lock) = unsafe { self.walk(|element| predicate(element)) };
+
+        // SAFETY: The link is protected by the lock of the node.
+        let next = NonNull::new(unsafe { self.raw.node_backward(node) })?;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The locks are always taken from the front to the back.
+        let mut next_lock = unsafe { self.lock(next.as_ptr()) };
+
+        // SAFETY: The links are protected by the locks of the nodes.
+        // SAFETY: The RCU grace period is enforced using `ConcurrentRef<T, F>`.
+        unsafe { self.raw.remove(next) };
+        *next_lock = true;
+
+        Some(ConcurrentRef::new(next.cast::<LockedNode<T>>()).tracked(&self.refs))
+    }
+
+    /// Returns `true` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*1*) time.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    /// Returns `true` if the list contains an element equal to the given value.
+    pub fn contains<G>(&self, x: &T, guard: &G) -> bool
+    where
+        T: PartialEq,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_forward(guard).any(|item| item == x)
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is empty.
+    pub fn back<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The node pointer can be converted to a reference.
+        unsafe { self.raw.get_back().as_ref() }.map(|r| &r.data)
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is empty.
+    pub fn front<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The node pointer can be converted to a reference.
+        unsafe { self.raw.get_front().as_ref() }.map(|r| &r.data)
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all items from back to front.
+    pub fn iter_forward<'me, 'guard, G>(
+        &'me self,
+        guard: &'guard G,
+    ) -> impl Iterator<Item = &'guard T> + 'guard
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU critical section is enforced.
+        Iter::new(
+            unsafe { RawIter::<Locked<T>, true>::from_back(&self.raw) },
+            guard,
+        )
+        .map(|element| &element.data)
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all items from front to back.
+    pub fn iter_reverse<'me, 'guard, G>(
+        &'me self,
+        guard: &'guard G,
+    ) -> impl Iterator<Item = &'guard T> + 'guard
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU critical section is enforced.
+        Iter::new(
+            unsafe { RawIter::<Locked<T>, false>::from_front(&self.raw) },
+            guard,
+        )
+        .map(|element| &element.data)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuConcurrentList`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuConcurrentList<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuConcurrentList`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuConcurrentList<T, F>
+where
+    T: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuConcurrentList<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The RCU grace period is not needed because there are no other readers.
+        while let Some(mut ptr) = NonNull::new(unsafe { self.raw.remove_back() }) {
+            drop(unsafe { Box::from_raw(ptr.as_mut()) });
+        }
+    }
+}
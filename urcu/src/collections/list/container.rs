@@ -1,16 +1,21 @@
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::pin::Pin;
 use std::ptr::NonNull;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError};
 
 use anyhow::{bail, Result};
 
-use crate::collections::list::iterator::Iter;
+use crate::collections::list::cursor::CursorMut;
+use crate::collections::list::iterator::{IntoIter, Iter};
 use crate::collections::list::raw::{RawIter, RawList, RawNode};
 use crate::collections::list::reference::Ref;
+use crate::rcu::context::{RcuContext, RcuReadContext};
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
 use crate::rcu::guard::RcuGuard;
+use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::RcuRefTracker;
 use crate::utility::*;
 
 /// Defines a RCU doubly linked list.
@@ -18,6 +23,10 @@ use crate::utility::*;
 /// This linked list supports multiple concurrents readers at any time, but only a single
 /// writer at a time. The list uses an internal lock for writing operations.
 ///
+/// A list created with [`RcuList::with_capacity`] is bounded. Adding an element to a
+/// full list fails, except with [`RcuList::push_back_bounded`] and
+/// [`RcuList::push_front_bounded`] which evict an element from the opposite end.
+///
 /// # Limitations
 ///
 /// ##### Mutable References
@@ -42,7 +51,9 @@ use crate::utility::*;
 /// prevent any other thread from accessing a RCU reference.
 pub struct RcuList<T, F = RcuDefaultFlavor> {
     raw: RawList<T>,
-    mutex: Mutex<()>,
+    mutex: Mutex<usize>,
+    capacity: Option<usize>,
+    refs: RcuRefTracker,
     _unsend: PhantomUnsend<F>,
     _unsync: PhantomUnsync<F>,
 }
@@ -53,10 +64,27 @@ where
 {
     /// Creates a new RCU linked list.
     pub fn new() -> Arc<Self> {
+        Self::with_capacity_opt(None)
+    }
+
+    /// Creates a new RCU linked list holding at most `capacity` elements.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity(capacity: usize) -> Arc<Self> {
+        assert!(capacity > 0, "capacity of the list must be non-zero");
+
+        Self::with_capacity_opt(Some(capacity))
+    }
+
+    fn with_capacity_opt(capacity: Option<usize>) -> Arc<Self> {
         let mut list = Arc::new(RcuList {
             // SAFETY: Initialisation is properly called.
             raw: unsafe { RawList::new() },
             mutex: Default::default(),
+            capacity,
+            refs: RcuRefTracker::new::<F>("RcuList"),
             _unsend: PhantomData,
             _unsync: PhantomData,
         });
@@ -77,65 +105,397 @@ where
         self.iter_forward(guard).any(|item| item == x)
     }
 
-    fn with_mutex<C, R>(&self, callback: C) -> Result<R>
+    /// Returns `true` if the list contains an element whose key is equal to the given key.
+    ///
+    /// The key is extracted from each element, so the element type doesn't need to be
+    /// compared against a fully constructed value.
+    pub fn contains_by_key<K, P, G>(&self, key: &K, mut extract: P, guard: &G) -> bool
+    where
+        K: PartialEq + ?Sized,
+        P: FnMut(&T) -> &K,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_forward(guard).any(|item| extract(item) == key)
+    }
+
+    /// Returns a reference to the first element matching the predicate, from front to back.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*n*) time.
+    pub fn find<'me, 'guard, G, P>(
+        &'me self,
+        mut predicate: P,
+        guard: &'guard G,
+    ) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        P: FnMut(&T) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_reverse(guard).find(|item| predicate(item))
+    }
+
+    /// Returns the maximum amount of elements in the list, if the list is bounded.
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Returns `true` if the list contains an element equal to the given value.
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn contains_with<C>(&self, context: &C, x: &T) -> bool
     where
-        C: FnOnce() -> R,
+        T: PartialEq,
+        C: RcuReadContext<Flavor = F>,
     {
-        match self.mutex.lock() {
-            Err(_) => bail!("mutex of the list has been poisoned"),
-            Ok(guard) => {
-                let result = callback();
-                drop(guard);
-                Ok(result)
+        self.contains(x, &context.rcu_read_lock())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        // The length is updated after each node is linked or unlinked, so it is still
+        // consistent if a writer panics while holding the lock.
+        self.mutex.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce(&mut usize) -> R,
+    {
+        let mut guard = self.lock();
+        let result = callback(&mut guard);
+        drop(guard);
+        result
+    }
+
+    /// Returns the first node matching the predicate, scanning from the front.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must hold the writer lock.
+    unsafe fn find_first<P>(&self, mut predicate: P) -> *mut RawNode<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        // SAFETY: There is mutual exclusion between writers.
+        let mut node = unsafe { self.raw.node_backward(std::ptr::null_mut()) };
+
+        // SAFETY: The nodes can't be removed while the writer lock is held.
+        while let Some(element) = unsafe { node.as_ref() } {
+            if predicate(element) {
+                break;
             }
+
+            // SAFETY: There is mutual exclusion between writers.
+            node = unsafe { self.raw.node_backward(node) };
         }
+
+        node
+    }
+
+    /// Inserts an element toward the front of the first element matching the predicate,
+    /// or at the back of the list if no element matches.
+    ///
+    /// The predicate receives the index of the element, the element and the inserted data.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must hold the writer lock.
+    unsafe fn insert_before_first<P>(
+        &self,
+        len: &mut usize,
+        data: T,
+        mut predicate: P,
+    ) -> Result<()>
+    where
+        P: FnMut(usize, &T, &T) -> bool,
+    {
+        if self.capacity.is_some_and(|capacity| *len >= capacity) {
+            bail!("list is full (capacity: {})", *len);
+        }
+
+        // SAFETY: There is mutual exclusion between writers.
+        let mut node = unsafe { self.raw.node_backward(std::ptr::null_mut()) };
+        let mut index = 0;
+
+        // SAFETY: The nodes can't be removed while the writer lock is held.
+        while let Some(element) = unsafe { node.as_ref() } {
+            if predicate(index, element, &data) {
+                break;
+            }
+
+            // SAFETY: There is mutual exclusion between writers.
+            node = unsafe { self.raw.node_backward(node) };
+            index += 1;
+        }
+
+        // SAFETY: There is mutual exclusion between writers.
+        unsafe { self.raw.insert_forward(node, RawNode::new(data)) };
+
+        *len += 1;
+        Ok(())
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must hold the writer lock.
+    unsafe fn push_back_locked(&self, len: &mut usize, data: T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        let evicted = if self.capacity.is_some_and(|capacity| *len >= capacity) {
+            *len -= 1;
+
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            NonNull::new(unsafe { self.raw.remove_front() })
+                .map(|node| Ref::new(node).tracked(&self.refs))
+        } else {
+            None
+        };
+
+        // SAFETY: There is mutual exclusion between writers.
+        unsafe {
+            let node = RawNode::new(data);
+            self.raw.insert_back(node);
+        }
+
+        *len += 1;
+        evicted
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must hold the writer lock.
+    unsafe fn push_front_locked(&self, len: &mut usize, data: T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        let evicted = if self.capacity.is_some_and(|capacity| *len >= capacity) {
+            *len -= 1;
+
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            NonNull::new(unsafe { self.raw.remove_back() })
+                .map(|node| Ref::new(node).tracked(&self.refs))
+        } else {
+            None
+        };
+
+        // SAFETY: There is mutual exclusion between writers.
+        unsafe {
+            let node = RawNode::new(data);
+            self.raw.insert_front(node);
+        }
+
+        *len += 1;
+        evicted
     }
 
     /// Adds an element to the back of a list.
     ///
+    /// It fails if the list is full.
+    ///
     /// #### Note
     ///
     /// This operation may block.
     pub fn push_back(&self, data: T) -> Result<()> {
-        self.with_mutex(|| {
-            // SAFETY: There is mutual exclusion between writers.
-            unsafe {
-                let node = RawNode::new(data);
-                self.raw.insert_back(node);
+        self.with_mutex(|len| {
+            if self.capacity.is_some_and(|capacity| *len >= capacity) {
+                bail!("list is full (capacity: {})", *len);
             }
+
+            // SAFETY: There is mutual exclusion between writers.
+            unsafe { self.raw.insert_back(RawNode::new(data)) };
+
+            *len += 1;
+            Ok(())
+        })
+    }
+
+    /// Adds an element to the back of a bounded list.
+    ///
+    /// If the list is full, the element at the front is removed and returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_back_bounded(&self, data: T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|len| {
+            // SAFETY: The writer lock is held.
+            unsafe { self.push_back_locked(len, data) }
+        })
+    }
+
+    /// Adds the elements of an iterator to the back of a list, one after the other.
+    ///
+    /// The writer lock is only taken once for all the elements. If the list is full, the
+    /// elements at the front are removed and returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_back_iter<I>(&self, iter: I) -> Vec<Ref<T, F>>
+    where
+        T: Send,
+        I: IntoIterator<Item = T>,
+    {
+        self.with_mutex(|len| {
+            iter.into_iter()
+                // SAFETY: The writer lock is held.
+                .filter_map(|data| unsafe { self.push_back_locked(len, data) })
+                .collect()
         })
     }
 
     /// Adds an element to the front of a list.
     ///
+    /// It fails if the list is full.
+    ///
     /// #### Note
     ///
     /// This operation may block.
     pub fn push_front(&self, data: T) -> Result<()> {
-        self.with_mutex(|| {
+        self.with_mutex(|len| {
+            if self.capacity.is_some_and(|capacity| *len >= capacity) {
+                bail!("list is full (capacity: {})", *len);
+            }
+
             // SAFETY: There is mutual exclusion between writers.
-            unsafe {
-                let node = RawNode::new(data);
-                self.raw.insert_front(node);
+            unsafe { self.raw.insert_front(RawNode::new(data)) };
+
+            *len += 1;
+            Ok(())
+        })
+    }
+
+    /// Adds an element to the front of a bounded list.
+    ///
+    /// If the list is full, the element at the back is removed and returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_front_bounded(&self, data: T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|len| {
+            // SAFETY: The writer lock is held.
+            unsafe { self.push_front_locked(len, data) }
+        })
+    }
+
+    /// Adds the elements of an iterator to the front of a list, one after the other.
+    ///
+    /// The writer lock is only taken once for all the elements. If the list is full, the
+    /// elements at the back are removed and returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn push_front_iter<I>(&self, iter: I) -> Vec<Ref<T, F>>
+    where
+        T: Send,
+        I: IntoIterator<Item = T>,
+    {
+        self.with_mutex(|len| {
+            iter.into_iter()
+                // SAFETY: The writer lock is held.
+                .filter_map(|data| unsafe { self.push_front_locked(len, data) })
+                .collect()
+        })
+    }
+
+    /// Inserts an element at the given index, shifting the following elements toward the back.
+    ///
+    /// The index is counted from the front of the list. It fails if the index is greater
+    /// than the length of the list or if the list is full.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time.
+    pub fn insert_at(&self, index: usize, data: T) -> Result<()>
+    where
+        T: Send,
+    {
+        self.with_mutex(|len| {
+            if index > *len {
+                bail!("index {} is out of bounds (length: {})", index, len);
             }
+
+            // SAFETY: The writer lock is held.
+            unsafe { self.insert_before_first(len, data, |position, _, _| position == index) }
         })
     }
 
+    /// Inserts an element in front of the first greater element, scanning from the front.
+    ///
+    /// The list stays sorted from front to back if it was already sorted. It fails if the
+    /// list is full.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time.
+    pub fn insert_sorted(&self, data: T) -> Result<()>
+    where
+        T: Send + Ord,
+    {
+        self.with_mutex(|len| {
+            // SAFETY: The writer lock is held.
+            unsafe { self.insert_before_first(len, data, |_, element, data| element > data) }
+        })
+    }
+
+    /// Returns a cursor pointing to the front element of the list.
+    ///
+    /// If the list is empty, the cursor points to the ghost position.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block. The writer lock is held until the cursor is dropped.
+    pub fn cursor_front_mut(&self) -> CursorMut<'_, T, F> {
+        CursorMut::new(&self.raw, &self.refs, self.capacity, self.lock())
+    }
+
+    /// Returns a cursor pointing to the front element of the list, or `None` if another
+    /// writer holds the lock.
+    ///
+    /// It is useful for low-priority maintenance that can be skipped rather than waiting
+    /// for the other writers.
+    pub fn try_cursor_front_mut(&self) -> Option<CursorMut<'_, T, F>> {
+        let len = match self.mutex.try_lock() {
+            Err(TryLockError::WouldBlock) => return None,
+            Err(TryLockError::Poisoned(error)) => error.into_inner(),
+            Ok(len) => len,
+        };
+
+        Some(CursorMut::new(&self.raw, &self.refs, self.capacity, len))
+    }
+
     /// Removes an element from the back of a list.
     ///
     /// #### Note
     ///
     /// This operation may block.
-    pub fn pop_back(&self) -> Result<Option<Ref<T, F>>>
+    pub fn pop_back(&self) -> Option<Ref<T, F>>
     where
         T: Send,
     {
-        self.with_mutex(|| {
+        self.with_mutex(|len| {
             // SAFETY: There is mutual exclusion between writers.
             // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
             let node = unsafe { self.raw.remove_back() };
 
-            NonNull::new(node).map(Ref::new)
+            NonNull::new(node).map(|node| {
+                *len -= 1;
+                Ref::new(node).tracked(&self.refs)
+            })
         })
     }
 
@@ -144,19 +504,199 @@ where
     /// #### Note
     ///
     /// This operation may block.
-    pub fn pop_front(&self) -> Result<Option<Ref<T, F>>>
+    pub fn pop_front(&self) -> Option<Ref<T, F>>
     where
         T: Send,
     {
-        self.with_mutex(|| {
+        self.with_mutex(|len| {
             // SAFETY: There is mutual exclusion between writers.
             // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
             let node = unsafe { self.raw.remove_front() };
 
-            NonNull::new(node).map(Ref::new)
+            NonNull::new(node).map(|node| {
+                *len -= 1;
+                Ref::new(node).tracked(&self.refs)
+            })
+        })
+    }
+
+    /// Removes up to `n` elements from the back of a list.
+    ///
+    /// The elements are returned in the order they were removed, from the back. The
+    /// writer lock is acquired only once for the whole batch.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_back_n(&self, n: usize) -> Vec<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|len| {
+            let mut refs = Vec::with_capacity(n.min(*len));
+
+            while refs.len() < n {
+                // SAFETY: There is mutual exclusion between writers.
+                // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+                let Some(node) = NonNull::new(unsafe { self.raw.remove_back() }) else {
+                    break;
+                };
+
+                *len -= 1;
+                refs.push(Ref::new(node).tracked(&self.refs));
+            }
+
+            refs
+        })
+    }
+
+    /// Removes up to `n` elements from the front of a list.
+    ///
+    /// The elements are returned in the order they were removed, from the front. The
+    /// writer lock is acquired only once for the whole batch.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_front_n(&self, n: usize) -> Vec<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|len| {
+            let mut refs = Vec::with_capacity(n.min(*len));
+
+            while refs.len() < n {
+                // SAFETY: There is mutual exclusion between writers.
+                // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+                let Some(node) = NonNull::new(unsafe { self.raw.remove_front() }) else {
+                    break;
+                };
+
+                *len -= 1;
+                refs.push(Ref::new(node).tracked(&self.refs));
+            }
+
+            refs
+        })
+    }
+
+    /// Retains only the elements matching the predicate.
+    ///
+    /// The elements are visited from front to back. The removed elements are returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time.
+    pub fn retain<P>(&self, mut predicate: P) -> Vec<Ref<T, F>>
+    where
+        T: Send,
+        P: FnMut(&T) -> bool,
+    {
+        self.with_mutex(|len| {
+            let mut refs = Vec::new();
+
+            // SAFETY: There is mutual exclusion between writers.
+            let mut node = unsafe { self.raw.node_backward(std::ptr::null_mut()) };
+
+            while let Some(current) = NonNull::new(node) {
+                // SAFETY: There is mutual exclusion between writers.
+                node = unsafe { self.raw.node_backward(node) };
+
+                // SAFETY: The node can't be removed while the writer lock is held.
+                if predicate(unsafe { current.as_ref() }) {
+                    continue;
+                }
+
+                // SAFETY: There is mutual exclusion between writers.
+                // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+                unsafe { self.raw.remove(current) };
+
+                *len -= 1;
+                refs.push(Ref::new(current).tracked(&self.refs));
+            }
+
+            refs
         })
     }
 
+    /// Removes the first element matching the predicate, scanning from the front.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time.
+    pub fn remove_first_where<P>(&self, predicate: P) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        P: FnMut(&T) -> bool,
+    {
+        self.with_mutex(|len| {
+            // SAFETY: The writer lock is held.
+            let node = NonNull::new(unsafe { self.find_first(predicate) })?;
+
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            unsafe { self.raw.remove(node) };
+
+            *len -= 1;
+            Some(Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Replaces the first element matching the predicate, scanning from the front.
+    ///
+    /// The readers observe either the old or the new element at the same position. If no
+    /// element matches, nothing is inserted and `None` is returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and computes linearly in *O*(*n*) time.
+    pub fn replace_first_where<P>(&self, predicate: P, data: T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        P: FnMut(&T) -> bool,
+    {
+        self.with_mutex(|_| {
+            // SAFETY: The writer lock is held.
+            let node = NonNull::new(unsafe { self.find_first(predicate) })?;
+
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            unsafe { self.raw.replace(node, RawNode::new(data)) };
+
+            Some(Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Removes an element from the back of a list and takes its ownership.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and blocks for a RCU grace period if an element is removed.
+    pub fn pop_back_owned<C>(&self, context: &mut C) -> Option<T>
+    where
+        T: Send + Unpin + 'static,
+        F: 'static,
+        C: RcuContext<Flavor = F>,
+    {
+        self.pop_back()
+            .map(|node| node.take_ownership(context).into_inner())
+    }
+
+    /// Removes an element from the front of a list and takes its ownership.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block and blocks for a RCU grace period if an element is removed.
+    pub fn pop_front_owned<C>(&self, context: &mut C) -> Option<T>
+    where
+        T: Send + Unpin + 'static,
+        F: 'static,
+        C: RcuContext<Flavor = F>,
+    {
+        self.pop_front()
+            .map(|node| node.take_ownership(context).into_inner())
+    }
+
     /// Returns `true` if the list is empty.
     ///
     /// #### Note
@@ -192,6 +732,128 @@ where
         unsafe { self.raw.get_front().as_ref() }.map(|r| r.deref())
     }
 
+    /// Calls a closure with a reference to the back element, or `None` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn back_with<C, P, R>(&self, context: &C, callback: P) -> R
+    where
+        C: RcuReadContext<Flavor = F>,
+        P: FnOnce(Option<&T>) -> R,
+    {
+        callback(self.back(&context.rcu_read_lock()))
+    }
+
+    /// Calls a closure with a reference to the front element, or `None` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn front_with<C, P, R>(&self, context: &C, callback: P) -> R
+    where
+        C: RcuReadContext<Flavor = F>,
+        P: FnOnce(Option<&T>) -> R,
+    {
+        callback(self.front(&context.rcu_read_lock()))
+    }
+
+    /// Provides a reference to the element at the given index, or `None` if it is out of bounds.
+    ///
+    /// The index is counted from the front of the list.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*n*) time.
+    pub fn get<'me, 'guard, G>(&'me self, index: usize, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_reverse(guard).nth(index)
+    }
+
+    /// Provides a reference to the element at the given index, or `None` if it is out of bounds.
+    ///
+    /// The index is counted from the back of the list.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*n*) time.
+    pub fn get_from_back<'me, 'guard, G>(
+        &'me self,
+        index: usize,
+        guard: &'guard G,
+    ) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_forward(guard).nth(index)
+    }
+
+    /// Provides a pinned reference to the element at the given index, or `None` if it is
+    /// out of bounds.
+    ///
+    /// #### Note
+    ///
+    /// The elements of the list are never moved until they are dropped, unless they
+    /// implement [`Unpin`].
+    pub fn get_pinned<'me, 'guard, G>(
+        &'me self,
+        index: usize,
+        guard: &'guard G,
+    ) -> Option<Pin<&'guard T>>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The element is boxed and never moved until it is dropped.
+        self.get(index, guard)
+            .map(|value| unsafe { Pin::new_unchecked(value) })
+    }
+
+    /// Returns the index of the first element matching the predicate, if any.
+    ///
+    /// The index is counted from the front of the list.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*n*) time.
+    pub fn position<G, P>(&self, predicate: P, guard: &G) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_reverse(guard).position(predicate)
+    }
+
+    /// Returns `true` if both lists contain equal elements in the same order.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*n*) time.
+    pub fn eq_with<G>(&self, other: &Self, guard: &G) -> bool
+    where
+        T: PartialEq,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_reverse(guard).eq(other.iter_reverse(guard))
+    }
+
+    /// Compares the elements of both lists lexicographically, from front to back.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*n*) time.
+    pub fn cmp_with<G>(&self, other: &Self, guard: &G) -> std::cmp::Ordering
+    where
+        T: Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_reverse(guard).cmp(other.iter_reverse(guard))
+    }
+
     /// Returns an iterator over the list.
     ///
     /// The iterator yields all items from back to front.
@@ -210,11 +872,87 @@ where
     pub fn iter_reverse<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, T, G, false>
     where
         'me: 'guard,
-        G: RcuGuard,
+        G: RcuGuard<Flavor = F>,
     {
         // SAFETY: The RCU critical section is enforced.
         Iter::new(unsafe { RawIter::<T, false>::from_front(&self.raw) }, guard)
     }
+
+    /// Returns an iterator over the list, starting at the given index.
+    ///
+    /// The index is counted from the front of the list and the iterator yields the items
+    /// from there to the back. It yields nothing if the index is out of bounds.
+    ///
+    /// #### Note
+    ///
+    /// * Skipping to the index computes linearly in *O*(*n*) time.
+    pub fn iter_from<'me, 'guard, G>(
+        &'me self,
+        index: usize,
+        guard: &'guard G,
+    ) -> Iter<'guard, T, G, false>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let mut iter = self.iter_reverse(guard);
+        if index > 0 {
+            iter.nth(index - 1);
+        }
+
+        iter
+    }
+
+    /// Removes the front element of an exclusively borrowed list.
+    pub(crate) fn take_front(&mut self) -> Option<Box<RawNode<T>>> {
+        // SAFETY: The list is borrowed mutably, there are no other writers or readers.
+        NonNull::new(unsafe { self.raw.remove_front() })
+            .map(|node| unsafe { Box::from_raw(node.as_ptr()) })
+    }
+
+    /// Removes the back element of an exclusively borrowed list.
+    pub(crate) fn take_back(&mut self) -> Option<Box<RawNode<T>>> {
+        // SAFETY: The list is borrowed mutably, there are no other writers or readers.
+        NonNull::new(unsafe { self.raw.remove_back() })
+            .map(|node| unsafe { Box::from_raw(node.as_ptr()) })
+    }
+
+    /// Returns a value formatting the elements of the list with [`Debug`].
+    ///
+    /// The elements are formatted from back to front.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn debug<'me, 'guard, G>(&'me self, guard: &'guard G) -> impl std::fmt::Debug + 'guard
+    where
+        'me: 'guard,
+        T: std::fmt::Debug,
+        G: RcuGuard<Flavor = F>,
+    {
+        DebugWith(move |f: &mut std::fmt::Formatter<'_>| {
+            f.debug_list().entries(self.iter_forward(guard)).finish()
+        })
+    }
+}
+
+/// Consumes the list into an iterator yielding the elements from front to back.
+///
+/// No RCU synchronization is needed since the list is exclusively owned. A list shared
+/// through an [`Arc`] can be recovered with [`Arc::try_unwrap`].
+///
+/// #### Note
+///
+/// The elements must implement [`Unpin`] since they may have been pinned in the list.
+impl<T, F> IntoIterator for RcuList<T, F>
+where
+    T: Unpin,
+    F: RcuFlavor,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self)
+    }
 }
 
 /// #### Safety
@@ -239,6 +977,8 @@ where
 
 impl<T, F> Drop for RcuList<T, F> {
     fn drop(&mut self) {
+        self.refs.report();
+
         // SAFETY: The RCU grace period is not needed because there are no other readers.
         while let Some(mut ptr) = NonNull::new(unsafe { self.raw.remove_back() }) {
             drop(unsafe { Box::from_raw(ptr.as_mut()) });
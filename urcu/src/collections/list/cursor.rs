@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::MutexGuard;
+
+use crate::collections::list::raw::{RawList, RawNode};
+use crate::collections::list::reference::Ref;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::tracker::RcuRefTracker;
+
+/// A cursor over an [`RcuList`] with editing operations.
+///
+/// The cursor moves from the front to the back of the list. It points either to an
+/// element or to a "ghost" position between the back and the front of the list, where
+/// [`CursorMut::current`] returns `None`.
+///
+/// #### Note
+///
+/// The writer lock of the list is held until the cursor is dropped. The readers are
+/// never blocked and observe each edit as soon as it is done.
+///
+/// [`RcuList`]: crate::collections::list::container::RcuList
+pub struct CursorMut<'list, T, F>
+where
+    F: RcuFlavor,
+{
+    raw: &'list RawList<T>,
+    refs: &'list RcuRefTracker,
+    capacity: Option<usize>,
+    len: MutexGuard<'list, usize>,
+    current: *mut RawNode<T>,
+    index: Option<usize>,
+    _context: PhantomData<F>,
+}
+
+impl<'list, T, F> CursorMut<'list, T, F>
+where
+    F: RcuFlavor,
+{
+    pub(crate) fn new(
+        raw: &'list RawList<T>,
+        refs: &'list RcuRefTracker,
+        capacity: Option<usize>,
+        len: MutexGuard<'list, usize>,
+    ) -> Self {
+        let mut cursor = Self {
+            raw,
+            refs,
+            capacity,
+            len,
+            current: std::ptr::null_mut(),
+            index: None,
+            _context: PhantomData,
+        };
+
+        cursor.move_next();
+        cursor
+    }
+
+    fn is_full(&self) -> bool {
+        self.capacity.is_some_and(|capacity| *self.len >= capacity)
+    }
+
+    /// Returns the index of the element pointed by the cursor, or `None` for the ghost.
+    ///
+    /// The index is counted from the front of the list.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Returns the element pointed by the cursor, or `None` for the ghost.
+    pub fn current(&self) -> Option<&T> {
+        // SAFETY: The node can't be removed while the writer lock is held.
+        unsafe { self.current.as_ref() }.map(|node| node.deref())
+    }
+
+    /// Returns the element after the cursor, toward the back of the list.
+    pub fn peek_next(&self) -> Option<&T> {
+        // SAFETY: There is mutual exclusion between writers.
+        // SAFETY: The node can't be removed while the writer lock is held.
+        unsafe { self.raw.node_backward(self.current).as_ref() }.map(|node| node.deref())
+    }
+
+    /// Returns the element before the cursor, toward the front of the list.
+    pub fn peek_prev(&self) -> Option<&T> {
+        // SAFETY: There is mutual exclusion between writers.
+        // SAFETY: The node can't be removed while the writer lock is held.
+        unsafe { self.raw.node_forward(self.current).as_ref() }.map(|node| node.deref())
+    }
+
+    /// Moves the cursor to the next element, toward the back of the list.
+    ///
+    /// If the cursor points to the back element, it moves to the ghost. If the cursor
+    /// points to the ghost, it moves to the front element.
+    pub fn move_next(&mut self) {
+        // SAFETY: There is mutual exclusion between writers.
+        self.current = unsafe { self.raw.node_backward(self.current) };
+        self.index = match self.current.is_null() {
+            true => None,
+            false => Some(self.index.map_or(0, |index| index + 1)),
+        };
+    }
+
+    /// Moves the cursor to the previous element, toward the front of the list.
+    ///
+    /// If the cursor points to the front element, it moves to the ghost. If the cursor
+    /// points to the ghost, it moves to the back element.
+    pub fn move_prev(&mut self) {
+        // SAFETY: There is mutual exclusion between writers.
+        self.current = unsafe { self.raw.node_forward(self.current) };
+        self.index = match self.current.is_null() {
+            true => None,
+            false => Some(self.index.map_or(*self.len - 1, |index| index - 1)),
+        };
+    }
+
+    /// Inserts an element after the cursor, toward the back of the list.
+    ///
+    /// If the cursor points to the ghost, the element becomes the front of the list.
+    /// If the list is full, nothing is inserted and the element is returned back.
+    pub fn insert_after(&mut self, data: T) -> Result<(), T>
+    where
+        T: Send,
+    {
+        if self.is_full() {
+            return Err(data);
+        }
+
+        // SAFETY: There is mutual exclusion between writers.
+        unsafe { self.raw.insert_backward(self.current, RawNode::new(data)) };
+
+        *self.len += 1;
+
+        Ok(())
+    }
+
+    /// Inserts an element before the cursor, toward the front of the list.
+    ///
+    /// If the cursor points to the ghost, the element becomes the back of the list.
+    /// If the list is full, nothing is inserted and the element is returned back.
+    pub fn insert_before(&mut self, data: T) -> Result<(), T>
+    where
+        T: Send,
+    {
+        if self.is_full() {
+            return Err(data);
+        }
+
+        // SAFETY: There is mutual exclusion between writers.
+        unsafe { self.raw.insert_forward(self.current, RawNode::new(data)) };
+
+        *self.len += 1;
+        if let Some(index) = &mut self.index {
+            *index += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the element pointed by the cursor and moves to the next element.
+    ///
+    /// If the cursor points to the ghost, nothing is removed.
+    pub fn remove_current(&mut self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        let node = NonNull::new(self.current)?;
+
+        // SAFETY: There is mutual exclusion between writers.
+        self.current = unsafe { self.raw.node_backward(self.current) };
+
+        // SAFETY: There is mutual exclusion between writers.
+        // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+        unsafe { self.raw.remove(node) };
+
+        *self.len -= 1;
+        if self.current.is_null() {
+            self.index = None;
+        }
+
+        Some(Ref::new(node).tracked(self.refs))
+    }
+
+    /// Replaces the element pointed by the cursor, returning the old element.
+    ///
+    /// The readers observe either the old or the new element at the same position. If the
+    /// cursor points to the ghost, nothing is inserted and `None` is returned.
+    pub fn replace_current(&mut self, data: T) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        let node = NonNull::new(self.current)?;
+
+        // SAFETY: There is mutual exclusion between writers.
+        // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+        self.current = unsafe { self.raw.replace(node, RawNode::new(data)) };
+
+        Some(Ref::new(node).tracked(self.refs))
+    }
+}
@@ -1,6 +1,8 @@
 use std::ops::Deref;
 
+use crate::collections::list::container::RcuList;
 use crate::collections::list::raw::RawIter;
+use crate::rcu::flavor::RcuFlavor;
 use crate::rcu::guard::RcuGuard;
 
 /// An iterator over the nodes of an [`RcuList`].
@@ -36,3 +38,46 @@ where
         unsafe { self.raw.next().as_ref() }.map(|node| node.deref())
     }
 }
+
+/// An owning iterator over the elements of an [`RcuList`].
+///
+/// The iterator yields all items from front to back.
+///
+/// [`RcuList`]: crate::collections::list::container::RcuList
+pub struct IntoIter<T, F>
+where
+    F: RcuFlavor,
+{
+    list: RcuList<T, F>,
+}
+
+impl<T, F> IntoIter<T, F>
+where
+    F: RcuFlavor,
+{
+    pub(crate) fn new(list: RcuList<T, F>) -> Self {
+        Self { list }
+    }
+}
+
+impl<T, F> Iterator for IntoIter<T, F>
+where
+    T: Unpin,
+    F: RcuFlavor,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.take_front().map(|node| node.into_data())
+    }
+}
+
+impl<T, F> DoubleEndedIterator for IntoIter<T, F>
+where
+    T: Unpin,
+    F: RcuFlavor,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.take_back().map(|node| node.into_data())
+    }
+}
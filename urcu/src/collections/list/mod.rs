@@ -1,11 +1,19 @@
+pub(crate) mod concurrent;
 pub(crate) mod container;
+pub(crate) mod cursor;
 pub(crate) mod iterator;
+pub(crate) mod multi;
+#[cfg(not(feature = "fuzzing"))]
 pub(crate) mod raw;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod raw;
 pub(crate) mod reference;
 
 #[cfg(test)]
 mod test;
 
+pub use crate::collections::list::cursor::*;
 pub use crate::collections::list::iterator::*;
 pub use crate::collections::list::reference::*;
 
@@ -14,7 +22,9 @@ mod asserts {
 
     use static_assertions::{assert_impl_all, assert_not_impl_all};
 
+    use crate::collections::list::concurrent::RcuConcurrentList;
     use crate::collections::list::container::RcuList;
+    use crate::collections::list::multi::RcuMultiWriterList;
     use crate::rcu::default::{RcuDefaultFlavor, RcuDefaultGuard};
     use crate::utility::asserts::*;
 
@@ -38,6 +48,54 @@ mod asserts {
         assert_impl_all!(RcuList<SendAndSync, RcuDefaultFlavor>: Sync);
     }
 
+    mod rcu_multi_writer_list {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuMultiWriterList<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuMultiWriterList<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuMultiWriterList<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuMultiWriterList<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuMultiWriterList<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuMultiWriterList<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuMultiWriterList<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuMultiWriterList<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_concurrent_list {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuConcurrentList<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuConcurrentList<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuConcurrentList<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuConcurrentList<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuConcurrentList<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuConcurrentList<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuConcurrentList<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuConcurrentList<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_list_cursor_mut {
+        use super::*;
+
+        // T: Send + Sync
+        assert_not_impl_all!(CursorMut<'_, SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(CursorMut<'_, SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
     mod rcu_list_ref_owned {
         use super::*;
 
@@ -58,6 +116,46 @@ mod asserts {
         assert_impl_all!(RefOwned<SendAndSync>: Sync);
     }
 
+    mod rcu_concurrent_list_ref_owned {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(ConcurrentRefOwned<NotSendNotSync>: Send);
+        assert_not_impl_all!(ConcurrentRefOwned<NotSendNotSync>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(ConcurrentRefOwned<SendButNotSync>: Send);
+        assert_not_impl_all!(ConcurrentRefOwned<SendButNotSync>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(ConcurrentRefOwned<NotSendButSync>: Send);
+        assert_impl_all!(ConcurrentRefOwned<NotSendButSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(ConcurrentRefOwned<SendAndSync>: Send);
+        assert_impl_all!(ConcurrentRefOwned<SendAndSync>: Sync);
+    }
+
+    mod rcu_list_into_iter {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(IntoIter<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(IntoIter<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(IntoIter<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(IntoIter<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(IntoIter<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(IntoIter<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(IntoIter<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(IntoIter<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
     mod rcu_list_iter_forward {
         use super::*;
 
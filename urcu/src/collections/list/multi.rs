@@ -0,0 +1,246 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::list::iterator::Iter;
+use crate::collections::list::raw::{RawIter, RawMultiList, RawNode};
+use crate::collections::list::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU doubly linked list with lock-free insertions.
+///
+/// Unlike [`RcuList`], adding an element at either end never waits for another writer.
+/// The element is linked with a compare-and-swap on its end of the list and it is
+/// visible to the readers once the insertion returns. Removals still take the internal
+/// lock.
+///
+/// # Limitations
+///
+/// ##### Ordering
+///
+/// Elements added to the same end by a single thread keep their order. Elements added
+/// concurrently by multiple threads are linked in an arbitrary order.
+///
+/// ##### Removals
+///
+/// A removal waits for the concurrent insertions at the same end to finish linking
+/// their element.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuMultiWriterList<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuMultiWriterList<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing a RCU reference.
+///
+/// [`RcuList`]: crate::collections::list::container::RcuList
+pub struct RcuMultiWriterList<T, F = RcuDefaultFlavor> {
+    raw: RawMultiList<T>,
+    mutex: Mutex<()>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuMultiWriterList<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU linked list.
+    pub fn new() -> Arc<Self> {
+        let mut list = Arc::new(RcuMultiWriterList {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawMultiList::new() },
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuMultiWriterList"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw list is in a stable memory location.
+        // SAFETY: All the nodes are removed upon dropping.
+        unsafe { Arc::<Self>::get_mut(&mut list).unwrap().raw.init() };
+
+        list
+    }
+
+    /// Adds an element to the back of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation is lock-free.
+    pub fn push_back(&self, data: T)
+    where
+        T: Send,
+    {
+        self.raw.insert_back(RawNode::new(data));
+    }
+
+    /// Adds an element to the front of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation is lock-free.
+    pub fn push_front(&self, data: T)
+    where
+        T: Send,
+    {
+        self.raw.insert_front(RawNode::new(data));
+    }
+
+    /// Removes an element from the back of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_back(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between removers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let node = unsafe { self.raw.remove_back() };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Removes an element from the front of a list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_front(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between removers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let node = unsafe { self.raw.remove_front() };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Returns `true` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*1*) time.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    /// Returns `true` if the list contains an element equal to the given value.
+    pub fn contains<G>(&self, x: &T, guard: &G) -> bool
+    where
+        T: PartialEq,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter_forward(guard).any(|item| item == x)
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is empty.
+    pub fn back<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The node pointer can be converted to a reference.
+        unsafe { self.raw.get_back().as_ref() }.map(|r| r.deref())
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is empty.
+    pub fn front<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The node pointer can be converted to a reference.
+        unsafe { self.raw.get_front().as_ref() }.map(|r| r.deref())
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all items from back to front.
+    pub fn iter_forward<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, T, G, true>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU critical section is enforced.
+        Iter::new(
+            unsafe { RawIter::<T, true>::from_multi_back(&self.raw) },
+            guard,
+        )
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all items from front to back.
+    pub fn iter_reverse<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, T, G, false>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU critical section is enforced.
+        Iter::new(
+            unsafe { RawIter::<T, false>::from_multi_front(&self.raw) },
+            guard,
+        )
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce() -> R,
+    {
+        // The mutex protects no data, a panicking writer leaves nothing inconsistent.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback();
+        drop(guard);
+        result
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuMultiWriterList`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuMultiWriterList<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuMultiWriterList`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuMultiWriterList<T, F>
+where
+    T: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuMultiWriterList<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The RCU grace period is not needed because there are no other readers.
+        while let Some(mut ptr) = NonNull::new(unsafe { self.raw.remove_back() }) {
+            drop(unsafe { Box::from_raw(ptr.as_mut()) });
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use std::marker::PhantomData;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 use container_of::container_of;
 use urcu_cds_sys::list;
@@ -24,6 +26,10 @@ impl<T> RawNode<T> {
         let node = unsafe { node_ptr.as_mut_unchecked() };
         &mut node.handle
     }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
 }
 
 impl<T> Deref for RawNode<T> {
@@ -34,6 +40,12 @@ impl<T> Deref for RawNode<T> {
     }
 }
 
+impl<T> DerefMut for RawNode<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
 /// #### Safety
 ///
 /// It is safe to send a [`RawNode<T>`] to another thread if `T` is [`Send`].
@@ -131,7 +143,7 @@ impl<T> RawList<T> {
     ///
     /// The caller must be in a RCU critical section.
     pub unsafe fn get_back(&self) -> *const RawNode<T> {
-        let handle = self.back.next as *const list::Head;
+        let handle = crate::rcu::dereference(self.back.next);
 
         if handle != &self.front {
             container_of!(handle, RawNode<T>, handle)
@@ -144,7 +156,7 @@ impl<T> RawList<T> {
     ///
     /// The caller must be in a RCU critical section.
     pub unsafe fn get_front(&self) -> *const RawNode<T> {
-        let handle = self.front.prev as *const list::Head;
+        let handle = crate::rcu::dereference(self.front.prev);
 
         if handle != &self.back {
             container_of!(handle, RawNode<T>, handle)
@@ -153,14 +165,412 @@ impl<T> RawList<T> {
         }
     }
 
+    /// Returns the neighbour of a node toward the front of the list.
+    ///
+    /// A null node starts from the back of the list. A null pointer is returned when
+    /// the front of the list is reached.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The node must be null or in the list.
+    pub unsafe fn node_forward(&self, node: *mut RawNode<T>) -> *mut RawNode<T> {
+        let handle = match node.as_ref() {
+            None => self.back.next,
+            Some(node) => node.handle.next,
+        };
+
+        if handle as *const list::Head != &self.front {
+            container_of!(handle, RawNode<T>, handle)
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    /// Returns the neighbour of a node toward the back of the list.
+    ///
+    /// A null node starts from the front of the list. A null pointer is returned when
+    /// the back of the list is reached.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The node must be null or in the list.
+    pub unsafe fn node_backward(&self, node: *mut RawNode<T>) -> *mut RawNode<T> {
+        let handle = match node.as_ref() {
+            None => self.front.prev,
+            Some(node) => node.handle.prev,
+        };
+
+        if handle as *const list::Head != &self.back {
+            container_of!(handle, RawNode<T>, handle)
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    /// Inserts a node next to another node, toward the front of the list.
+    ///
+    /// A null position inserts the node at the back of the list.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The position must be null or in the list.
+    pub unsafe fn insert_forward(&self, position: *mut RawNode<T>, node: Box<RawNode<T>>) {
+        let head = match position.as_mut() {
+            None => &self.back as *const list::Head as *mut list::Head,
+            Some(position) => &mut position.handle,
+        };
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { list::add_rcu(node.into_handle(), head) }
+    }
+
+    /// Inserts a node next to another node, toward the back of the list.
+    ///
+    /// A null position inserts the node at the front of the list.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The position must be null or in the list.
+    pub unsafe fn insert_backward(&self, position: *mut RawNode<T>, node: Box<RawNode<T>>) {
+        let head = match position.as_mut() {
+            None => &self.front as *const list::Head as *mut list::Head,
+            Some(position) => &mut position.handle,
+        };
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { list::add_tail_rcu(node.into_handle(), head) }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The node must be in the list.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove(&self, node: NonNull<RawNode<T>>) {
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { list::del_rcu(&mut (*node.as_ptr()).handle) };
+    }
+
+    /// Replaces a node of the list by another node, returning the inserted node.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The old node must be in the list.
+    ///
+    /// The caller must wait a RCU grace period before freeing the old node.
+    pub unsafe fn replace(
+        &self,
+        old: NonNull<RawNode<T>>,
+        node: Box<RawNode<T>>,
+    ) -> *mut RawNode<T> {
+        let handle = node.into_handle();
+
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe { list::replace_rcu(&mut (*old.as_ptr()).handle, handle) };
+
+        container_of!(handle, RawNode<T>, handle)
+    }
+
     pub fn empty(&self) -> bool {
         self.back.next as *const list::Head == &self.front
     }
 }
 
+/// Returns the link of a head toward the middle of a [`RawMultiList`].
+///
+/// #### Safety
+///
+/// The head must be valid for the returned lifetime.
+unsafe fn inward<'a>(head: *mut list::Head, back: bool) -> &'a AtomicPtr<list::Head> {
+    match back {
+        true => AtomicPtr::from_ptr(std::ptr::addr_of_mut!((*head).next)),
+        false => AtomicPtr::from_ptr(std::ptr::addr_of_mut!((*head).prev)),
+    }
+}
+
+/// Returns the link of a head toward the end of a [`RawMultiList`].
+///
+/// #### Safety
+///
+/// The head must be valid for the returned lifetime.
+unsafe fn outward<'a>(head: *mut list::Head, back: bool) -> &'a AtomicPtr<list::Head> {
+    inward(head, !back)
+}
+
+/// Defines a doubly linked list with lock-free insertions at both ends.
+///
+/// A middle sentinel splits the list into a back half and a front half. An insertion
+/// only modifies the half of its end, so insertions at both ends never race on the same
+/// link. An insertion is linked with a compare-and-swap on its end, then it fixes the
+/// link of the element it displaced. A removal waits for that fix before unlinking the
+/// element, so removals are always done on a consistent list.
+///
+/// The middle sentinel is skipped by the readers.
+pub struct RawMultiList<T> {
+    back: list::Head,
+    middle: list::Head,
+    front: list::Head,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawMultiList<T> {
+    /// #### Safety
+    ///
+    /// The caller must call [`RawMultiList::init`] once [`RawMultiList`] is in a stable
+    /// memory location.
+    pub unsafe fn new() -> Self {
+        Self {
+            back: Default::default(),
+            middle: Default::default(),
+            front: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must ensure [`RawMultiList`] is in a stable memory location.
+    /// The caller must remove all nodes before dropping this type.
+    pub unsafe fn init(&mut self) {
+        self.back.next = &mut self.middle;
+        self.middle.prev = &mut self.back;
+        self.middle.next = &mut self.front;
+        self.front.prev = &mut self.middle;
+    }
+
+    fn sentinel(&self, back: bool) -> *mut list::Head {
+        match back {
+            true => &self.back as *const list::Head as *mut list::Head,
+            false => &self.front as *const list::Head as *mut list::Head,
+        }
+    }
+
+    fn middle(&self) -> *mut list::Head {
+        &self.middle as *const list::Head as *mut list::Head
+    }
+
+    fn insert(&self, node: Box<RawNode<T>>, back: bool) {
+        let handle = node.into_handle();
+        let sentinel = self.sentinel(back);
+
+        // SAFETY: The node is not shared until the exchange succeeds.
+        // SAFETY: The sentinels live as long as the list.
+        let displaced = unsafe {
+            outward(handle, back).store(sentinel, Ordering::Relaxed);
+
+            let mut current = inward(sentinel, back).load(Ordering::Acquire);
+            loop {
+                inward(handle, back).store(current, Ordering::Relaxed);
+
+                match inward(sentinel, back).compare_exchange_weak(
+                    current,
+                    handle,
+                    Ordering::SeqCst,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => break current,
+                    Err(actual) => current = actual,
+                }
+            }
+        };
+
+        // SAFETY: The displaced head can't be removed until its link is fixed.
+        unsafe { outward(displaced, back).store(handle, Ordering::Release) };
+    }
+
+    /// Inserts a node at the back of the list.
+    ///
+    /// The node is visible to the readers when this function returns.
+    pub fn insert_back(&self, node: Box<RawNode<T>>) {
+        self.insert(node, true)
+    }
+
+    /// Inserts a node at the front of the list.
+    ///
+    /// The node is visible to the readers when this function returns.
+    pub fn insert_front(&self, node: Box<RawNode<T>>) {
+        self.insert(node, false)
+    }
+
+    /// Unlinks the outermost node of an end, returning null if an insertion displaced it.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other removers.
+    ///
+    /// The node must have been the outermost node of the end.
+    unsafe fn unlink_outermost(&self, node: *mut list::Head, back: bool) -> *mut list::Head {
+        let sentinel = self.sentinel(back);
+        let next = inward(node, back).load(Ordering::Acquire);
+
+        // The insertion of the node might not have fixed the link of the next head yet.
+        while outward(next, back).load(Ordering::Acquire) != node {
+            std::hint::spin_loop();
+        }
+
+        if inward(sentinel, back)
+            .compare_exchange(node, next, Ordering::SeqCst, Ordering::Relaxed)
+            .is_err()
+        {
+            return std::ptr::null_mut();
+        }
+
+        // An insertion might already have displaced the next head and fixed its link.
+        let _ = outward(next, back).compare_exchange(
+            node,
+            sentinel,
+            Ordering::SeqCst,
+            Ordering::Relaxed,
+        );
+
+        node
+    }
+
+    /// Unlinks the innermost node of a half, returning null if an insertion displaced it.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other removers.
+    ///
+    /// The node must be the innermost node of the half.
+    unsafe fn unlink_innermost(&self, node: *mut list::Head, back: bool) -> *mut list::Head {
+        let sentinel = self.sentinel(back);
+        let middle = self.middle();
+
+        if inward(sentinel, back).load(Ordering::Acquire) == node {
+            return self.unlink_outermost(node, back);
+        }
+
+        // The insertion that displaced the node might not have fixed its link yet.
+        let prev = loop {
+            let prev = outward(node, back).load(Ordering::Acquire);
+            if prev != sentinel {
+                break prev;
+            }
+
+            std::hint::spin_loop();
+        };
+
+        inward(prev, back).store(middle, Ordering::Release);
+        outward(middle, back).store(prev, Ordering::Release);
+
+        node
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other removers.
+    unsafe fn remove(&self, back: bool) -> *mut RawNode<T> {
+        let middle = self.middle();
+        let other = self.sentinel(!back);
+
+        loop {
+            let outermost = inward(self.sentinel(back), back).load(Ordering::Acquire);
+
+            let handle = if outermost != middle {
+                self.unlink_outermost(outermost, back)
+            } else {
+                // The half is empty, the node is the innermost one of the other half.
+                let innermost = outward(middle, !back).load(Ordering::Acquire);
+
+                if innermost != other {
+                    self.unlink_innermost(innermost, !back)
+                } else if inward(other, !back).load(Ordering::Acquire) == middle {
+                    // The halves only shrink when removing, so both were empty.
+                    return std::ptr::null_mut();
+                } else {
+                    // The insertion in the other half did not fix its link yet.
+                    std::ptr::null_mut()
+                }
+            };
+
+            if !handle.is_null() {
+                return container_of!(handle, RawNode<T>, handle);
+            }
+
+            std::hint::spin_loop();
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other removers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove_back(&self) -> *mut RawNode<T> {
+        self.remove(true)
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other removers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove_front(&self) -> *mut RawNode<T> {
+        self.remove(false)
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get_back(&self) -> *const RawNode<T> {
+        let mut handle = crate::rcu::dereference(self.back.next);
+        if handle == &self.middle {
+            handle = crate::rcu::dereference(self.middle.next);
+        }
+
+        if handle != &self.front {
+            container_of!(handle, RawNode<T>, handle)
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get_front(&self) -> *const RawNode<T> {
+        let mut handle = crate::rcu::dereference(self.front.prev);
+        if handle == &self.middle {
+            handle = crate::rcu::dereference(self.middle.prev);
+        }
+
+        if handle != &self.back {
+            container_of!(handle, RawNode<T>, handle)
+        } else {
+            std::ptr::null_mut()
+        }
+    }
+
+    pub fn empty(&self) -> bool {
+        let middle = self.middle();
+
+        // SAFETY: The sentinels live as long as the list.
+        unsafe {
+            inward(self.sentinel(true), true).load(Ordering::Acquire) == middle
+                && inward(self.sentinel(false), false).load(Ordering::Acquire) == middle
+        }
+    }
+}
+
 pub struct RawIter<T, const FORWARD: bool> {
     current: *const list::Head,
     last: *const list::Head,
+    skip: *const list::Head,
     _unsend: PhantomUnsend<T>,
     _unsync: PhantomUnsync<T>,
 }
@@ -173,6 +583,20 @@ impl<T> RawIter<T, true> {
         Self {
             current: crate::rcu::dereference(list.back.next),
             last: &list.front,
+            skip: std::ptr::null(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn from_multi_back(list: &RawMultiList<T>) -> Self {
+        Self {
+            current: crate::rcu::dereference(list.back.next),
+            last: &list.front,
+            skip: &list.middle,
             _unsend: PhantomData,
             _unsync: PhantomData,
         }
@@ -187,6 +611,20 @@ impl<T> RawIter<T, false> {
         Self {
             current: crate::rcu::dereference(list.front.prev),
             last: &list.back,
+            skip: std::ptr::null(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn from_multi_front(list: &RawMultiList<T>) -> Self {
+        Self {
+            current: crate::rcu::dereference(list.front.prev),
+            last: &list.back,
+            skip: &list.middle,
             _unsend: PhantomData,
             _unsync: PhantomData,
         }
@@ -198,20 +636,23 @@ impl<T, const FORWARD: bool> RawIter<T, FORWARD> {
     ///
     /// The caller must be in a RCU critical section.
     pub unsafe fn next(&mut self) -> *const RawNode<T> {
-        if self.current == self.last {
-            return std::ptr::null();
-        }
+        loop {
+            if self.current == self.last {
+                return std::ptr::null();
+            }
 
-        match self.current.as_ref() {
-            None => std::ptr::null(),
-            Some(handle) => {
-                self.current = if FORWARD {
-                    crate::rcu::dereference_mut(handle.next)
-                } else {
-                    crate::rcu::dereference_mut(handle.prev)
-                };
+            let Some(handle) = self.current.as_ref() else {
+                return std::ptr::null();
+            };
+
+            self.current = if FORWARD {
+                crate::rcu::dereference_mut(handle.next)
+            } else {
+                crate::rcu::dereference_mut(handle.prev)
+            };
 
-                container_of!(handle as *const list::Head, RawNode<T>, handle)
+            if !std::ptr::eq(handle, self.skip) {
+                return container_of!(handle as *const list::Head, RawNode<T>, handle);
             }
         }
     }
@@ -1,3 +1,4 @@
+use crate::collections::list::concurrent::LockedNode;
 use crate::collections::list::raw::RawNode;
 use crate::rcu::reference;
 
@@ -6,6 +7,20 @@ use crate::rcu::reference;
 /// [`RcuList`]: crate::collections::list::container::RcuList
 pub type RefOwned<T> = reference::BoxRefOwned<RawNode<T>>;
 
+impl<T> RefOwned<T> {
+    /// Moves the element out of the reference.
+    ///
+    /// #### Note
+    ///
+    /// The element must implement [`Unpin`] since it may have been pinned in the list.
+    pub fn into_inner(self) -> T
+    where
+        T: Unpin,
+    {
+        self.into_box().into_data()
+    }
+}
+
 /// An RCU reference to a element removed from an [`RcuList`].
 ///
 /// #### Requirements
@@ -14,3 +29,31 @@ pub type RefOwned<T> = reference::BoxRefOwned<RawNode<T>>;
 ///
 /// [`RcuList`]: crate::collections::list::container::RcuList
 pub type Ref<T, F> = reference::RcuRefBox<RawNode<T>, F>;
+
+/// An owned RCU reference to a element removed from an [`RcuConcurrentList`].
+///
+/// [`RcuConcurrentList`]: crate::collections::list::concurrent::RcuConcurrentList
+pub type ConcurrentRefOwned<T> = reference::BoxRefOwned<LockedNode<T>>;
+
+impl<T> ConcurrentRefOwned<T> {
+    /// Moves the element out of the reference.
+    ///
+    /// #### Note
+    ///
+    /// The element must implement [`Unpin`] since it may have been pinned in the list.
+    pub fn into_inner(self) -> T
+    where
+        T: Unpin,
+    {
+        self.into_box().into_data()
+    }
+}
+
+/// An RCU reference to a element removed from an [`RcuConcurrentList`].
+///
+/// #### Requirements
+///
+/// `T` must be [`Send`] because [`Drop::drop`] might execute cleanup in another thread.
+///
+/// [`RcuConcurrentList`]: crate::collections::list::concurrent::RcuConcurrentList
+pub type ConcurrentRef<T, F> = reference::RcuRefBox<LockedNode<T>, F>;
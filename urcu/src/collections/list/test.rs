@@ -1,4 +1,8 @@
+use std::sync::Arc;
+
+use crate::collections::list::concurrent::RcuConcurrentList;
 use crate::collections::list::container::RcuList;
+use crate::collections::list::multi::RcuMultiWriterList;
 use crate::rcu::context::RcuReadContext;
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
@@ -43,27 +47,27 @@ fn peek() {
     assert_eq!(list.front(&guard), Some(&50));
     assert!(!list.is_empty());
 
-    list.pop_back().unwrap().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
     assert_eq!(list.back(&guard), Some(&20));
     assert_eq!(list.front(&guard), Some(&50));
     assert!(!list.is_empty());
 
-    list.pop_front().unwrap().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
     assert_eq!(list.back(&guard), Some(&20));
     assert_eq!(list.front(&guard), Some(&30));
     assert!(!list.is_empty());
 
-    list.pop_front().unwrap().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
     assert_eq!(list.back(&guard), Some(&20));
     assert_eq!(list.front(&guard), Some(&10));
     assert!(!list.is_empty());
 
-    list.pop_back().unwrap().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
     assert_eq!(list.back(&guard), Some(&10));
     assert_eq!(list.front(&guard), Some(&10));
     assert!(!list.is_empty());
 
-    list.pop_back().unwrap().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
     assert_eq!(list.back(&guard), None);
     assert_eq!(list.front(&guard), None);
     assert!(list.is_empty());
@@ -110,11 +114,11 @@ fn iter() {
         vec![160, 105, 184, 140, 128, 174, 150, 147, 120, 183]
     );
 
-    list.pop_back().unwrap().call_cleanup(&context);
-    list.pop_back().unwrap().call_cleanup(&context);
-    list.pop_back().unwrap().call_cleanup(&context);
-    list.pop_front().unwrap().call_cleanup(&context);
-    list.pop_back().unwrap().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
 
     assert_eq!(
         list.iter_forward(&guard).copied().collect::<Vec<_>>(),
@@ -126,10 +130,10 @@ fn iter() {
         vec![105, 184, 140, 128, 174]
     );
 
-    list.pop_front().unwrap().call_cleanup(&context);
-    list.pop_back().unwrap().call_cleanup(&context);
-    list.pop_back().unwrap().call_cleanup(&context);
-    list.pop_front().unwrap().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
+    list.pop_back().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
     list.push_back(142).unwrap();
 
     assert_eq!(
@@ -142,8 +146,8 @@ fn iter() {
         vec![140, 142]
     );
 
-    list.pop_front().unwrap().call_cleanup(&context);
-    list.pop_front().unwrap().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
+    list.pop_front().call_cleanup(&context);
 
     assert_eq!(
         list.iter_forward(&guard).copied().collect::<Vec<_>>(),
@@ -155,3 +159,599 @@ fn iter() {
         vec![]
     );
 }
+
+#[test]
+fn with_capacity() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::with_capacity(3);
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(list.capacity(), Some(3));
+    assert!(list.push_back_bounded(10).is_none());
+    assert!(list.push_back_bounded(20).is_none());
+    assert!(list.push_back_bounded(30).is_none());
+    assert!(list.push_back(40).is_err());
+    assert!(list.push_front(40).is_err());
+
+    let evicted = list.push_back_bounded(40).unwrap();
+    assert_eq!(*evicted, 10);
+    evicted.call_cleanup(&context);
+
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![20, 30, 40]
+    );
+
+    let evicted = list.push_front_bounded(50).unwrap();
+    assert_eq!(*evicted, 40);
+    evicted.call_cleanup(&context);
+
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![50, 20, 30]
+    );
+
+    list.pop_front().call_cleanup(&context);
+    list.push_back(60).unwrap();
+
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![20, 30, 60]
+    );
+}
+
+#[test]
+fn with_context() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+
+    assert!(!list.contains_with(&context, &10));
+    assert_eq!(list.back_with(&context, |value| value.copied()), None);
+    assert_eq!(list.front_with(&context, |value| value.copied()), None);
+
+    list.push_back(10).unwrap();
+    list.push_back(20).unwrap();
+
+    assert!(list.contains_with(&context, &10));
+    assert!(!list.contains_with(&context, &30));
+    assert_eq!(list.back_with(&context, |value| value.copied()), Some(20));
+    assert_eq!(list.front_with(&context, |value| value.copied()), Some(10));
+}
+
+#[test]
+fn multi_writer() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuMultiWriterList::<u32>::new();
+    assert!(list.is_empty());
+
+    list.push_back(10);
+    list.push_back(20);
+    list.push_front(30);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![30, 10, 20]
+    );
+    drop(guard);
+
+    std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let list = &list;
+
+            scope.spawn(move || {
+                for value in 0..100 {
+                    list.push_back(thread * 100 + value);
+                }
+            });
+        }
+    });
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(list.iter_forward(&guard).count(), 403);
+    assert_eq!(list.front(&guard), Some(&30));
+    assert!(list.contains(&399, &guard));
+    drop(guard);
+
+    while let Some(value) = list.pop_front() {
+        value.call_cleanup(&context);
+    }
+
+    assert!(list.is_empty());
+}
+
+#[test]
+fn pop_owned() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<String>::new();
+
+    assert_eq!(list.pop_back_owned(&mut context), None);
+
+    list.push_back("first".to_owned()).unwrap();
+    list.push_back("second".to_owned()).unwrap();
+    list.push_back("third".to_owned()).unwrap();
+
+    assert_eq!(list.pop_back_owned(&mut context).as_deref(), Some("third"));
+    assert_eq!(list.pop_front_owned(&mut context).as_deref(), Some("first"));
+    assert_eq!(
+        list.pop_front_owned(&mut context).as_deref(),
+        Some("second")
+    );
+    assert_eq!(list.pop_front_owned(&mut context), None);
+}
+
+#[test]
+fn indexed() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+
+    list.push_back(10).unwrap();
+    list.push_back(20).unwrap();
+    list.push_front(5).unwrap();
+
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(list.get(0, &guard), Some(&5));
+    assert_eq!(list.get(1, &guard), Some(&10));
+    assert_eq!(list.get(2, &guard), Some(&20));
+    assert_eq!(list.get(3, &guard), None);
+
+    assert_eq!(list.position(|value| *value == 20, &guard), Some(2));
+    assert_eq!(list.position(|value| *value > 5, &guard), Some(1));
+    assert_eq!(list.position(|value| *value == 30, &guard), None);
+}
+
+#[test]
+fn pinned() {
+    use std::marker::PhantomPinned;
+
+    struct Pinned(u32, PhantomPinned);
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<Pinned>::new();
+
+    list.push_back(Pinned(10, PhantomPinned)).unwrap();
+    list.push_back(Pinned(20, PhantomPinned)).unwrap();
+
+    let guard = context.rcu_read_lock();
+    let value = list.get_pinned(1, &guard).unwrap();
+    assert_eq!(value.0, 20);
+    assert!(list.get_pinned(2, &guard).is_none());
+    drop(guard);
+
+    let mut value = list.pop_back().unwrap().take_ownership(&mut context);
+    assert_eq!(value.as_pin_mut().0, 20);
+}
+
+#[test]
+fn debug() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+    let guard = context.rcu_read_lock();
+    assert_eq!(format!("{:?}", list.debug(&guard)), "[]");
+
+    list.push_back(8121).unwrap();
+    list.push_back(3405).unwrap();
+
+    assert_eq!(
+        format!("{:?}", list.debug(&guard)),
+        format!("{:?}", list.iter_forward(&guard).collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn cursor_mut() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+
+    list.push_back(10).unwrap();
+    list.push_back(20).unwrap();
+    list.push_back(30).unwrap();
+
+    let mut cursor = list.cursor_front_mut();
+    assert_eq!(cursor.current(), Some(&10));
+    assert_eq!(cursor.index(), Some(0));
+
+    cursor.move_next();
+    assert_eq!(cursor.current(), Some(&20));
+    assert_eq!(cursor.peek_prev(), Some(&10));
+    assert_eq!(cursor.peek_next(), Some(&30));
+
+    cursor.insert_before(15).unwrap();
+    cursor.insert_after(25).unwrap();
+    assert_eq!(cursor.index(), Some(2));
+
+    let removed = cursor.remove_current().unwrap();
+    assert_eq!(*removed, 20);
+    assert_eq!(cursor.current(), Some(&25));
+    assert_eq!(cursor.index(), Some(2));
+
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.index(), None);
+
+    cursor.insert_after(5).unwrap();
+    cursor.move_prev();
+    assert_eq!(cursor.current(), Some(&30));
+    assert_eq!(cursor.index(), Some(4));
+    drop(cursor);
+
+    removed.call_cleanup(&context);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![5, 10, 15, 25, 30]
+    );
+}
+
+#[test]
+fn insert_sorted() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::with_capacity(5);
+
+    list.insert_sorted(30).unwrap();
+    list.insert_sorted(10).unwrap();
+    list.insert_sorted(20).unwrap();
+    list.insert_at(0, 5).unwrap();
+    list.insert_at(4, 40).unwrap();
+
+    assert!(list.insert_sorted(15).is_err());
+    assert!(list.pop_back().is_some());
+    assert!(list.insert_at(5, 50).is_err());
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![5, 10, 20, 30]
+    );
+}
+
+#[test]
+fn retain() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+
+    for value in 1..=6 {
+        list.push_back(value).unwrap();
+    }
+
+    let removed = list.retain(|value| value % 2 == 0);
+    assert_eq!(
+        removed.iter().map(|node| **node).collect::<Vec<_>>(),
+        vec![1, 3, 5]
+    );
+    removed.call_cleanup(&context);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![2, 4, 6]
+    );
+}
+
+#[test]
+fn into_iter() {
+    let list = RcuList::<u32>::new();
+
+    for value in 1..=4 {
+        list.push_back(value).unwrap();
+    }
+
+    let list = Arc::try_unwrap(list).ok().unwrap();
+    let mut iter = list.into_iter();
+
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(4));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+}
+
+#[test]
+fn remove_first_where() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+
+    for value in [10, 25, 30, 45] {
+        list.push_back(value).unwrap();
+    }
+
+    let removed = list.remove_first_where(|value| value % 2 == 1);
+    let removed = removed.unwrap();
+    assert_eq!(*removed, 25);
+    removed.call_cleanup(&context);
+
+    assert!(list.remove_first_where(|value| *value > 50).is_none());
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![10, 30, 45]
+    );
+}
+
+#[test]
+fn replace() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+
+    for value in [10, 20, 30] {
+        list.push_back(value).unwrap();
+    }
+
+    let replaced = list.replace_first_where(|value| *value == 20, 25);
+    let replaced = replaced.unwrap();
+    assert_eq!(*replaced, 20);
+    replaced.call_cleanup(&context);
+
+    assert!(list.replace_first_where(|value| *value == 20, 0).is_none());
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+
+    let replaced = cursor.replace_current(35).unwrap();
+    assert_eq!(*replaced, 30);
+    assert_eq!(cursor.current(), Some(&35));
+    assert_eq!(cursor.peek_prev(), Some(&25));
+    replaced.call_cleanup(&context);
+
+    cursor.move_next();
+    assert!(cursor.replace_current(40).is_none());
+    drop(cursor);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![10, 25, 35]
+    );
+}
+
+#[test]
+fn try_cursor_front_mut() {
+    let list = RcuList::<u32>::new();
+    list.push_back(10).unwrap();
+
+    let cursor = list.try_cursor_front_mut().unwrap();
+    assert_eq!(cursor.current(), Some(&10));
+    assert!(list.try_cursor_front_mut().is_none());
+
+    drop(cursor);
+    assert!(list.try_cursor_front_mut().is_some());
+}
+
+#[test]
+fn poisoned() {
+    use std::panic::AssertUnwindSafe;
+
+    let list = RcuList::<u32>::new();
+    list.push_back(10).unwrap();
+
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        list.retain(|_| panic!("writer panicked"));
+    }));
+    assert!(result.is_err());
+
+    list.push_back(20).unwrap();
+    assert_eq!(list.cursor_front_mut().current(), Some(&10));
+}
+
+#[test]
+fn push_iter() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::with_capacity(4);
+
+    assert!(list.push_back_iter([10, 20, 30]).is_empty());
+    assert!(list.push_front_iter([5]).is_empty());
+
+    let evicted = list.push_back_iter([40, 50]);
+    assert_eq!(
+        evicted.iter().map(|node| **node).collect::<Vec<_>>(),
+        vec![5, 10]
+    );
+    evicted.call_cleanup(&context);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        vec![20, 30, 40, 50]
+    );
+}
+
+#[test]
+fn compare() {
+    use std::cmp::Ordering;
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let first = RcuList::<u32>::new();
+    let second = RcuList::<u32>::new();
+
+    first.push_back_iter([10, 20]);
+    second.push_back_iter([10, 20]);
+
+    let guard = context.rcu_read_lock();
+    assert!(first.eq_with(&second, &guard));
+    assert_eq!(first.cmp_with(&second, &guard), Ordering::Equal);
+
+    second.push_front(15).unwrap();
+    assert!(!first.eq_with(&second, &guard));
+    assert_eq!(first.cmp_with(&second, &guard), Ordering::Less);
+}
+
+#[test]
+fn concurrent_writers() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuConcurrentList::<u32>::new();
+    assert!(list.is_empty());
+
+    std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let list = &list;
+
+            scope.spawn(move || {
+                let context = RcuDefaultFlavor::rcu_context_builder()
+                    .with_read_context()
+                    .register_thread()
+                    .unwrap();
+
+                for value in 0..100 {
+                    let guard = context.rcu_read_lock();
+                    list.insert_sorted(value * 4 + thread, &guard);
+                }
+            });
+        }
+    });
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        list.iter_reverse(&guard).copied().collect::<Vec<_>>(),
+        (0..400).collect::<Vec<_>>()
+    );
+
+    list.push_front(1000, &guard);
+    list.push_back(2000, &guard);
+    assert_eq!(list.front(&guard), Some(&1000));
+    assert_eq!(list.back(&guard), Some(&2000));
+
+    let front = list.pop_front(&guard).unwrap();
+    let back = list.pop_back(&guard).unwrap();
+    let removed = list
+        .remove_first_where(|value| *value == 200, &guard)
+        .unwrap();
+    assert!(!list.contains(&200, &guard));
+    drop(guard);
+
+    assert_eq!(front.take_ownership(&mut context).into_inner(), 1000);
+    assert_eq!(*back, 2000);
+    assert_eq!(*removed, 200);
+    back.call_cleanup(&context);
+    removed.call_cleanup(&context);
+}
+
+#[test]
+fn find() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<(u32, &'static str)>::new();
+    list.push_back_iter([(1, "one"), (2, "two"), (3, "three"), (2, "deux")]);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(list.find(|(id, _)| *id == 2, &guard), Some(&(2, "two")));
+    assert_eq!(list.find(|(id, _)| *id == 4, &guard), None);
+    assert!(list.contains_by_key("three", |(_, name)| *name, &guard));
+    assert!(!list.contains_by_key(&4, |(id, _)| id, &guard));
+}
+
+#[test]
+fn pop_n() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+    list.push_back_iter([10, 20, 30, 40, 50]);
+
+    let front = list.pop_front_n(2);
+    assert_eq!(
+        front.iter().map(|node| **node).collect::<Vec<_>>(),
+        vec![10, 20]
+    );
+
+    let back = list.pop_back_n(5);
+    assert_eq!(
+        back.iter().map(|node| **node).collect::<Vec<_>>(),
+        vec![50, 40, 30]
+    );
+
+    assert!(list.is_empty());
+    assert!(list.pop_back_n(1).is_empty());
+
+    front.call_cleanup(&context);
+    back.call_cleanup(&context);
+}
+
+#[test]
+fn iter_from() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuList::<u32>::new();
+    list.push_back_iter([10, 20, 30, 40]);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(list.get_from_back(0, &guard), Some(&40));
+    assert_eq!(list.get_from_back(4, &guard), None);
+    assert_eq!(
+        list.iter_from(2, &guard).copied().collect::<Vec<_>>(),
+        vec![30, 40]
+    );
+    assert_eq!(list.iter_from(0, &guard).count(), 4);
+    assert_eq!(list.iter_from(5, &guard).count(), 0);
+}
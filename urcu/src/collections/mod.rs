@@ -1,7 +1,27 @@
 //! Collections types.
 
+pub mod arc;
+pub mod bitmap;
 pub mod boxed;
+pub mod cell;
+pub mod clock;
+pub mod counter;
 pub mod hashmap;
+pub mod hashset;
+pub mod hlist;
+pub mod interner;
+pub mod intrusive;
 pub mod list;
+pub mod model;
+pub mod percpu;
+pub mod protected;
 pub mod queue;
+pub mod radix;
+pub mod ring;
+pub mod skiplist;
+pub mod slab;
 pub mod stack;
+pub mod vec;
+pub mod wfqueue;
+pub mod wfstack;
+pub mod workqueue;
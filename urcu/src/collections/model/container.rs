@@ -0,0 +1,297 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::hashmap::reference::Ref as HashMapRef;
+use crate::collections::list::container::RcuList;
+use crate::collections::list::reference::Ref as ListRef;
+use crate::collections::queue::container::RcuQueue;
+use crate::collections::queue::reference::Ref as QueueRef;
+use crate::collections::stack::container::RcuStack;
+use crate::collections::stack::reference::Ref as StackRef;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+
+/// This trait defines a container that can be mirrored in a standard collection.
+pub trait ModelTarget {
+    /// Defines the standard collection mirroring the container.
+    type Model: Default + Debug;
+}
+
+/// Defines a container where every operation is mirrored in a standard collection.
+///
+/// Every operation asserts that the container and the model return the same element.
+/// A checkpoint asserts that both hold the same elements.
+///
+/// | Container            | Model             |
+/// |:---------------------|:------------------|
+/// | [`RcuHashMap<K, V>`] | [`HashMap<K, V>`] |
+/// | [`RcuList<T>`]       | [`VecDeque<T>`]   |
+/// | [`RcuQueue<T>`]      | [`VecDeque<T>`]   |
+/// | [`RcuStack<T>`]      | [`Vec<T>`]        |
+///
+/// #### Note
+///
+/// This is a test utility that panics on the first divergence. The wrapper must be the
+/// only writer of the container, otherwise the model cannot be deterministic.
+pub struct ModelChecked<T>
+where
+    T: ModelTarget,
+{
+    container: Arc<T>,
+    model: T::Model,
+}
+
+impl<T> ModelChecked<T>
+where
+    T: ModelTarget,
+{
+    /// Wraps an empty container.
+    pub fn new(container: Arc<T>) -> Self {
+        Self {
+            container,
+            model: T::Model::default(),
+        }
+    }
+
+    /// Returns the container.
+    pub fn container(&self) -> &Arc<T> {
+        &self.container
+    }
+
+    /// Returns the model of the container.
+    pub fn model(&self) -> &T::Model {
+        &self.model
+    }
+
+    /// Consumes the wrapper, returning the container.
+    pub fn into_inner(self) -> Arc<T> {
+        self.container
+    }
+}
+
+impl<K, V, F> ModelTarget for RcuHashMap<K, V, F>
+where
+    K: Send + Debug + 'static,
+    V: Send + Debug + 'static,
+    F: RcuFlavor + 'static,
+{
+    type Model = HashMap<K, V>;
+}
+
+impl<K, V, F> ModelChecked<RcuHashMap<K, V, F>>
+where
+    K: Send + Clone + Eq + Hash + Debug,
+    V: Send + Clone + PartialEq + Debug,
+    F: RcuFlavor,
+{
+    /// Inserts a key-value pair in the hashmap and the model.
+    pub fn insert<G>(&mut self, key: K, value: V, guard: &G) -> Option<HashMapRef<K, V, F>>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let old = self.container.insert(key.clone(), value.clone(), guard);
+        let expected = self.model.insert(key, value);
+
+        assert_eq!(old.as_ref().map(|old| old.value()), expected.as_ref());
+        old
+    }
+
+    /// Removes a key from the hashmap and the model.
+    pub fn remove<G>(&mut self, key: &K, guard: &G) -> Option<HashMapRef<K, V, F>>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let old = self.container.remove(key, guard);
+        let expected = self.model.remove(key);
+
+        assert_eq!(old.as_ref().map(|old| old.value()), expected.as_ref());
+        old
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<'me, 'guard, G>(&'me self, key: &K, guard: &'guard G) -> Option<&'guard V>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let value = self.container.get(key, guard);
+
+        assert_eq!(value, self.model.get(key));
+        value
+    }
+
+    /// Asserts that the hashmap and the model hold the same key-value pairs.
+    pub fn checkpoint<G>(&self, guard: &G)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let entries = self.container.iter(guard).collect::<HashMap<_, _>>();
+        let expected = self.model.iter().collect::<HashMap<_, _>>();
+
+        assert_eq!(entries, expected);
+    }
+}
+
+impl<T, F> ModelTarget for RcuList<T, F>
+where
+    T: Debug,
+    F: RcuFlavor,
+{
+    type Model = VecDeque<T>;
+}
+
+/// The front and the back of the model are the front and the back of the list.
+impl<T, F> ModelChecked<RcuList<T, F>>
+where
+    T: Send + Clone + PartialEq + Debug,
+    F: RcuFlavor,
+{
+    fn is_full(&self) -> bool {
+        self.container
+            .capacity()
+            .is_some_and(|capacity| self.model.len() >= capacity)
+    }
+
+    /// Adds an element to the back of the bounded list and the model.
+    pub fn push_back_bounded(&mut self, data: T) -> Option<ListRef<T, F>> {
+        let evicted = self.container.push_back_bounded(data.clone());
+        let expected = if self.is_full() {
+            self.model.pop_front()
+        } else {
+            None
+        };
+
+        self.model.push_back(data);
+
+        assert_eq!(evicted.as_deref(), expected.as_ref());
+        evicted
+    }
+
+    /// Adds an element to the front of the bounded list and the model.
+    pub fn push_front_bounded(&mut self, data: T) -> Option<ListRef<T, F>> {
+        let evicted = self.container.push_front_bounded(data.clone());
+        let expected = if self.is_full() {
+            self.model.pop_back()
+        } else {
+            None
+        };
+
+        self.model.push_front(data);
+
+        assert_eq!(evicted.as_deref(), expected.as_ref());
+        evicted
+    }
+
+    /// Removes an element from the back of the list and the model.
+    pub fn pop_back(&mut self) -> Option<ListRef<T, F>> {
+        let value = self.container.pop_back();
+
+        assert_eq!(value.as_deref(), self.model.pop_back().as_ref());
+        value
+    }
+
+    /// Removes an element from the front of the list and the model.
+    pub fn pop_front(&mut self) -> Option<ListRef<T, F>> {
+        let value = self.container.pop_front();
+
+        assert_eq!(value.as_deref(), self.model.pop_front().as_ref());
+        value
+    }
+
+    /// Asserts that the list and the model hold the same elements in the same order.
+    pub fn checkpoint<G>(&self, guard: &G)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        assert_eq!(self.container.back(guard), self.model.back());
+        assert_eq!(self.container.front(guard), self.model.front());
+
+        let values = self.container.iter_reverse(guard).collect::<Vec<_>>();
+        let expected = self.model.iter().collect::<Vec<_>>();
+
+        assert_eq!(values, expected);
+    }
+}
+
+impl<T, F> ModelTarget for RcuQueue<T, F>
+where
+    T: Debug,
+    F: RcuFlavor,
+{
+    type Model = VecDeque<T>;
+}
+
+/// The queue cannot be traversed, so the elements are only compared when removed.
+impl<T, F> ModelChecked<RcuQueue<T, F>>
+where
+    T: Send + Clone + PartialEq + Debug,
+    F: RcuFlavor,
+{
+    /// Adds an element to the back of the queue and the model.
+    pub fn push<G>(&mut self, data: T, guard: &G)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.container.push(data.clone(), guard);
+        self.model.push_back(data);
+    }
+
+    /// Removes an element from the front of the queue and the model.
+    pub fn pop<G>(&mut self, guard: &G) -> Option<QueueRef<T, F>>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let value = self.container.pop(guard);
+
+        assert_eq!(value.as_deref(), self.model.pop_front().as_ref());
+        value
+    }
+}
+
+impl<T, F> ModelTarget for RcuStack<T, F>
+where
+    T: Debug,
+    F: RcuFlavor,
+{
+    type Model = Vec<T>;
+}
+
+/// The top of the stack is the last element of the model.
+impl<T, F> ModelChecked<RcuStack<T, F>>
+where
+    T: Send + Clone + PartialEq + Debug,
+    F: RcuFlavor,
+{
+    /// Adds an element to the top of the stack and the model.
+    pub fn push(&mut self, data: T) {
+        self.container.push(data.clone());
+        self.model.push(data);
+    }
+
+    /// Removes an element from the top of the stack and the model.
+    pub fn pop<G>(&mut self, guard: &G) -> Option<StackRef<T, F>>
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        let value = self.container.pop(guard);
+
+        assert_eq!(value.as_deref(), self.model.pop().as_ref());
+        value
+    }
+
+    /// Asserts that the stack and the model hold the same elements in the same order.
+    pub fn checkpoint<G>(&self, guard: &G)
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        assert_eq!(self.container.peek(guard), self.model.last());
+
+        let values = self.container.iter(guard).collect::<Vec<_>>();
+        let expected = self.model.iter().rev().collect::<Vec<_>>();
+
+        assert_eq!(values, expected);
+    }
+}
@@ -0,0 +1,8 @@
+//! Model checking of the containers against the standard collections.
+
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::model::container::*;
@@ -0,0 +1,105 @@
+use crate::collections::hashmap::container::RcuHashMap;
+use crate::collections::list::container::RcuList;
+use crate::collections::model::container::ModelChecked;
+use crate::collections::queue::container::RcuQueue;
+use crate::collections::stack::container::RcuStack;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn hashmap() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let mut hashmap = ModelChecked::new(RcuHashMap::<u32, u32>::new().unwrap());
+    let guard = context.rcu_read_lock();
+
+    for i in 0..64 {
+        hashmap.insert(i % 16, i, &guard).safe_cleanup();
+    }
+
+    hashmap.checkpoint(&guard);
+    assert_eq!(hashmap.get(&3, &guard), Some(&51));
+
+    for i in (0..16).step_by(3) {
+        hashmap.remove(&i, &guard).safe_cleanup();
+    }
+
+    hashmap.remove(&100, &guard).safe_cleanup();
+    hashmap.checkpoint(&guard);
+    assert_eq!(hashmap.model().len(), 10);
+}
+
+#[test]
+fn list() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let mut list = ModelChecked::new(RcuList::<u32>::with_capacity(5));
+
+    for i in 0..8 {
+        if i % 2 == 1 {
+            list.push_front_bounded(i).safe_cleanup();
+        } else {
+            list.push_back_bounded(i).safe_cleanup();
+        }
+
+        list.checkpoint(&context.rcu_read_lock());
+    }
+
+    list.pop_back().safe_cleanup();
+    list.pop_front().safe_cleanup();
+    list.checkpoint(&context.rcu_read_lock());
+    assert_eq!(list.model().len(), 3);
+}
+
+#[test]
+fn queue() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let mut queue = ModelChecked::new(RcuQueue::<u32>::new());
+    let guard = context.rcu_read_lock();
+
+    for i in 0..8 {
+        queue.push(i, &guard);
+    }
+
+    for _ in 0..10 {
+        queue.pop(&guard).safe_cleanup();
+    }
+
+    assert!(queue.model().is_empty());
+}
+
+#[test]
+fn stack() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let mut stack = ModelChecked::new(RcuStack::<u32>::new());
+    let guard = context.rcu_read_lock();
+
+    for i in 0..8 {
+        stack.push(i);
+    }
+
+    stack.checkpoint(&guard);
+
+    for _ in 0..3 {
+        stack.pop(&guard).safe_cleanup();
+    }
+
+    stack.checkpoint(&guard);
+    assert_eq!(stack.model(), &[0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,188 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Arc;
+
+use crate::collections::boxed::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::{PhantomUnsend, PhantomUnsync};
+
+/// Returns the amount of configured CPUs.
+fn cpu_count() -> usize {
+    // SAFETY: The call has no preconditions.
+    let count = unsafe { libc::sysconf(libc::_SC_NPROCESSORS_CONF) };
+
+    if count > 0 {
+        count as usize
+    } else {
+        std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1)
+    }
+}
+
+/// Returns the CPU executing the current thread.
+fn current_cpu() -> usize {
+    // SAFETY: The call has no preconditions.
+    let cpu = unsafe { libc::sched_getcpu() };
+
+    cpu.max(0) as usize
+}
+
+/// Defines a slot of a per-CPU container, padded to avoid false sharing.
+#[repr(align(64))]
+struct Slot<T>(AtomicPtr<T>);
+
+/// Defines a RCU container with one slot per CPU.
+///
+/// Each slot holds a boxed value published under RCU. Readers access the slot of the CPU
+/// they are running on without any lock, while writers replace slots atomically.
+///
+/// # Limitations
+///
+/// ##### Migrations
+///
+/// A thread may be migrated to another CPU right after finding its slot. The local slot
+/// is only a hint to spread the accesses across CPUs. Use [interior mutabillity] that can
+/// be shared between threads for the values.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// ##### CPUs
+///
+/// The amount of slots is the amount of configured CPUs when the container is created. A
+/// CPU brought online afterward shares the slot of another CPU.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuPerCpu<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuPerCpu<T>` without calling any RCU primitives
+/// since lifetime rules prevent any other thread from accessing a RCU reference.
+pub struct RcuPerCpu<T, F = RcuDefaultFlavor> {
+    slots: Box<[Slot<T>]>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuPerCpu<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new per-CPU container, initializing the slot of each CPU with `init`.
+    pub fn new<I>(mut init: I) -> Arc<Self>
+    where
+        I: FnMut(usize) -> T,
+    {
+        Arc::new(Self {
+            slots: (0..cpu_count())
+                .map(|cpu| Slot(AtomicPtr::new(Box::into_raw(Box::new(init(cpu))))))
+                .collect(),
+            refs: RcuRefTracker::new::<F>("RcuPerCpu"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    /// Returns the amount of slots.
+    pub fn cpus(&self) -> usize {
+        self.slots.len()
+    }
+
+    fn local_index(&self) -> usize {
+        current_cpu() % self.slots.len()
+    }
+
+    /// Returns a reference to the value of the current CPU.
+    pub fn local<'me, 'guard, G>(&'me self, guard: &'guard G) -> &'guard T
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        let slot = &self.slots[self.local_index()];
+
+        // SAFETY: The underlying pointer is never null.
+        unsafe { slot.0.load(Ordering::Acquire).as_ref_unchecked() }
+    }
+
+    /// Returns a reference to the value of a CPU, or `None` if there is no slot for it.
+    pub fn get<'me, 'guard, G>(&'me self, cpu: usize, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        self.slots.get(cpu).map(|slot| {
+            // SAFETY: The underlying pointer is never null.
+            unsafe { slot.0.load(Ordering::Acquire).as_ref_unchecked() }
+        })
+    }
+
+    /// Returns an iterator over the values of every CPU.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> impl Iterator<Item = &'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        self.slots.iter().map(|slot| {
+            // SAFETY: The underlying pointer is never null.
+            unsafe { slot.0.load(Ordering::Acquire).as_ref_unchecked() }
+        })
+    }
+
+    /// Replaces the value of a CPU atomically.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if there is no slot for the CPU.
+    pub fn replace(&self, cpu: usize, value: T) -> Ref<T, F>
+    where
+        T: Send,
+    {
+        let slot = self
+            .slots
+            .get(cpu)
+            .unwrap_or_else(|| panic!("CPU {} is out of range (slots: {})", cpu, self.slots.len()));
+
+        let new_ptr = Box::into_raw(Box::new(value));
+        let old_ptr = slot.0.swap(new_ptr, Ordering::AcqRel);
+
+        Ref::new(old_ptr).tracked(&self.refs)
+    }
+
+    /// Replaces the value of the current CPU atomically.
+    pub fn replace_local(&self, value: T) -> Ref<T, F>
+    where
+        T: Send,
+    {
+        self.replace(self.local_index(), value)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuPerCpu`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuPerCpu<T, F> where T: Send {}
+
+/// #### Safety
+///
+/// An [`RcuPerCpu`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuPerCpu<T, F> where T: Sync {}
+
+impl<T, F> Drop for RcuPerCpu<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        for slot in self.slots.iter() {
+            // SAFETY: The underlying pointer is never null.
+            drop(unsafe { Box::from_raw(slot.0.load(Ordering::Relaxed)) });
+        }
+    }
+}
@@ -0,0 +1,28 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::percpu::container::RcuPerCpu;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    // T: !Send + !Sync
+    assert_not_impl_all!(RcuPerCpu<NotSendNotSync, RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuPerCpu<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+    // T: Send + !Sync
+    assert_impl_all!(RcuPerCpu<SendButNotSync, RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuPerCpu<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+    // T: !Send + Sync
+    assert_not_impl_all!(RcuPerCpu<NotSendButSync, RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuPerCpu<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+    // T: Send + Sync
+    assert_impl_all!(RcuPerCpu<SendAndSync, RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuPerCpu<SendAndSync, RcuDefaultFlavor>: Sync);
+}
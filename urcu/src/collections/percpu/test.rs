@@ -0,0 +1,50 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::collections::percpu::container::RcuPerCpu;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn local_statistics() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stats = RcuPerCpu::<AtomicU64>::new(|_| AtomicU64::new(0));
+    let guard = context.rcu_read_lock();
+
+    for _ in 0..100 {
+        stats.local(&guard).fetch_add(1, Ordering::Relaxed);
+    }
+
+    let total: u64 = stats
+        .iter(&guard)
+        .map(|value| value.load(Ordering::Relaxed))
+        .sum();
+
+    assert_eq!(total, 100);
+    assert_eq!(stats.iter(&guard).count(), stats.cpus());
+}
+
+#[test]
+fn replace() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let slots = RcuPerCpu::<usize>::new(|cpu| cpu * 10);
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(slots.get(0, &guard), Some(&0));
+    assert_eq!(slots.get(slots.cpus(), &guard), None);
+
+    let old = slots.replace(0, 4127);
+    assert_eq!(slots.get(0, &guard), Some(&4127));
+    drop(guard);
+
+    assert_eq!(*old.take_ownership(&mut context), 0);
+}
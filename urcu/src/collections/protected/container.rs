@@ -0,0 +1,131 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::boxed::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::{PhantomUnsend, PhantomUnsync};
+
+/// Defines a value protected by RCU for readers and by a lock for writers.
+///
+/// Readers borrow the current value without any lock. Writers are serialized by an
+/// internal lock, update a copy of the value and publish it. The previous value is freed
+/// after a RCU grace period.
+///
+/// # Limitations
+///
+/// ##### Copies
+///
+/// Every write clones the whole value. This type is meant for values read often and
+/// written rarely, such as configurations or routing tables.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuProtected<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuProtected<T>` without calling any RCU primitives
+/// since lifetime rules prevent any other thread from accessing a RCU reference.
+pub struct RcuProtected<T, F = RcuDefaultFlavor> {
+    ptr: AtomicPtr<T>,
+    mutex: Mutex<()>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuProtected<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU protected value.
+    pub fn new(data: T) -> Arc<Self> {
+        Arc::new(Self {
+            ptr: AtomicPtr::new(Box::into_raw(Box::new(data))),
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuProtected"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the current value.
+    pub fn read<'me, 'guard, G>(&'me self, guard: &'guard G) -> &'guard T
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The underlying pointer is never null.
+        unsafe { self.ptr.load(Ordering::Acquire).as_ref_unchecked() }
+    }
+
+    /// Updates a copy of the value with `callback` and publishes it.
+    ///
+    /// The previous value is freed in the RCU cleanup thread after a grace period.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn write<C, R>(&self, callback: C) -> R
+    where
+        T: Clone + Send + 'static,
+        F: 'static,
+        C: FnOnce(&mut T) -> R,
+    {
+        let (result, old) = self.write_with_ref(callback);
+        old.safe_cleanup();
+        result
+    }
+
+    /// Updates a copy of the value with `callback` and publishes it.
+    ///
+    /// The previous value is returned as a RCU reference.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn write_with_ref<C, R>(&self, callback: C) -> (R, Ref<T, F>)
+    where
+        T: Clone + Send,
+        C: FnOnce(&mut T) -> R,
+    {
+        // The copy is published after the callback returns, so the current value is
+        // untouched if a writer panics while holding the lock.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // SAFETY: The underlying pointer is never null.
+        // SAFETY: The value is only removed by writers, which are excluded by the lock.
+        let mut data = unsafe { self.ptr.load(Ordering::Acquire).as_ref_unchecked() }.clone();
+        let result = callback(&mut data);
+
+        let new_ptr = Box::into_raw(Box::new(data));
+        let old_ptr = self.ptr.swap(new_ptr, Ordering::AcqRel);
+
+        drop(guard);
+
+        (result, Ref::new(old_ptr).tracked(&self.refs))
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuProtected`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuProtected<T, F> where T: Send {}
+
+/// #### Safety
+///
+/// An [`RcuProtected`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuProtected<T, F> where T: Sync {}
+
+impl<T, F> Drop for RcuProtected<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The underlying pointer is never null.
+        drop(unsafe { Box::from_raw(self.ptr.load(Ordering::Relaxed)) });
+    }
+}
@@ -0,0 +1,28 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::protected::container::RcuProtected;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    // T: !Send + !Sync
+    assert_not_impl_all!(RcuProtected<NotSendNotSync, RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuProtected<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+    // T: Send + !Sync
+    assert_impl_all!(RcuProtected<SendButNotSync, RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuProtected<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+    // T: !Send + Sync
+    assert_not_impl_all!(RcuProtected<NotSendButSync, RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuProtected<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+    // T: Send + Sync
+    assert_impl_all!(RcuProtected<SendAndSync, RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuProtected<SendAndSync, RcuDefaultFlavor>: Sync);
+}
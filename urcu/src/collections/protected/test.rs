@@ -0,0 +1,42 @@
+use crate::collections::protected::container::RcuProtected;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn write() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let routes = RcuProtected::<Vec<u32>>::new(vec![1, 2]);
+    let guard = context.rcu_read_lock();
+    let before = routes.read(&guard);
+
+    let len = routes.write(|routes| {
+        routes.push(3);
+        routes.len()
+    });
+
+    assert_eq!(len, 3);
+    assert_eq!(before, &vec![1, 2]);
+    assert_eq!(routes.read(&guard), &vec![1, 2, 3]);
+}
+
+#[test]
+fn write_with_ref() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let value = RcuProtected::<u32>::new(4127);
+
+    let ((), old) = value.write_with_ref(|value| *value += 1);
+    assert_eq!(*old.take_ownership(&mut context), 4127);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(value.read(&guard), &4128);
+}
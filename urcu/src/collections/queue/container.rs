@@ -1,12 +1,17 @@
 use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use crate::collections::queue::raw::{RawNode, RawQueue};
 use crate::collections::queue::reference::Ref;
+use crate::rcu::context::RcuReadContext;
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
 use crate::rcu::guard::RcuGuard;
+use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::RcuRefTracker;
 use crate::utility::*;
 
 /// Defines a RCU wait-free queue.
@@ -30,6 +35,8 @@ use crate::utility::*;
 /// prevent any other thread from accessing a RCU reference.
 pub struct RcuQueue<T, F = RcuDefaultFlavor> {
     raw: RawQueue<T, F>,
+    len: AtomicUsize,
+    refs: RcuRefTracker,
     _unsend: PhantomUnsend,
     _unsync: PhantomUnsync,
 }
@@ -43,6 +50,8 @@ where
         let mut queue = Arc::new(RcuQueue {
             // SAFETY: Initialisation is properly called.
             raw: unsafe { RawQueue::new() },
+            len: AtomicUsize::new(0),
+            refs: RcuRefTracker::new::<F>("RcuQueue"),
             _unsend: PhantomData,
             _unsync: PhantomData,
         });
@@ -62,6 +71,9 @@ where
     {
         let node = RawNode::new(data);
 
+        // The length is incremented first so that it never underflows.
+        self.len.fetch_add(1, Ordering::Relaxed);
+
         // SAFETY: The RCU read-lock is taken.
         unsafe { self.raw.enqueue(node) };
     }
@@ -74,7 +86,64 @@ where
     {
         // SAFETY: The RCU read-lock is taken.
         // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
-        NonNull::new(unsafe { self.raw.dequeue() }).map(Ref::<T, F>::new)
+        let node = NonNull::new(unsafe { self.raw.dequeue() })?;
+        self.len.fetch_sub(1, Ordering::Relaxed);
+
+        Some(Ref::<T, F>::new(node).tracked(&self.refs))
+    }
+
+    /// Removes an element to the front of the queue, if any, and takes its ownership.
+    ///
+    /// #### Note
+    ///
+    /// This operation blocks for a RCU grace period if an element is removed.
+    pub fn pop_owned<C>(&self, context: &mut C) -> Option<T>
+    where
+        T: Send + 'static,
+        F: 'static,
+        C: RcuReadContext<Flavor = F>,
+    {
+        let node = self.pop(&context.rcu_read_lock())?;
+
+        Some(node.take_ownership(context).into_inner())
+    }
+
+    /// Moves approximately half of the elements to the back of another queue.
+    ///
+    /// The elements are taken from the front of the queue and keep their order. The
+    /// amount of moved elements is returned.
+    ///
+    /// #### Note
+    ///
+    /// The length of the queue is only approximated while there are concurrent writers.
+    pub fn steal_half<G>(&self, dest: &RcuQueue<T, F>, guard: &G) -> usize
+    where
+        T: Send + 'static,
+        F: 'static,
+        G: RcuGuard<Flavor = F>,
+    {
+        let count = self.len.load(Ordering::Relaxed).div_ceil(2);
+        let mut nodes = Vec::with_capacity(count);
+
+        while nodes.len() < count {
+            // SAFETY: The RCU read-lock is taken.
+            let Some(node) = NonNull::new(unsafe { self.raw.dequeue() }) else {
+                break;
+            };
+
+            self.len.fetch_sub(1, Ordering::Relaxed);
+
+            // SAFETY: The node is dequeued and the queue never lends its data.
+            let (data, node) = unsafe { RawNode::take_data(node) };
+            dest.push(data, guard);
+
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            nodes.push(Ref::<ManuallyDrop<T>, F>::new(node));
+        }
+
+        let stolen = nodes.len();
+        nodes.safe_cleanup();
+        stolen
     }
 }
 
@@ -100,6 +169,8 @@ where
 
 impl<T, F> Drop for RcuQueue<T, F> {
     fn drop(&mut self) {
+        self.refs.report();
+
         // SAFETY: The RCU read-lock is not needed there are no other writers.
         // SAFETY: The RCU grace period is not needed there are no other readers.
         for ptr in unsafe { self.raw.dequeue_all() } {
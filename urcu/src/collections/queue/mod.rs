@@ -1,4 +1,5 @@
 pub(crate) mod container;
+pub(crate) mod priority;
 pub(crate) mod raw;
 pub(crate) mod reference;
 
@@ -13,6 +14,7 @@ mod asserts {
     use static_assertions::{assert_impl_all, assert_not_impl_all};
 
     use crate::collections::queue::container::RcuQueue;
+    use crate::collections::queue::priority::RcuPriorityQueue;
     use crate::rcu::default::RcuDefaultFlavor;
     use crate::utility::asserts::*;
 
@@ -36,6 +38,26 @@ mod asserts {
         assert_impl_all!(RcuQueue<SendAndSync, RcuDefaultFlavor>: Sync);
     }
 
+    mod rcu_priority_queue {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuPriorityQueue<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuPriorityQueue<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuPriorityQueue<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuPriorityQueue<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuPriorityQueue<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuPriorityQueue<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuPriorityQueue<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuPriorityQueue<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
     mod rcu_queue_ref {
         use super::*;
 
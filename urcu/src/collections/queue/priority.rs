@@ -0,0 +1,145 @@
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+use crate::collections::queue::raw::{RawNode, RawQueue};
+use crate::collections::queue::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU wait-free queue with strict priority lanes.
+///
+/// This queue is composed of `LANES` internal queues. The lane `0` has the highest
+/// priority and an element is only removed from a lane once all the lanes with a
+/// higher priority are empty.
+///
+/// # Limitations
+///
+/// ##### Ordering
+///
+/// Elements are only ordered relative to other elements of the same lane. Because
+/// writers might concurrently add elements, a call to [`RcuPriorityQueue::pop`] might
+/// return an element from a lower priority lane even if a higher priority element was
+/// added during the call.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuPriorityQueue<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuPriorityQueue<T>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing a RCU reference.
+pub struct RcuPriorityQueue<T, F = RcuDefaultFlavor, const LANES: usize = 2> {
+    lanes: [RawQueue<T, F>; LANES],
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend,
+    _unsync: PhantomUnsync,
+}
+
+impl<T, F, const LANES: usize> RcuPriorityQueue<T, F, LANES>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU priority queue.
+    pub fn new() -> Arc<Self> {
+        const { assert!(LANES > 0, "priority queue must have at least one lane") };
+
+        let mut queue = Arc::new(RcuPriorityQueue {
+            // SAFETY: Initialisation is properly called.
+            lanes: std::array::from_fn(|_| unsafe { RawQueue::new() }),
+            refs: RcuRefTracker::new::<F>("RcuPriorityQueue"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw queues are in a stable memory location.
+        // SAFETY: All the nodes are removed upon dropping.
+        for lane in Arc::<Self>::get_mut(&mut queue).unwrap().lanes.iter_mut() {
+            unsafe { lane.init() };
+        }
+
+        queue
+    }
+
+    /// Adds an element to the back of a lane.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `lane` is greater or equal to `LANES`.
+    pub fn push<G>(&self, data: T, lane: usize, _guard: &G)
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        let node = RawNode::new(data);
+
+        // SAFETY: The RCU read-lock is taken.
+        unsafe { self.lanes[lane].enqueue(node) };
+    }
+
+    /// Removes an element from the front of the highest priority non-empty lane, if any.
+    pub fn pop<G>(&self, _guard: &G) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.lanes.iter().find_map(|lane| {
+            // SAFETY: The RCU read-lock is taken.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            NonNull::new(unsafe { lane.dequeue() })
+                .map(|node| Ref::<T, F>::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Removes an element from the front of a specific lane, if any.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `lane` is greater or equal to `LANES`.
+    pub fn pop_lane<G>(&self, lane: usize, _guard: &G) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU read-lock is taken.
+        // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+        NonNull::new(unsafe { self.lanes[lane].dequeue() })
+            .map(|node| Ref::<T, F>::new(node).tracked(&self.refs))
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuPriorityQueue`] can be used to send `T` to another thread.
+unsafe impl<T, F, const LANES: usize> Send for RcuPriorityQueue<T, F, LANES>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuPriorityQueue`] can be used to share `T` between threads.
+unsafe impl<T, F, const LANES: usize> Sync for RcuPriorityQueue<T, F, LANES>
+where
+    T: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F, const LANES: usize> Drop for RcuPriorityQueue<T, F, LANES> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        for lane in self.lanes.iter() {
+            // SAFETY: The RCU read-lock is not needed there are no other writers.
+            // SAFETY: The RCU grace period is not needed there are no other readers.
+            for ptr in unsafe { lane.dequeue_all() } {
+                // SAFETY: The pointer is always non-null and valid.
+                drop(unsafe { Box::from_raw(ptr) });
+            }
+        }
+    }
+}
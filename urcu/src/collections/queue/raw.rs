@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
-use std::mem::MaybeUninit;
+use std::mem::{ManuallyDrop, MaybeUninit};
 use std::ops::Deref;
+use std::ptr::NonNull;
 
 use container_of::container_of;
 use urcu_cds_sys::lfq;
@@ -8,10 +9,12 @@ use urcu_cds_sys::lfq;
 use crate::rcu::flavor::RcuFlavor;
 use crate::utility::*;
 
+#[repr(C)]
 pub struct RawNode<T> {
     handle: lfq::NodeRcu,
     data: T,
 }
+
 impl<T> RawNode<T> {
     pub fn new(data: T) -> Box<Self> {
         let mut handle = MaybeUninit::<lfq::NodeRcu>::uninit();
@@ -31,6 +34,25 @@ impl<T> RawNode<T> {
         let node = unsafe { node_ptr.as_mut_unchecked() };
         &mut node.handle
     }
+
+    /// Moves the data out of the node, leaving a node that doesn't own it.
+    ///
+    /// #### Safety
+    ///
+    /// The node must be removed from the queue and its data must not be borrowed.
+    ///
+    /// The caller must wait a RCU grace period before freeing the returned node.
+    pub unsafe fn take_data(node: NonNull<Self>) -> (T, NonNull<RawNode<ManuallyDrop<T>>>) {
+        // SAFETY: The data is never read again through the original node.
+        let data = unsafe { std::ptr::read(&node.as_ref().data) };
+
+        // SAFETY: The layout is the same, `ManuallyDrop<T>` is transparent.
+        (data, node.cast())
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
 }
 
 impl<T> Deref for RawNode<T> {
@@ -6,6 +6,13 @@ use crate::rcu::reference;
 /// [`RcuQueue`]: crate::collections::queue::container::RcuQueue
 pub type RefOwned<T> = reference::BoxRefOwned<RawNode<T>>;
 
+impl<T> RefOwned<T> {
+    /// Moves the element out of the reference.
+    pub fn into_inner(self) -> T {
+        self.into_box().into_data()
+    }
+}
+
 /// An RCU reference to a element removed from an [`RcuQueue`].
 ///
 /// [`RcuQueue`]: crate::collections::queue::container::RcuQueue
@@ -1,4 +1,5 @@
 use crate::collections::queue::container::RcuQueue;
+use crate::collections::queue::priority::RcuPriorityQueue;
 use crate::rcu::context::RcuReadContext;
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
@@ -36,3 +37,87 @@ fn simple() {
     assert_eq!(queue.pop(&guard).as_deref(), None);
     assert_eq!(queue.pop(&guard).as_deref(), None);
 }
+
+#[test]
+fn priority() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let queue = RcuPriorityQueue::<u32, RcuDefaultFlavor, 3>::new();
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(queue.pop(&guard).as_deref(), None);
+
+    queue.push(10, 2, &guard);
+    queue.push(20, 1, &guard);
+    queue.push(30, 2, &guard);
+    queue.push(40, 0, &guard);
+    queue.push(50, 1, &guard);
+
+    assert_eq!(queue.pop(&guard).as_deref(), Some(&40));
+    assert_eq!(queue.pop(&guard).as_deref(), Some(&20));
+
+    queue.push(60, 0, &guard);
+
+    assert_eq!(queue.pop_lane(2, &guard).as_deref(), Some(&10));
+    assert_eq!(queue.pop(&guard).as_deref(), Some(&60));
+    assert_eq!(queue.pop(&guard).as_deref(), Some(&50));
+    assert_eq!(queue.pop(&guard).as_deref(), Some(&30));
+    assert_eq!(queue.pop(&guard).as_deref(), None);
+}
+
+#[test]
+fn steal_half() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let source = RcuQueue::<u32>::new();
+    let dest = RcuQueue::<u32>::new();
+    let guard = context.rcu_read_lock();
+
+    assert_eq!(source.steal_half(&dest, &guard), 0);
+
+    source.push(10, &guard);
+    source.push(20, &guard);
+    source.push(30, &guard);
+    source.push(40, &guard);
+    source.push(50, &guard);
+    dest.push(60, &guard);
+
+    assert_eq!(source.steal_half(&dest, &guard), 3);
+
+    assert_eq!(source.pop(&guard).as_deref(), Some(&40));
+    assert_eq!(source.pop(&guard).as_deref(), Some(&50));
+    assert_eq!(source.pop(&guard).as_deref(), None);
+
+    assert_eq!(dest.pop(&guard).as_deref(), Some(&60));
+    assert_eq!(dest.pop(&guard).as_deref(), Some(&10));
+    assert_eq!(dest.pop(&guard).as_deref(), Some(&20));
+    assert_eq!(dest.pop(&guard).as_deref(), Some(&30));
+    assert_eq!(dest.pop(&guard).as_deref(), None);
+}
+
+#[test]
+fn pop_owned() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let queue = RcuQueue::<String>::new();
+
+    assert_eq!(queue.pop_owned(&mut context), None);
+
+    let guard = context.rcu_read_lock();
+    queue.push("first".to_owned(), &guard);
+    queue.push("second".to_owned(), &guard);
+    drop(guard);
+
+    assert_eq!(queue.pop_owned(&mut context).as_deref(), Some("first"));
+    assert_eq!(queue.pop_owned(&mut context).as_deref(), Some("second"));
+    assert_eq!(queue.pop_owned(&mut context), None);
+}
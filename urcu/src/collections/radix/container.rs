@@ -0,0 +1,235 @@
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::boxed::reference::Ref;
+use crate::collections::radix::raw::{RawPruned, RawTree};
+use crate::rcu::callback::RcuCallFn;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU radix tree with longest-prefix-match lookups.
+///
+/// The keys are bit strings given as a byte slice and a length in bits, such as the
+/// network prefixes of a routing table (e.g. `10.0.0.0/8` is `(&[10, 0, 0, 0], 8)`).
+/// Lookups are lock-free and the tree uses an internal lock for writing operations.
+///
+/// # Limitations
+///
+/// ##### Path Compression
+///
+/// Each level of the tree consumes a single bit of the key, so a lookup visits as many
+/// nodes as the length of the matched prefix.
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a value, it is impossible to get a
+/// mutable references to the values inside the tree. You should design the type stored
+/// in the tree with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuRadixTree<V>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuRadixTree<V>` without calling any RCU primitives
+/// since lifetime rules prevent any other thread from accessing a RCU reference.
+pub struct RcuRadixTree<V, F = RcuDefaultFlavor> {
+    raw: RawTree<V>,
+    mutex: Mutex<()>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<V, F> RcuRadixTree<V, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU radix tree.
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            raw: RawTree::new(),
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuRadixTree"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce() -> R,
+    {
+        // The nodes are linked and unlinked without calling user code, so the tree is
+        // still consistent if a writer panics while holding the lock.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback();
+        drop(guard);
+        result
+    }
+
+    /// Inserts a value at a prefix of `len` bits, returning the previous value.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `len` is larger than the amount of bits in `prefix`.
+    pub fn insert(&self, prefix: &[u8], len: usize, value: V) -> Option<Ref<V, F>>
+    where
+        V: Send,
+    {
+        Self::check_prefix(prefix, len);
+
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<V, F>`.
+            let ptr = unsafe { self.raw.insert(prefix, len, Box::new(value)) };
+
+            (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+        })
+    }
+
+    /// Removes the value at a prefix of `len` bits.
+    ///
+    /// The nodes left empty are reclaimed by the RCU cleanup thread.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `len` is larger than the amount of bits in `prefix`.
+    pub fn remove(&self, prefix: &[u8], len: usize) -> Option<Ref<V, F>>
+    where
+        V: Send + 'static,
+        F: 'static,
+    {
+        Self::check_prefix(prefix, len);
+
+        self.with_mutex(|| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<V, F>`.
+            // SAFETY: The RCU grace period is enforced for the pruned nodes.
+            let (ptr, pruned) = unsafe { self.raw.remove(prefix, len) };
+
+            Self::reclaim(pruned);
+
+            (!ptr.is_null()).then(|| Ref::new(ptr).tracked(&self.refs))
+        })
+    }
+
+    /// Returns a reference to the value at a prefix of `len` bits.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `len` is larger than the amount of bits in `prefix`.
+    pub fn get<'me, 'guard, G>(
+        &'me self,
+        prefix: &[u8],
+        len: usize,
+        guard: &'guard G,
+    ) -> Option<&'guard V>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        Self::check_prefix(prefix, len);
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The pointer can be converted to a reference.
+        unsafe { self.raw.get(prefix, len).as_ref() }
+    }
+
+    /// Returns the value of the longest prefix matching a key, along with the length of
+    /// the prefix in bits.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*b*) time, where *b* is the amount of bits.
+    pub fn longest_match<'me, 'guard, G>(
+        &'me self,
+        key: &[u8],
+        guard: &'guard G,
+    ) -> Option<(usize, &'guard V)>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        let (len, ptr) = unsafe { self.raw.longest_match(key) };
+
+        // SAFETY: The pointer can be converted to a reference.
+        unsafe { ptr.as_ref() }.map(|value| (len, value))
+    }
+
+    /// Returns `true` if the tree is empty.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*1*) time.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    fn check_prefix(prefix: &[u8], len: usize) {
+        assert!(
+            len <= prefix.len() * 8,
+            "prefix length {} is larger than the key ({} bits)",
+            len,
+            prefix.len() * 8
+        );
+    }
+
+    fn reclaim(pruned: RawPruned<V>)
+    where
+        V: 'static,
+        F: 'static,
+    {
+        if pruned.is_empty() {
+            return;
+        }
+
+        F::rcu_cleanup(Box::new(move |context| {
+            // The nodes are dropped after a RCU grace period.
+            context.rcu_call(RcuCallFn::new(move || drop(pruned)));
+        }));
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuRadixTree`] can be used to send `V` to another thread.
+unsafe impl<V, F> Send for RcuRadixTree<V, F>
+where
+    V: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuRadixTree`] can be used to share `V` between threads.
+unsafe impl<V, F> Sync for RcuRadixTree<V, F>
+where
+    V: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<V, F> Drop for RcuRadixTree<V, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+    }
+}
@@ -0,0 +1,33 @@
+pub(crate) mod container;
+pub(crate) mod raw;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::radix::container::RcuRadixTree;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    mod rcu_radix_tree {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuRadixTree<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuRadixTree<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuRadixTree<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuRadixTree<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuRadixTree<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuRadixTree<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuRadixTree<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuRadixTree<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+}
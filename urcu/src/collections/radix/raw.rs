@@ -0,0 +1,203 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::utility::*;
+
+/// Returns a bit of a key, starting from the most significant bit of the first byte.
+fn bit(key: &[u8], index: usize) -> usize {
+    ((key[index / 8] >> (7 - index % 8)) & 1) as usize
+}
+
+pub struct RawNode<V> {
+    children: [AtomicPtr<RawNode<V>>; 2],
+    value: AtomicPtr<V>,
+}
+
+impl<V> RawNode<V> {
+    fn new() -> Self {
+        Self {
+            children: Default::default(),
+            value: Default::default(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.value.load(Ordering::Relaxed).is_null()
+            && self.children[0].load(Ordering::Relaxed).is_null()
+            && self.children[1].load(Ordering::Relaxed).is_null()
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section or have mutual exclusion from writers.
+    unsafe fn child(&self, bit: usize) -> Option<&Self> {
+        self.children[bit].load(Ordering::Acquire).as_ref()
+    }
+}
+
+/// Defines nodes unlinked from the tree, waiting for a RCU grace period.
+#[allow(clippy::vec_box)]
+pub struct RawPruned<V>(Vec<Box<RawNode<V>>>);
+
+impl<V> RawPruned<V> {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Defines a binary trie where each level consumes a bit of the key.
+pub struct RawTree<V> {
+    root: RawNode<V>,
+    _unsend: PhantomUnsend<V>,
+    _unsync: PhantomUnsync<V>,
+}
+
+impl<V> RawTree<V> {
+    pub fn new() -> Self {
+        Self {
+            root: RawNode::new(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// Stores a value at a prefix, returning the previous value.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the returned value.
+    pub unsafe fn insert(&self, prefix: &[u8], len: usize, value: Box<V>) -> *mut V {
+        let mut node = &self.root;
+
+        for index in 0..len {
+            let link = &node.children[bit(prefix, index)];
+
+            if link.load(Ordering::Relaxed).is_null() {
+                // The node is fully initialized before being published.
+                let child = Box::into_raw(Box::new(RawNode::new()));
+                link.store(child, Ordering::Release);
+            }
+
+            node = link.load(Ordering::Relaxed).as_ref_unchecked();
+        }
+
+        node.value.swap(Box::into_raw(value), Ordering::AcqRel)
+    }
+
+    /// Removes the value at a prefix, unlinking the nodes left empty.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the value and the nodes.
+    pub unsafe fn remove(&self, prefix: &[u8], len: usize) -> (*mut V, RawPruned<V>) {
+        let mut path = Vec::with_capacity(len);
+        let mut node = &self.root;
+
+        for index in 0..len {
+            let bit = bit(prefix, index);
+
+            match node.child(bit) {
+                None => return (std::ptr::null_mut(), RawPruned(Vec::new())),
+                Some(child) => {
+                    path.push((node, bit));
+                    node = child;
+                }
+            }
+        }
+
+        let value = node.value.swap(std::ptr::null_mut(), Ordering::Relaxed);
+        let mut pruned = Vec::new();
+
+        while let Some((parent, bit)) = path.pop() {
+            let child = parent.children[bit].load(Ordering::Relaxed);
+
+            if !child.as_ref_unchecked().is_empty() {
+                break;
+            }
+
+            parent.children[bit].store(std::ptr::null_mut(), Ordering::Release);
+            pruned.push(Box::from_raw(child));
+        }
+
+        (value, RawPruned(pruned))
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get(&self, prefix: &[u8], len: usize) -> *const V {
+        let mut node = &self.root;
+
+        for index in 0..len {
+            match node.child(bit(prefix, index)) {
+                None => return std::ptr::null(),
+                Some(child) => node = child,
+            }
+        }
+
+        node.value.load(Ordering::Acquire)
+    }
+
+    /// Returns the value of the longest prefix matching a key, with the prefix length.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn longest_match(&self, key: &[u8]) -> (usize, *const V) {
+        let mut node = &self.root;
+        let mut found = (0, node.value.load(Ordering::Acquire).cast_const());
+
+        for index in 0..key.len() * 8 {
+            match node.child(bit(key, index)) {
+                None => break,
+                Some(child) => node = child,
+            }
+
+            let value = node.value.load(Ordering::Acquire);
+            if !value.is_null() {
+                found = (index + 1, value);
+            }
+        }
+
+        found
+    }
+
+    pub fn empty(&self) -> bool {
+        self.root.is_empty()
+    }
+}
+
+impl<V> Drop for RawTree<V> {
+    fn drop(&mut self) {
+        let root: *mut RawNode<V> = &mut self.root;
+        let mut nodes = vec![root];
+
+        while let Some(ptr) = nodes.pop() {
+            // SAFETY: The nodes are reachable from a single parent.
+            let node = unsafe { ptr.as_mut_unchecked() };
+
+            for child in &mut node.children {
+                let child = *child.get_mut();
+
+                if !child.is_null() {
+                    nodes.push(child);
+                }
+            }
+
+            let value = *node.value.get_mut();
+            if !value.is_null() {
+                // SAFETY: The values are allocated by `RawTree::insert`.
+                drop(unsafe { Box::from_raw(value) });
+            }
+
+            if ptr != root {
+                // SAFETY: The nodes are allocated by `RawTree::insert`.
+                drop(unsafe { Box::from_raw(ptr) });
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::collections::radix::container::RcuRadixTree;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn longest_match() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let tree = RcuRadixTree::<&str>::new();
+    assert!(tree.is_empty());
+
+    assert!(tree.insert(&[0, 0, 0, 0], 0, "default").is_none());
+    assert!(tree.insert(&[10, 0, 0, 0], 8, "private").is_none());
+    assert!(tree.insert(&[10, 1, 0, 0], 16, "site").is_none());
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        tree.longest_match(&[10, 1, 2, 3], &guard),
+        Some((16, &"site"))
+    );
+    assert_eq!(
+        tree.longest_match(&[10, 2, 2, 3], &guard),
+        Some((8, &"private"))
+    );
+    assert_eq!(
+        tree.longest_match(&[192, 168, 0, 1], &guard),
+        Some((0, &"default"))
+    );
+    assert_eq!(tree.get(&[10, 0, 0, 0], 8, &guard), Some(&"private"));
+    assert_eq!(tree.get(&[10, 0, 0, 0], 9, &guard), None);
+
+    let replaced = tree.insert(&[10, 1, 0, 0], 16, "campus").unwrap();
+    assert_eq!(
+        tree.longest_match(&[10, 1, 2, 3], &guard),
+        Some((16, &"campus"))
+    );
+
+    let removed = tree.remove(&[10, 1, 0, 0], 16).unwrap();
+    assert!(tree.remove(&[10, 1, 0, 0], 16).is_none());
+    assert_eq!(
+        tree.longest_match(&[10, 1, 2, 3], &guard),
+        Some((8, &"private"))
+    );
+    drop(guard);
+
+    assert_eq!(*replaced.take_ownership(&mut context), "site");
+    assert_eq!(*removed.take_ownership(&mut context), "campus");
+
+    let removed = (
+        tree.remove(&[10, 0, 0, 0], 8).unwrap(),
+        tree.remove(&[0, 0, 0, 0], 0).unwrap(),
+    );
+    assert!(tree.is_empty());
+
+    let removed = removed.take_ownership(&mut context);
+    assert_eq!((*removed.0, *removed.1), ("private", "default"));
+}
@@ -0,0 +1,266 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::collections::boxed::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a slot of the ring buffer.
+///
+/// The sequence number tells whether the slot is ready for a producer or a consumer of
+/// a given lap around the buffer.
+struct Slot<T> {
+    sequence: AtomicUsize,
+    ptr: AtomicPtr<T>,
+}
+
+/// Defines a RCU bounded multi-producer multi-consumer ring buffer.
+///
+/// The elements are referenced from a fixed array of slots, so the amount of elements
+/// in the buffer is bounded. Producers and consumers never take a lock. A popped element
+/// is returned as a RCU reference since readers may still be peeking at it.
+///
+/// # Limitations
+///
+/// ##### Capacity
+///
+/// The capacity is rounded up to the next power of two. Pushing an element in a full
+/// buffer fails instead of waiting for a consumer.
+///
+/// ##### Progress
+///
+/// Producers and consumers claim a slot with a compare-and-swap loop, so they are
+/// lock-free but not wait-free. A thread may retry while other threads make progress.
+///
+/// ##### Memory
+///
+/// Each element is allocated separately and a slot is reused as soon as its element is
+/// popped. The popped elements are only freed after a RCU grace period, so they are not
+/// accounted for by the capacity.
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing an element, it is impossible to get a
+/// mutable references to the elements inside the buffer. You should design the type stored
+/// in the buffer with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuRingBuffer<T>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuRingBuffer<T>` without calling any RCU primitives
+/// since lifetime rules prevent any other thread from accessing a RCU reference.
+pub struct RcuRingBuffer<T, F = RcuDefaultFlavor> {
+    slots: Box<[Slot<T>]>,
+    /// The position of the next element to pop.
+    head: AtomicUsize,
+    /// The position of the next element to push.
+    tail: AtomicUsize,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuRingBuffer<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU ring buffer holding at least `capacity` elements.
+    ///
+    /// #### Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new(capacity: usize) -> Arc<Self> {
+        assert!(capacity > 0, "capacity of the ring buffer must be non-zero");
+
+        let slots = (0..capacity.next_power_of_two())
+            .map(|sequence| Slot {
+                sequence: AtomicUsize::new(sequence),
+                ptr: AtomicPtr::default(),
+            })
+            .collect();
+
+        Arc::new(Self {
+            slots,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            refs: RcuRefTracker::new::<F>("RcuRingBuffer"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn slot(&self, position: usize) -> &Slot<T> {
+        &self.slots[position & (self.slots.len() - 1)]
+    }
+
+    /// Adds an element to the back of the buffer.
+    ///
+    /// Returns the element if the buffer is full.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn try_push(&self, data: T) -> Result<(), T>
+    where
+        T: Send,
+    {
+        let mut position = self.tail.load(Ordering::Relaxed);
+
+        loop {
+            let slot = self.slot(position);
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            match sequence.wrapping_sub(position) as isize {
+                0 => match self.tail.compare_exchange_weak(
+                    position,
+                    position.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        slot.ptr
+                            .store(Box::into_raw(Box::new(data)), Ordering::Release);
+                        slot.sequence
+                            .store(position.wrapping_add(1), Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => position = current,
+                },
+                // The slot still holds an element from the previous lap.
+                difference if difference < 0 => return Err(data),
+                _ => position = self.tail.load(Ordering::Relaxed),
+            }
+        }
+    }
+
+    /// Removes an element from the front of the buffer.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn pop(&self) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        let mut position = self.head.load(Ordering::Relaxed);
+
+        loop {
+            let slot = self.slot(position);
+            let sequence = slot.sequence.load(Ordering::Acquire);
+
+            match sequence.wrapping_sub(position.wrapping_add(1)) as isize {
+                0 => match self.head.compare_exchange_weak(
+                    position,
+                    position.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let ptr = slot.ptr.swap(std::ptr::null_mut(), Ordering::Acquire);
+                        slot.sequence
+                            .store(position.wrapping_add(self.slots.len()), Ordering::Release);
+
+                        // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+                        return Some(Ref::new(ptr).tracked(&self.refs));
+                    }
+                    Err(current) => position = current,
+                },
+                // The slot has not been filled yet.
+                difference if difference < 0 => return None,
+                _ => position = self.head.load(Ordering::Relaxed),
+            }
+        }
+    }
+
+    /// Returns a reference to the front element, or `None` if the buffer is empty.
+    ///
+    /// #### Note
+    ///
+    /// The element may be popped by a consumer while the reference is alive.
+    pub fn peek<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        let position = self.head.load(Ordering::Acquire);
+        let slot = self.slot(position);
+
+        if slot.sequence.load(Ordering::Acquire) != position.wrapping_add(1) {
+            return None;
+        }
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: A popped element is reclaimed after a RCU grace period.
+        unsafe { slot.ptr.load(Ordering::Acquire).as_ref() }
+    }
+
+    /// Returns the amount of elements in the buffer.
+    ///
+    /// #### Note
+    ///
+    /// The amount may be outdated if there are concurrent producers or consumers.
+    pub fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        let tail = self.tail.load(Ordering::Acquire);
+
+        tail.wrapping_sub(head).min(self.slots.len())
+    }
+
+    /// Returns `true` if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the buffer is full.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.slots.len()
+    }
+
+    /// Returns the maximum amount of elements in the buffer.
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuRingBuffer`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuRingBuffer<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuRingBuffer`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuRingBuffer<T, F>
+where
+    T: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuRingBuffer<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        for slot in self.slots.iter_mut() {
+            let ptr = *slot.ptr.get_mut();
+
+            if !ptr.is_null() {
+                // SAFETY: The RCU grace period is not needed because there are no other readers.
+                drop(unsafe { Box::from_raw(ptr) });
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::ring::container::RcuRingBuffer;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    mod rcu_ring_buffer {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuRingBuffer<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuRingBuffer<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuRingBuffer<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuRingBuffer<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuRingBuffer<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuRingBuffer<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuRingBuffer<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuRingBuffer<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::collections::ring::container::RcuRingBuffer;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn bounded() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let ring = RcuRingBuffer::<u32>::new(3);
+    assert_eq!(ring.capacity(), 4);
+    assert!(ring.is_empty());
+    assert!(ring.pop().is_none());
+
+    for value in 0..4 {
+        ring.try_push(value).unwrap();
+    }
+
+    assert!(ring.is_full());
+    assert_eq!(ring.try_push(4), Err(4));
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(ring.peek(&guard), Some(&0));
+
+    let first = ring.pop().unwrap();
+    assert_eq!(ring.peek(&guard), Some(&1));
+    ring.try_push(4).unwrap();
+    assert_eq!(ring.len(), 4);
+    drop(guard);
+
+    let popped = (0..4).map(|_| ring.pop().unwrap()).collect::<Vec<_>>();
+    assert!(ring.pop().is_none());
+
+    assert_eq!(*first.take_ownership(&mut context), 0);
+
+    let popped = popped.take_ownership(&mut context);
+    assert_eq!(
+        popped.iter().map(|x| **x).collect::<Vec<_>>(),
+        vec![1, 2, 3, 4]
+    );
+}
+
+#[test]
+fn concurrent() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let ring = RcuRingBuffer::<u32>::new(16);
+
+    let popped = std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let ring = &ring;
+
+            scope.spawn(move || {
+                for value in 0..100 {
+                    let mut data = thread * 100 + value;
+
+                    while let Err(rejected) = ring.try_push(data) {
+                        data = rejected;
+                        std::thread::yield_now();
+                    }
+                }
+            });
+        }
+
+        let mut popped = Vec::new();
+        while popped.len() < 400 {
+            match ring.pop() {
+                Some(data) => popped.push(data),
+                None => std::thread::yield_now(),
+            }
+        }
+
+        popped
+    });
+
+    let mut values = popped
+        .take_ownership(&mut context)
+        .into_iter()
+        .map(|x| *x)
+        .collect::<Vec<_>>();
+
+    values.sort();
+    assert_eq!(values, (0..400).collect::<Vec<_>>());
+}
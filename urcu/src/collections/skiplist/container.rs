@@ -0,0 +1,260 @@
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+use std::ptr::NonNull;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::skiplist::iterator::Iter;
+use crate::collections::skiplist::raw::{RawIter, RawNode, RawSkipList, MAX_HEIGHT};
+use crate::collections::skiplist::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines a RCU sorted map implemented as a skip list.
+///
+/// This skip list supports multiple concurrents readers at any time, but only a single
+/// writer at a time. The list uses an internal lock for writing operations.
+///
+/// Unlike the other data structures, it is implemented in Rust since `liburcu-cds` does
+/// not provide an ordered container.
+///
+/// # Limitations
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a node's data, it is impossible
+/// to get a mutable references to the data inside the list. You should design the type
+/// stored in the list with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// ##### Replacement
+///
+/// When a key is replaced, the new entry is linked before the old one is unlinked. An
+/// iterator running concurrently may yield both entries.
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuSkipList<K, V>>` to a non-registered RCU thread. A
+/// non-registered thread may drop an `RcuSkipList<K, V>` without calling any RCU
+/// primitives since lifetime rules prevent any other thread from accessing a RCU reference.
+pub struct RcuSkipList<K, V, F = RcuDefaultFlavor> {
+    raw: RawSkipList<K, V>,
+    /// The state of the random generator for the height of the nodes.
+    mutex: Mutex<u64>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<K, V, F> RcuSkipList<K, V, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU skip list.
+    pub fn new() -> Arc<Self> {
+        Arc::new(RcuSkipList {
+            raw: RawSkipList::new(),
+            mutex: Mutex::new(0x853c_49e6_748f_ea9b),
+            refs: RcuRefTracker::new::<F>("RcuSkipList"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce(&mut u64) -> R,
+    {
+        // The keys are compared before any node is linked or unlinked, so the list is
+        // still consistent if a writer panics while holding the lock.
+        let mut guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback(&mut guard);
+        drop(guard);
+        result
+    }
+
+    /// Returns a random height with a geometric distribution.
+    fn random_height(state: &mut u64) -> usize {
+        // xorshift64
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+
+        (state.trailing_ones() as usize + 1).min(MAX_HEIGHT)
+    }
+
+    /// Inserts a key-value pair in the list.
+    ///
+    /// If the list already had this key present, the old entry is returned.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn insert(&self, key: K, value: V) -> Option<Ref<K, V, F>>
+    where
+        K: Send + Ord,
+        V: Send,
+    {
+        self.with_mutex(|state| {
+            let node = RawNode::new(key, value, Self::random_height(state));
+
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<K, V, F>`.
+            let node = unsafe { self.raw.insert(node) };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Removes a key from the list, returning the entry if the key was in the list.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn remove(&self, key: &K) -> Option<Ref<K, V, F>>
+    where
+        K: Send + Ord,
+        V: Send,
+    {
+        self.with_mutex(|_| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<K, V, F>`.
+            let node = unsafe { self.raw.remove(key) };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Removes the entry with the smallest key.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_first(&self) -> Option<Ref<K, V, F>>
+    where
+        K: Send,
+        V: Send,
+    {
+        self.with_mutex(|_| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<K, V, F>`.
+            let node = unsafe { self.raw.remove_first() };
+
+            NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes in *O*(*log n*) expected time.
+    pub fn get<'me, 'guard, G>(&'me self, key: &K, guard: &'guard G) -> Option<&'guard V>
+    where
+        'me: 'guard,
+        K: Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The node pointer can be converted to a reference.
+        unsafe { self.raw.get(key).as_ref() }.map(|node| &node.value)
+    }
+
+    /// Returns `true` if the list contains a value for the specified key.
+    pub fn contains<G>(&self, key: &K, guard: &G) -> bool
+    where
+        K: Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.get(key, guard).is_some()
+    }
+
+    /// Returns the entry with the smallest key, or `None` if the list is empty.
+    pub fn first<'me, 'guard, G>(&'me self, guard: &'guard G) -> Option<(&'guard K, &'guard V)>
+    where
+        'me: 'guard,
+        K: Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter(guard).next()
+    }
+
+    /// Returns `true` if the list is empty.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes linearly in *O*(*1*) time.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+
+    /// Returns an iterator over the list.
+    ///
+    /// The iterator yields all entries in ascending order of keys.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, K, V, G>
+    where
+        'me: 'guard,
+        K: Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.range(.., guard)
+    }
+
+    /// Returns an iterator over a range of the list.
+    ///
+    /// The iterator yields the entries within the range in ascending order of keys.
+    pub fn range<'me, 'guard, R, G>(
+        &'me self,
+        range: R,
+        guard: &'guard G,
+    ) -> Iter<'guard, K, V, G, R>
+    where
+        'me: 'guard,
+        K: Ord,
+        R: RangeBounds<K>,
+        G: RcuGuard<Flavor = F>,
+    {
+        // SAFETY: The RCU critical section is enforced.
+        let raw = unsafe { RawIter::from_bound(&self.raw, range.start_bound()) };
+
+        Iter::new(raw, range, guard)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuSkipList`] can be used to send `K` and `V` to another thread.
+unsafe impl<K, V, F> Send for RcuSkipList<K, V, F>
+where
+    K: Send,
+    V: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuSkipList`] can be used to share `K` and `V` between threads.
+unsafe impl<K, V, F> Sync for RcuSkipList<K, V, F>
+where
+    K: Sync,
+    V: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<K, V, F> Drop for RcuSkipList<K, V, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The RCU grace period is not needed because there are no other readers.
+        while let Some(mut ptr) = NonNull::new(unsafe { self.raw.remove_first() }) {
+            drop(unsafe { Box::from_raw(ptr.as_mut()) });
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::ops::{RangeBounds, RangeFull};
+
+use crate::collections::skiplist::raw::RawIter;
+use crate::rcu::guard::RcuGuard;
+
+/// An iterator over the entries of an [`RcuSkipList`] within a range.
+///
+/// [`RcuSkipList`]: crate::collections::skiplist::container::RcuSkipList
+pub struct Iter<'guard, K, V, G, R = RangeFull>
+where
+    G: RcuGuard,
+{
+    raw: RawIter<K, V>,
+    range: R,
+    #[allow(dead_code)]
+    guard: &'guard G,
+}
+
+impl<'guard, K, V, G, R> Iter<'guard, K, V, G, R>
+where
+    G: RcuGuard,
+{
+    pub(crate) fn new(raw: RawIter<K, V>, range: R, guard: &'guard G) -> Self {
+        Self { raw, range, guard }
+    }
+}
+
+impl<'guard, K, V, G, R> Iterator for Iter<'guard, K, V, G, R>
+where
+    Self: 'guard,
+    K: Ord,
+    G: RcuGuard,
+    R: RangeBounds<K>,
+{
+    type Item = (&'guard K, &'guard V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: The RCU critical section is enforced.
+        unsafe { self.raw.next().as_ref() }
+            .filter(|node| self.range.contains(&node.key))
+            .map(|node| (&node.key, &node.value))
+    }
+}
@@ -0,0 +1,72 @@
+pub(crate) mod container;
+pub(crate) mod iterator;
+pub(crate) mod raw;
+pub(crate) mod reference;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::skiplist::iterator::*;
+pub use crate::collections::skiplist::reference::*;
+
+mod asserts {
+    use super::*;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::skiplist::container::RcuSkipList;
+    use crate::rcu::default::{RcuDefaultFlavor, RcuDefaultGuard};
+    use crate::utility::asserts::*;
+
+    mod rcu_skip_list {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuSkipList<NotSendNotSync, NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuSkipList<NotSendNotSync, NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuSkipList<SendButNotSync, SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuSkipList<SendButNotSync, SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuSkipList<NotSendButSync, NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuSkipList<NotSendButSync, NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuSkipList<SendAndSync, SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuSkipList<SendAndSync, SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_skip_list_ref {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(Ref<SendButNotSync, SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Ref<SendButNotSync, SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(Ref<SendAndSync, SendAndSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(Ref<SendAndSync, SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_skip_list_ref_owned {
+        use super::*;
+
+        // T: Send + !Sync
+        assert_impl_all!(RefOwned<SendButNotSync, SendButNotSync>: Send);
+        assert_not_impl_all!(RefOwned<SendButNotSync, SendButNotSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RefOwned<SendAndSync, SendAndSync>: Send);
+        assert_impl_all!(RefOwned<SendAndSync, SendAndSync>: Sync);
+    }
+
+    mod rcu_skip_list_iter {
+        use super::*;
+
+        // T: Send + Sync
+        assert_not_impl_all!(Iter<'_, SendAndSync, SendAndSync, RcuDefaultGuard>: Send);
+        assert_not_impl_all!(Iter<'_, SendAndSync, SendAndSync, RcuDefaultGuard>: Sync);
+    }
+}
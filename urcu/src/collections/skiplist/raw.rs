@@ -0,0 +1,272 @@
+use std::marker::PhantomData;
+use std::ops::{Bound, Deref};
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::utility::*;
+
+/// The maximum height of a node, enough for 2^32 elements.
+pub const MAX_HEIGHT: usize = 32;
+
+pub struct RawNode<K, V> {
+    pub key: K,
+    pub value: V,
+    next: Box<[AtomicPtr<RawNode<K, V>>]>,
+}
+
+impl<K, V> RawNode<K, V> {
+    pub fn new(key: K, value: V, height: usize) -> Box<Self> {
+        Box::new(Self {
+            key,
+            value,
+            next: (0..height).map(|_| AtomicPtr::default()).collect(),
+        })
+    }
+
+    pub fn into_inner(self) -> (K, V) {
+        (self.key, self.value)
+    }
+
+    fn height(&self) -> usize {
+        self.next.len()
+    }
+}
+
+impl<K, V> Deref for RawNode<K, V> {
+    type Target = V;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// #### Safety
+///
+/// It is safe to send a [`RawNode<K, V>`] to another thread if `K` and `V` are [`Send`].
+unsafe impl<K: Send, V: Send> Send for RawNode<K, V> {}
+
+/// #### Safety
+///
+/// It is safe to share a [`RawNode<K, V>`] between threads if `K` and `V` are [`Sync`].
+unsafe impl<K: Sync, V: Sync> Sync for RawNode<K, V> {}
+
+/// Defines the predecessors of a key at every level, `null` being the head.
+type Predecessors<K, V> = [*mut RawNode<K, V>; MAX_HEIGHT];
+
+pub struct RawSkipList<K, V> {
+    head: [AtomicPtr<RawNode<K, V>>; MAX_HEIGHT],
+    _unsend: PhantomUnsend<(K, V)>,
+    _unsync: PhantomUnsync<(K, V)>,
+}
+
+impl<K, V> RawSkipList<K, V> {
+    pub fn new() -> Self {
+        Self {
+            head: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// Returns the link of a node at a level, or the link of the head if `null`.
+    ///
+    /// #### Safety
+    ///
+    /// The node must be alive and at least `level + 1` high.
+    unsafe fn link(&self, node: *const RawNode<K, V>, level: usize) -> &AtomicPtr<RawNode<K, V>> {
+        match node.as_ref() {
+            None => &self.head[level],
+            Some(node) => &node.next[level],
+        }
+    }
+
+    /// Returns the first node not ordered before the bound.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section or have mutual exclusion from writers.
+    unsafe fn search(
+        &self,
+        bound: Bound<&K>,
+        mut predecessors: Option<&mut Predecessors<K, V>>,
+    ) -> *mut RawNode<K, V>
+    where
+        K: Ord,
+    {
+        let before = |key: &K| match bound {
+            Bound::Included(bound) => key < bound,
+            Bound::Excluded(bound) => key <= bound,
+            Bound::Unbounded => false,
+        };
+
+        let mut predecessor = std::ptr::null_mut();
+
+        for level in (0..MAX_HEIGHT).rev() {
+            loop {
+                let next = self.link(predecessor, level).load(Ordering::Acquire);
+
+                match next.as_ref() {
+                    Some(node) if before(&node.key) => predecessor = next,
+                    _ => break,
+                }
+            }
+
+            if let Some(predecessors) = predecessors.as_mut() {
+                predecessors[level] = predecessor;
+            }
+        }
+
+        self.link(predecessor, 0).load(Ordering::Acquire)
+    }
+
+    /// Inserts a node, returning the node with the same key.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the returned node.
+    pub unsafe fn insert(&self, node: Box<RawNode<K, V>>) -> *mut RawNode<K, V>
+    where
+        K: Ord,
+    {
+        let mut predecessors = [std::ptr::null_mut(); MAX_HEIGHT];
+        let current = self.search(Bound::Included(&node.key), Some(&mut predecessors));
+        let current = current
+            .as_ref()
+            .filter(|current| current.key == node.key)
+            .map_or(std::ptr::null_mut(), |_| current);
+
+        let height = node.height();
+        for (level, next) in node.next.iter().enumerate() {
+            next.store(
+                self.link(predecessors[level], level)
+                    .load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+        }
+
+        // The node is published from the bottom, it is fully initialized before.
+        let node = Box::into_raw(node);
+        for (level, predecessor) in predecessors.iter().enumerate().take(height) {
+            self.link(*predecessor, level)
+                .store(node, Ordering::Release);
+        }
+
+        // The replaced node is now after the new node at its lowest levels.
+        if let Some(current) = current.as_ref() {
+            for level in (0..current.height()).rev() {
+                let predecessor = if level < height {
+                    node
+                } else {
+                    predecessors[level]
+                };
+                let next = current.next[level].load(Ordering::Relaxed);
+                self.link(predecessor, level).store(next, Ordering::Release);
+            }
+        }
+
+        current
+    }
+
+    /// Removes the node with the specified key.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove(&self, key: &K) -> *mut RawNode<K, V>
+    where
+        K: Ord,
+    {
+        let mut predecessors = [std::ptr::null_mut(); MAX_HEIGHT];
+        let node = self.search(Bound::Included(key), Some(&mut predecessors));
+
+        match node.as_ref() {
+            Some(current) if current.key == *key => {
+                self.unlink(current, &predecessors);
+                node
+            }
+            _ => std::ptr::null_mut(),
+        }
+    }
+
+    /// Removes the first node.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn remove_first(&self) -> *mut RawNode<K, V> {
+        let node = self.head[0].load(Ordering::Relaxed);
+
+        if let Some(current) = node.as_ref() {
+            self.unlink(current, &[std::ptr::null_mut(); MAX_HEIGHT]);
+        }
+
+        node
+    }
+
+    unsafe fn unlink(&self, node: &RawNode<K, V>, predecessors: &Predecessors<K, V>) {
+        for level in (0..node.height()).rev() {
+            let next = node.next[level].load(Ordering::Relaxed);
+            self.link(predecessors[level], level)
+                .store(next, Ordering::Release);
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get(&self, key: &K) -> *const RawNode<K, V>
+    where
+        K: Ord,
+    {
+        let node = self.search(Bound::Included(key), None);
+
+        match node.as_ref() {
+            Some(current) if current.key == *key => node,
+            _ => std::ptr::null(),
+        }
+    }
+
+    pub fn empty(&self) -> bool {
+        self.head[0].load(Ordering::Relaxed).is_null()
+    }
+}
+
+pub struct RawIter<K, V> {
+    current: *const RawNode<K, V>,
+    _unsend: PhantomUnsend<(K, V)>,
+    _unsync: PhantomUnsync<(K, V)>,
+}
+
+impl<K, V> RawIter<K, V> {
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn from_bound(list: &RawSkipList<K, V>, bound: Bound<&K>) -> Self
+    where
+        K: Ord,
+    {
+        Self {
+            current: list.search(bound, None),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn next(&mut self) -> *const RawNode<K, V> {
+        match self.current.as_ref() {
+            None => std::ptr::null(),
+            Some(node) => {
+                self.current = node.next[0].load(Ordering::Acquire);
+                node
+            }
+        }
+    }
+}
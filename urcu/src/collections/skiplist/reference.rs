@@ -0,0 +1,41 @@
+use crate::collections::skiplist::raw::RawNode;
+use crate::rcu::reference;
+
+/// An owned RCU reference to a element removed from an [`RcuSkipList`].
+///
+/// [`RcuSkipList`]: crate::collections::skiplist::container::RcuSkipList
+pub type RefOwned<K, V> = reference::BoxRefOwned<RawNode<K, V>>;
+
+impl<K, V> RefOwned<K, V> {
+    /// Returns the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.as_inner().key
+    }
+
+    /// Returns the value of the entry.
+    pub fn value(&self) -> &V {
+        &self.as_inner().value
+    }
+
+    /// Moves the key-value pair out of the reference.
+    ///
+    /// #### Note
+    ///
+    /// The value must implement [`Unpin`] since it may have been pinned in the list.
+    pub fn into_inner(self) -> (K, V)
+    where
+        V: Unpin,
+    {
+        self.into_box().into_inner()
+    }
+}
+
+/// An RCU reference to a element removed from an [`RcuSkipList`].
+///
+/// #### Requirements
+///
+/// `K` and `V` must be [`Send`] because [`Drop::drop`] might execute cleanup in another
+/// thread.
+///
+/// [`RcuSkipList`]: crate::collections::skiplist::container::RcuSkipList
+pub type Ref<K, V, F> = reference::RcuRefBox<RawNode<K, V>, F>;
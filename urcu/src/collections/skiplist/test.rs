@@ -0,0 +1,89 @@
+use crate::collections::skiplist::container::RcuSkipList;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn sorted() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuSkipList::<u32, u32>::new();
+    assert!(list.is_empty());
+
+    for key in [50, 10, 40, 20, 30] {
+        assert!(list.insert(key, key * 10).is_none());
+    }
+
+    let guard = context.rcu_read_lock();
+    assert!(!list.is_empty());
+    assert_eq!(list.get(&40, &guard), Some(&400));
+    assert_eq!(list.get(&45, &guard), None);
+    assert_eq!(list.first(&guard), Some((&10, &100)));
+
+    let keys = list.iter(&guard).map(|(key, _)| *key).collect::<Vec<_>>();
+    assert_eq!(keys, vec![10, 20, 30, 40, 50]);
+
+    let keys = list
+        .range(20..40, &guard)
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec![20, 30]);
+
+    let keys = list
+        .range(25..=40, &guard)
+        .map(|(key, _)| *key)
+        .collect::<Vec<_>>();
+    assert_eq!(keys, vec![30, 40]);
+
+    let replaced = list.insert(30, 301).unwrap();
+    assert_eq!(list.get(&30, &guard), Some(&301));
+    assert_eq!(list.iter(&guard).count(), 5);
+
+    let removed = list.remove(&20).unwrap();
+    assert!(list.remove(&20).is_none());
+    assert!(!list.contains(&20, &guard));
+
+    let popped = list.pop_first().unwrap();
+    let keys = list.iter(&guard).map(|(key, _)| *key).collect::<Vec<_>>();
+    assert_eq!(keys, vec![30, 40, 50]);
+    drop(guard);
+
+    assert_eq!(
+        replaced.take_ownership(&mut context).into_inner(),
+        (30, 300)
+    );
+    assert_eq!(removed.take_ownership(&mut context).into_inner(), (20, 200));
+
+    let popped = popped.take_ownership(&mut context);
+    assert_eq!((*popped.key(), *popped.value()), (10, 100));
+}
+
+#[test]
+fn many() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let list = RcuSkipList::<u32, ()>::new();
+
+    for key in (0..1000).map(|key| (key * 7919) % 1000) {
+        list.insert(key, ());
+    }
+
+    let removed = (0..1000)
+        .step_by(2)
+        .map(|key| list.remove(&key).unwrap())
+        .collect::<Vec<_>>();
+
+    let guard = context.rcu_read_lock();
+    let keys = list.iter(&guard).map(|(key, _)| *key).collect::<Vec<_>>();
+    assert_eq!(keys, (1..1000).step_by(2).collect::<Vec<_>>());
+    drop(guard);
+
+    assert_eq!(removed.take_ownership(&mut context).len(), 500);
+}
@@ -0,0 +1,224 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::boxed::reference::Ref;
+use crate::collections::slab::iterator::Iter;
+use crate::collections::slab::raw::RawSlab;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::*;
+
+/// Defines the state of the writers of an [`RcuSlab`].
+#[derive(Default)]
+struct SlabState {
+    /// The keys of the removed elements, reused by the next insertions.
+    vacant: Vec<usize>,
+    len: usize,
+}
+
+/// Defines a RCU object pool addressed by integer keys.
+///
+/// Inserting an element returns a small integer key, which readers can resolve without
+/// locking. The slots are allocated in chunks that are never moved, so the pool grows
+/// without copying its elements. The pool uses an internal lock for writing operations.
+///
+/// # Limitations
+///
+/// ##### Key Reuse
+///
+/// The key of a removed element is reused by a later insertion. A reader resolving a
+/// stale key may observe the new element.
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing an element, it is impossible to get a
+/// mutable references to the elements inside the pool. You should design the type stored
+/// in the pool with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuSlab<T>>` to a non-registered RCU thread. A non-registered
+/// thread may drop an `RcuSlab<T>` without calling any RCU primitives since lifetime rules
+/// prevent any other thread from accessing a RCU reference.
+pub struct RcuSlab<T, F = RcuDefaultFlavor> {
+    raw: RawSlab<T>,
+    /// The keys above this bound have never been used.
+    end: AtomicUsize,
+    mutex: Mutex<SlabState>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuSlab<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU object pool.
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            raw: RawSlab::new(),
+            end: AtomicUsize::new(0),
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuSlab"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce(&mut SlabState) -> R,
+    {
+        // The state is updated without calling user code, so it is still consistent if
+        // a writer panics while holding the lock.
+        let mut guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback(&mut guard);
+        drop(guard);
+        result
+    }
+
+    /// Inserts an element in the pool, returning its key.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn insert(&self, data: T) -> usize
+    where
+        T: Send,
+    {
+        self.with_mutex(|state| {
+            let key = state.vacant.pop().unwrap_or_else(|| {
+                let key = self.end.load(Ordering::Relaxed);
+                self.end.store(key + 1, Ordering::Release);
+                key
+            });
+
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The key is either vacant or never used.
+            unsafe { self.raw.insert(key, Box::new(data)) };
+
+            state.len += 1;
+            key
+        })
+    }
+
+    /// Removes the element with the specified key.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn remove(&self, key: usize) -> Option<Ref<T, F>>
+    where
+        T: Send,
+    {
+        self.with_mutex(|state| {
+            // SAFETY: There is mutual exclusion between writers.
+            // SAFETY: The RCU grace period is enforced using `Ref<T, F>`.
+            let ptr = unsafe { self.raw.remove(key) };
+
+            if ptr.is_null() {
+                return None;
+            }
+
+            state.vacant.push(key);
+            state.len -= 1;
+
+            Some(Ref::new(ptr).tracked(&self.refs))
+        })
+    }
+
+    /// Returns a reference to the element with the specified key.
+    ///
+    /// #### Note
+    ///
+    /// * This operation computes in *O*(*1*) time.
+    pub fn get<'me, 'guard, G>(&'me self, key: usize, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The pointer can be converted to a reference.
+        unsafe { self.raw.get(key).as_ref() }
+    }
+
+    /// Returns `true` if the pool contains an element with the specified key.
+    pub fn contains<G>(&self, key: usize, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.get(key, guard).is_some()
+    }
+
+    /// Returns the amount of elements in the pool.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn len(&self) -> usize {
+        self.with_mutex(|state| state.len)
+    }
+
+    /// Returns `true` if the pool is empty.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the pool.
+    ///
+    /// The iterator yields all the keys and their element in ascending order of keys.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> Iter<'guard, T, G>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        Iter::new(&self.raw, self.end.load(Ordering::Acquire), guard)
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuSlab`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuSlab<T, F>
+where
+    T: Send,
+    F: RcuFlavor,
+{
+}
+
+/// #### Safety
+///
+/// An [`RcuSlab`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuSlab<T, F>
+where
+    T: Sync,
+    F: RcuFlavor,
+{
+}
+
+impl<T, F> Drop for RcuSlab<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        for key in 0..*self.end.get_mut() {
+            // SAFETY: The RCU grace period is not needed because there are no other readers.
+            let ptr = unsafe { self.raw.remove(key) };
+
+            if !ptr.is_null() {
+                drop(unsafe { Box::from_raw(ptr) });
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::collections::slab::raw::RawSlab;
+use crate::rcu::guard::RcuGuard;
+
+/// An iterator over the entries of an [`RcuSlab`].
+///
+/// [`RcuSlab`]: crate::collections::slab::container::RcuSlab
+pub struct Iter<'guard, T, G>
+where
+    G: RcuGuard,
+{
+    raw: &'guard RawSlab<T>,
+    key: usize,
+    end: usize,
+    #[allow(dead_code)]
+    guard: &'guard G,
+}
+
+impl<'guard, T, G> Iter<'guard, T, G>
+where
+    G: RcuGuard,
+{
+    pub(crate) fn new(raw: &'guard RawSlab<T>, end: usize, guard: &'guard G) -> Self {
+        Self {
+            raw,
+            key: 0,
+            end,
+            guard,
+        }
+    }
+}
+
+impl<'guard, T, G> Iterator for Iter<'guard, T, G>
+where
+    Self: 'guard,
+    G: RcuGuard,
+{
+    type Item = (usize, &'guard T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.key < self.end {
+            let key = self.key;
+            self.key += 1;
+
+            // SAFETY: The RCU critical section is enforced.
+            if let Some(value) = unsafe { self.raw.get(key).as_ref() } {
+                return Some((key, value));
+            }
+        }
+
+        None
+    }
+}
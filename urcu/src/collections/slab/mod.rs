@@ -0,0 +1,46 @@
+pub(crate) mod container;
+pub(crate) mod iterator;
+pub(crate) mod raw;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::slab::iterator::*;
+
+mod asserts {
+    use super::*;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::slab::container::RcuSlab;
+    use crate::rcu::default::{RcuDefaultFlavor, RcuDefaultGuard};
+    use crate::utility::asserts::*;
+
+    mod rcu_slab {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuSlab<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuSlab<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuSlab<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuSlab<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuSlab<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuSlab<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuSlab<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuSlab<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+
+    mod rcu_slab_iter {
+        use super::*;
+
+        // T: Send + Sync
+        assert_not_impl_all!(Iter<'_, SendAndSync, RcuDefaultGuard>: Send);
+        assert_not_impl_all!(Iter<'_, SendAndSync, RcuDefaultGuard>: Sync);
+    }
+}
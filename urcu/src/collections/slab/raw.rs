@@ -0,0 +1,114 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::utility::*;
+
+/// The amount of slots in the first chunk, as a power of two.
+const FIRST_CHUNK_SHIFT: u32 = 5;
+
+/// The amount of chunks needed to address every key.
+const CHUNKS: usize = (usize::BITS - FIRST_CHUNK_SHIFT) as usize;
+
+type Slot<T> = AtomicPtr<T>;
+
+/// Defines an array of slots growing by chunks.
+///
+/// Each chunk is twice as large as the previous one and is never moved once allocated,
+/// so readers can access the slots without synchronizing with the writers.
+pub struct RawSlab<T> {
+    chunks: [AtomicPtr<Slot<T>>; CHUNKS],
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawSlab<T> {
+    pub fn new() -> Self {
+        Self {
+            chunks: std::array::from_fn(|_| AtomicPtr::default()),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    fn chunk_len(chunk: usize) -> usize {
+        1 << (chunk as u32 + FIRST_CHUNK_SHIFT)
+    }
+
+    /// Returns the chunk and the offset of a key.
+    fn locate(key: usize) -> (usize, usize) {
+        let biased = key + (1 << FIRST_CHUNK_SHIFT);
+        let chunk = (usize::BITS - 1 - biased.leading_zeros() - FIRST_CHUNK_SHIFT) as usize;
+
+        (chunk, biased - Self::chunk_len(chunk))
+    }
+
+    fn slot(&self, key: usize) -> Option<&Slot<T>> {
+        if key > usize::MAX - (1 << FIRST_CHUNK_SHIFT) {
+            return None;
+        }
+
+        let (chunk, offset) = Self::locate(key);
+        let slots = self.chunks[chunk].load(Ordering::Acquire);
+
+        // SAFETY: The chunk is never freed before the slab and has enough slots.
+        unsafe { slots.as_ref().map(|_| &*slots.add(offset)) }
+    }
+
+    /// Stores a value in a slot, allocating its chunk if needed.
+    ///
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The slot must be empty.
+    pub unsafe fn insert(&self, key: usize, value: Box<T>) {
+        let (chunk, _) = Self::locate(key);
+
+        if self.chunks[chunk].load(Ordering::Relaxed).is_null() {
+            let slots = (0..Self::chunk_len(chunk))
+                .map(|_| Slot::default())
+                .collect::<Box<[Slot<T>]>>();
+
+            self.chunks[chunk].store(Box::into_raw(slots).cast(), Ordering::Release);
+        }
+
+        self.slot(key)
+            .unwrap()
+            .store(Box::into_raw(value), Ordering::Release);
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must have mutual exclusion from other writers.
+    ///
+    /// The caller must wait a RCU grace period before freeing the value.
+    pub unsafe fn remove(&self, key: usize) -> *mut T {
+        self.slot(key).map_or(std::ptr::null_mut(), |slot| {
+            slot.swap(std::ptr::null_mut(), Ordering::Relaxed)
+        })
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must be in a RCU critical section.
+    pub unsafe fn get(&self, key: usize) -> *const T {
+        self.slot(key)
+            .map_or(std::ptr::null(), |slot| slot.load(Ordering::Acquire))
+    }
+}
+
+impl<T> Drop for RawSlab<T> {
+    fn drop(&mut self) {
+        for (chunk, slots) in self.chunks.iter_mut().enumerate() {
+            let slots = *slots.get_mut();
+
+            if !slots.is_null() {
+                let slots = std::ptr::slice_from_raw_parts_mut(slots, Self::chunk_len(chunk));
+
+                // SAFETY: The chunk was allocated as a boxed slice of this length.
+                // SAFETY: The caller removed all the values before dropping.
+                drop(unsafe { Box::from_raw(slots) });
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::collections::slab::container::RcuSlab;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn keys() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let slab = RcuSlab::<u32>::new();
+    assert!(slab.is_empty());
+
+    // Enough elements to allocate multiple chunks.
+    let keys = (0..100).map(|value| slab.insert(value)).collect::<Vec<_>>();
+    assert_eq!(keys, (0..100).collect::<Vec<_>>());
+    assert_eq!(slab.len(), 100);
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(slab.get(42, &guard), Some(&42));
+    assert_eq!(slab.get(100, &guard), None);
+    assert_eq!(slab.get(usize::MAX, &guard), None);
+
+    let removed = slab.remove(42).unwrap();
+    assert!(slab.remove(42).is_none());
+    assert!(!slab.contains(42, &guard));
+    assert_eq!(slab.iter(&guard).count(), 99);
+
+    // The key of the removed element is reused.
+    assert_eq!(slab.insert(4200), 42);
+    assert_eq!(slab.get(42, &guard), Some(&4200));
+    drop(guard);
+
+    assert_eq!(*removed.take_ownership(&mut context), 42);
+}
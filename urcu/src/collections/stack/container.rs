@@ -6,9 +6,12 @@ use std::sync::Arc;
 use crate::collections::stack::iterator::{Iter, IterRef};
 use crate::collections::stack::raw::{RawNode, RawStack};
 use crate::collections::stack::reference::Ref;
+use crate::rcu::context::RcuReadContext;
 use crate::rcu::default::RcuDefaultFlavor;
 use crate::rcu::flavor::RcuFlavor;
 use crate::rcu::guard::RcuGuard;
+use crate::rcu::reference::RcuRef;
+use crate::rcu::tracker::RcuRefTracker;
 use crate::utility::*;
 
 /// Defines a RCU wait-free stack.
@@ -40,6 +43,7 @@ use crate::utility::*;
 /// prevent any other thread from accessing a RCU reference.
 pub struct RcuStack<T, F = RcuDefaultFlavor> {
     raw: RawStack<T>,
+    refs: RcuRefTracker,
     _unsend: PhantomUnsend<(T, F)>,
     _unsync: PhantomUnsync<(T, F)>,
 }
@@ -53,6 +57,7 @@ where
         Arc::new(RcuStack {
             // SAFETY: All node are pop'ed before dropping.
             raw: unsafe { RawStack::new() },
+            refs: RcuRefTracker::new::<F>("RcuStack"),
             _unsend: PhantomData,
             _unsync: PhantomData,
         })
@@ -65,6 +70,41 @@ where
         self.raw.push(node);
     }
 
+    /// Moves all the elements on top of another stack.
+    ///
+    /// The elements keep their order and the nodes are moved as a single chain, without
+    /// being pushed one by one.
+    ///
+    /// #### Note
+    ///
+    /// This operation blocks for a RCU grace period if the stack is not empty. The
+    /// elements are only pushed on `dest` once the concurrent pops of this stack are
+    /// finished, otherwise a pop could succeed on a stale head if the elements come back.
+    ///
+    /// The elements are in neither stack during the grace period. A reader iterating over
+    /// this stack during the move may also visit the elements that were already in `dest`.
+    pub fn take_into<C>(&self, dest: &RcuStack<T, F>, context: &mut C)
+    where
+        C: RcuReadContext<Flavor = F>,
+    {
+        if self.raw.empty() {
+            return;
+        }
+
+        let guard = context.rcu_read_lock();
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: The nodes are not freed.
+        let nodes = unsafe { self.raw.pop_all() };
+
+        drop(guard);
+
+        context.rcu_synchronize();
+
+        // SAFETY: The RCU grace period is over, no pop can still read the nodes.
+        unsafe { dest.raw.push_all(nodes) };
+    }
+
     /// Removes an element from the top of the stack.
     pub fn pop<G>(&self, guard: &G) -> Option<Ref<T, F>>
     where
@@ -77,7 +117,44 @@ where
         // SAFETY: RCU grace period is enforced.
         let node = unsafe { self.raw.pop() };
 
-        NonNull::new(node).map(Ref::new)
+        NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
+    }
+
+    /// Removes an element from the top of the stack and takes its ownership.
+    ///
+    /// #### Note
+    ///
+    /// This operation blocks for a RCU grace period if an element is removed.
+    pub fn pop_owned<C>(&self, context: &mut C) -> Option<T>
+    where
+        T: Send + 'static,
+        F: 'static,
+        C: RcuReadContext<Flavor = F>,
+    {
+        let node = self.pop(&context.rcu_read_lock())?;
+
+        Some(node.take_ownership(context).into_inner())
+    }
+
+    /// Removes the element on top of the stack if the predicate returns `true`.
+    ///
+    /// #### Note
+    ///
+    /// If another thread modifies the top of the stack concurrently, the predicate
+    /// is evaluated again on the new top element.
+    pub fn pop_if<P, G>(&self, predicate: P, guard: &G) -> Option<Ref<T, F>>
+    where
+        T: Send,
+        P: FnMut(&T) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The RCU critical section is enforced.
+        // SAFETY: RCU grace period is enforced.
+        let node = unsafe { self.raw.pop_if(predicate) };
+
+        NonNull::new(node).map(|node| Ref::new(node).tracked(&self.refs))
     }
 
     /// Removes all elements from the stack.
@@ -88,7 +165,7 @@ where
     {
         // SAFETY: The RCU critical section is enforced.
         // SAFETY: RCU grace period is enforced.
-        IterRef::new(unsafe { self.raw.pop_all() })
+        IterRef::new(unsafe { self.raw.pop_all() }, self.refs.clone())
     }
 
     /// Returns a reference to the element on top of the stack.
@@ -104,6 +181,60 @@ where
         unsafe { node.as_ref() }.map(|node| node.deref())
     }
 
+    /// Calls a closure with a reference to the element on top of the stack.
+    ///
+    /// #### Note
+    ///
+    /// The RCU critical section is only held for the duration of the call.
+    pub fn peek_with<C, P, R>(&self, context: &C, callback: P) -> R
+    where
+        C: RcuReadContext<Flavor = F>,
+        P: FnOnce(Option<&T>) -> R,
+    {
+        callback(self.peek(&context.rcu_read_lock()))
+    }
+
+    /// Returns `true` if the stack contains an element equal to the given value.
+    pub fn contains<G>(&self, x: &T, guard: &G) -> bool
+    where
+        T: PartialEq,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter(guard).any(|item| item == x)
+    }
+
+    /// Returns a reference to the first element matching the predicate, from top to bottom.
+    pub fn find<'me, 'guard, G, P>(
+        &'me self,
+        mut predicate: P,
+        guard: &'guard G,
+    ) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        P: FnMut(&T) -> bool,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter(guard).find(|item| predicate(item))
+    }
+
+    /// Returns `true` if both stacks contain equal elements in the same order.
+    pub fn eq_with<G>(&self, other: &Self, guard: &G) -> bool
+    where
+        T: PartialEq,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter(guard).eq(other.iter(guard))
+    }
+
+    /// Compares the elements of both stacks lexicographically, from top to bottom.
+    pub fn cmp_with<G>(&self, other: &Self, guard: &G) -> std::cmp::Ordering
+    where
+        T: Ord,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.iter(guard).cmp(other.iter(guard))
+    }
+
     /// Returns an iterator over the stack.
     ///
     /// The iterator yields all items from top to bottom.
@@ -116,6 +247,22 @@ where
         Iter::new(unsafe { self.raw.iter() }, guard)
     }
 
+    /// Returns a value formatting the elements of the stack with [`Debug`].
+    ///
+    /// The elements are formatted from top to bottom.
+    ///
+    /// [`Debug`]: std::fmt::Debug
+    pub fn debug<'me, 'guard, G>(&'me self, guard: &'guard G) -> impl std::fmt::Debug + 'guard
+    where
+        'me: 'guard,
+        T: std::fmt::Debug,
+        G: RcuGuard<Flavor = F>,
+    {
+        DebugWith(move |f: &mut std::fmt::Formatter<'_>| {
+            f.debug_list().entries(self.iter(guard)).finish()
+        })
+    }
+
     /// Returns `true` if there is no node in the stack.
     pub fn is_empty(&self) -> bool {
         self.raw.empty()
@@ -144,6 +291,8 @@ where
 
 impl<T, F> Drop for RcuStack<T, F> {
     fn drop(&mut self) {
+        self.refs.report();
+
         // SAFETY: The RCU read-lock is not needed there are no other writers.
         // SAFETY: The RCU grace period is not needed there are no other readers.
         let mut iter = unsafe { self.raw.pop_all() };
@@ -1,6 +1,10 @@
 pub(crate) mod container;
 pub(crate) mod iterator;
+#[cfg(not(feature = "fuzzing"))]
 pub(crate) mod raw;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod raw;
 pub(crate) mod reference;
 
 #[cfg(test)]
@@ -1,6 +1,8 @@
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::ops::Deref;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 use container_of::container_of;
 use urcu_cds_sys::lfs;
@@ -31,6 +33,10 @@ impl<T> RawNode<T> {
         let node = unsafe { node_ptr.as_mut_unchecked() };
         &mut node.handle
     }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
 }
 
 impl<T> Deref for RawNode<T> {
@@ -99,6 +105,47 @@ impl<T> RawStack<T> {
         }
     }
 
+    /// #### Safety
+    ///
+    /// The caller must be inside a RCU critical section.
+    ///
+    /// The caller must wait a RCU grace period before freeing the node.
+    pub unsafe fn pop_if<P>(&self, mut predicate: P) -> *mut RawNode<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let head_ptr = &self.handle.head as *const *mut lfs::Head as *mut *mut lfs::Head;
+
+        // SAFETY: The head pointer is only modified atomically by the C library.
+        let head_atomic = unsafe { AtomicPtr::from_ptr(head_ptr) };
+
+        loop {
+            let head = head_atomic.load(Ordering::Acquire);
+
+            // SAFETY: The head is valid for the duration of the RCU critical section.
+            let Some(head_ref) = (unsafe { head.as_ref() }) else {
+                return std::ptr::null_mut();
+            };
+
+            let node: *mut RawNode<T> = container_of!(head, RawNode<T>, handle);
+
+            // SAFETY: The node is valid for the duration of the RCU critical section.
+            if !predicate(unsafe { &(*node).data }) {
+                return std::ptr::null_mut();
+            }
+
+            // SAFETY: A `cds_lfs_head` only wraps the first node of the stack.
+            let next = head_ref.node.next as *mut lfs::Head;
+
+            if head_atomic
+                .compare_exchange(head, next, Ordering::SeqCst, Ordering::Acquire)
+                .is_ok()
+            {
+                return node;
+            }
+        }
+    }
+
     /// #### Safety
     ///
     /// The caller must be inside a RCU critical section.
@@ -113,6 +160,49 @@ impl<T> RawStack<T> {
         )
     }
 
+    /// Pushes the nodes removed with [`RawStack::pop_all`] on top of the stack.
+    ///
+    /// The nodes keep their order and are pushed as a single chain.
+    ///
+    /// #### Safety
+    ///
+    /// No pop that read the nodes before they were removed may still be running, which
+    /// is usually enforced by waiting a RCU grace period. Otherwise, such a pop could
+    /// succeed on a stale head after the nodes are pushed back.
+    pub unsafe fn push_all(&self, nodes: RawIterRef<T>) {
+        let Some(first) = NonNull::new(nodes.node) else {
+            return;
+        };
+
+        // SAFETY: A `cds_lfs_head` only wraps the first node of the stack.
+        let head = first.as_ptr() as *mut lfs::Head;
+        let mut tail = first.as_ptr();
+
+        // SAFETY: The nodes were removed from the stack, we are the only writer.
+        while let Some(next) = unsafe { NonNull::new((*tail).next) } {
+            tail = next.as_ptr();
+        }
+
+        // SAFETY: The next pointer may be concurrently read by RCU readers.
+        let tail_next = unsafe { AtomicPtr::from_ptr(&mut (*tail).next) };
+
+        let head_ptr = &self.handle.head as *const *mut lfs::Head as *mut *mut lfs::Head;
+
+        // SAFETY: The head pointer is only modified atomically by the C library.
+        let head_atomic = unsafe { AtomicPtr::from_ptr(head_ptr) };
+
+        let mut current = head_atomic.load(Ordering::Acquire);
+        loop {
+            // SAFETY: A `cds_lfs_head` only wraps the first node of the stack.
+            tail_next.store(current as *mut lfs::Node, Ordering::Relaxed);
+
+            match head_atomic.compare_exchange(current, head, Ordering::SeqCst, Ordering::Acquire) {
+                Ok(_) => return,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
     /// #### Safety
     ///
     /// The caller must be inside a RCU critical section.
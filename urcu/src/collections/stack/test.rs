@@ -150,3 +150,197 @@ fn iter_ref() {
         vec![183, 120, 160, 105, 147, 150, 184, 174, 128, 140]
     );
 }
+
+#[test]
+fn pop_if() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<u32>::new();
+    let guard = context.rcu_read_lock();
+
+    assert!(stack.pop_if(|_| true, &guard).is_none());
+
+    stack.push(10);
+    stack.push(20);
+    stack.push(30);
+
+    assert!(stack.pop_if(|value| *value < 30, &guard).is_none());
+    assert_eq!(stack.peek(&guard), Some(&30));
+
+    stack
+        .pop_if(|value| *value == 30, &guard)
+        .unwrap()
+        .call_cleanup(&context);
+    assert_eq!(stack.peek(&guard), Some(&20));
+
+    stack
+        .pop_if(|value| *value == 20, &guard)
+        .unwrap()
+        .call_cleanup(&context);
+    assert_eq!(stack.peek(&guard), Some(&10));
+
+    assert!(stack.pop_if(|value| *value > 10, &guard).is_none());
+    assert_eq!(stack.iter(&guard).copied().collect::<Vec<_>>(), vec![10]);
+}
+
+#[test]
+fn peek_with() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<u32>::new();
+
+    assert_eq!(stack.peek_with(&context, |value| value.copied()), None);
+
+    stack.push(10);
+    stack.push(20);
+
+    assert_eq!(stack.peek_with(&context, |value| value.copied()), Some(20));
+}
+
+#[test]
+fn take_into() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let source = RcuStack::<u32>::new();
+    let dest = RcuStack::<u32>::new();
+
+    source.take_into(&dest, &mut context);
+    assert!(dest.is_empty());
+
+    source.push(10);
+    source.push(20);
+    dest.push(30);
+    dest.push(40);
+
+    source.take_into(&dest, &mut context);
+    assert!(source.is_empty());
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(
+        dest.iter(&guard).copied().collect::<Vec<_>>(),
+        vec![20, 10, 40, 30]
+    );
+    drop(guard);
+}
+
+#[test]
+fn pop_owned() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<String>::new();
+
+    assert_eq!(stack.pop_owned(&mut context), None);
+
+    stack.push("first".to_owned());
+    stack.push("second".to_owned());
+
+    assert_eq!(stack.pop_owned(&mut context).as_deref(), Some("second"));
+    assert_eq!(stack.pop_owned(&mut context).as_deref(), Some("first"));
+    assert_eq!(stack.pop_owned(&mut context), None);
+}
+
+#[test]
+fn contains() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<u32>::new();
+    let guard = context.rcu_read_lock();
+
+    assert!(!stack.contains(&10, &guard));
+    assert_eq!(stack.find(|_| true, &guard), None);
+
+    stack.push(10);
+    stack.push(15);
+    stack.push(20);
+
+    assert!(stack.contains(&10, &guard));
+    assert!(!stack.contains(&30, &guard));
+    assert_eq!(stack.find(|value| *value > 10, &guard), Some(&20));
+    assert_eq!(stack.find(|value| value % 2 == 1, &guard), Some(&15));
+    assert_eq!(stack.find(|value| *value > 20, &guard), None);
+}
+
+#[test]
+fn owned_ordering() {
+    use std::collections::BTreeSet;
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<u32>::new();
+
+    stack.push(20);
+    stack.push(10);
+    stack.push(30);
+
+    let guard = context.rcu_read_lock();
+    let refs = stack.pop_all(&guard).collect::<Vec<_>>();
+    drop(guard);
+
+    let owned = refs.take_ownership(&mut context);
+    let sorted = owned.into_iter().collect::<BTreeSet<_>>();
+
+    assert_eq!(
+        sorted
+            .into_iter()
+            .map(|value| value.into_inner())
+            .collect::<Vec<_>>(),
+        vec![10, 20, 30]
+    );
+}
+
+#[test]
+fn debug() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<u32>::new();
+    let guard = context.rcu_read_lock();
+    assert_eq!(format!("{:?}", stack.debug(&guard)), "[]");
+
+    stack.push(1577);
+    stack.push(2984);
+    assert_eq!(format!("{:?}", stack.debug(&guard)), "[2984, 1577]");
+}
+
+#[test]
+fn compare() {
+    use std::cmp::Ordering;
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let first = RcuStack::<u32>::new();
+    let second = RcuStack::<u32>::new();
+    let guard = context.rcu_read_lock();
+
+    first.push(10);
+    second.push(10);
+    assert!(first.eq_with(&second, &guard));
+    assert_eq!(first.cmp_with(&second, &guard), Ordering::Equal);
+
+    first.push(20);
+    assert!(!first.eq_with(&second, &guard));
+    assert_eq!(first.cmp_with(&second, &guard), Ordering::Greater);
+}
@@ -0,0 +1,181 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use crate::collections::boxed::reference::Ref;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuard;
+use crate::rcu::tracker::RcuRefTracker;
+use crate::utility::{PhantomUnsend, PhantomUnsync};
+
+/// Defines a RCU vector published as immutable snapshots.
+///
+/// Readers borrow the current snapshot as a slice for the duration of their critical
+/// section. Writers build a new snapshot, usually from a copy of the current one, and
+/// publish it atomically. The previous snapshot is returned as a RCU reference.
+///
+/// # Limitations
+///
+/// ##### Copy-on-Write
+///
+/// Every update allocates a whole new snapshot. This vector is suited for small or
+/// rarely updated data that is read often.
+///
+/// ##### Mutable References
+///
+/// Because there might always be readers borrowing a snapshot, it is impossible to get a
+/// mutable references to the elements of the vector. You should design the type stored
+/// in the vector with [interior mutabillity] that can be shared between threads.
+///
+/// [interior mutabillity]: https://doc.rust-lang.org/reference/interior-mutability.html
+///
+/// # Safety
+///
+/// It is safe to send an `Arc<RcuVec<T>>` to a non-registered RCU thread. A non-registered
+/// thread may drop an `RcuVec<T>` without calling any RCU primitives since lifetime rules
+/// prevent any other thread from accessing a RCU reference.
+pub struct RcuVec<T, F = RcuDefaultFlavor> {
+    ptr: AtomicPtr<Vec<T>>,
+    mutex: Mutex<()>,
+    refs: RcuRefTracker,
+    _unsend: PhantomUnsend<F>,
+    _unsync: PhantomUnsync<F>,
+}
+
+impl<T, F> RcuVec<T, F>
+where
+    F: RcuFlavor,
+{
+    /// Creates a new RCU vector.
+    pub fn new(data: Vec<T>) -> Arc<Self> {
+        Arc::new(Self {
+            ptr: AtomicPtr::new(Box::into_raw(Box::new(data))),
+            mutex: Default::default(),
+            refs: RcuRefTracker::new::<F>("RcuVec"),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        })
+    }
+
+    /// Returns the current snapshot as a slice.
+    pub fn as_slice<'me, 'guard, G>(&'me self, guard: &'guard G) -> &'guard [T]
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        let _ = guard;
+
+        // SAFETY: The underlying pointer is never null.
+        // SAFETY: The snapshot is reclaimed after a RCU grace period.
+        unsafe { self.ptr.load(Ordering::Acquire).as_ref_unchecked() }
+    }
+
+    /// Returns a reference to an element of the current snapshot.
+    pub fn get<'me, 'guard, G>(&'me self, index: usize, guard: &'guard G) -> Option<&'guard T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.as_slice(guard).get(index)
+    }
+
+    /// Returns the length of the current snapshot.
+    pub fn len<G>(&self, guard: &G) -> usize
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.as_slice(guard).len()
+    }
+
+    /// Returns `true` if the current snapshot is empty.
+    pub fn is_empty<G>(&self, guard: &G) -> bool
+    where
+        G: RcuGuard<Flavor = F>,
+    {
+        self.as_slice(guard).is_empty()
+    }
+
+    /// Returns an iterator over the current snapshot.
+    pub fn iter<'me, 'guard, G>(&'me self, guard: &'guard G) -> std::slice::Iter<'guard, T>
+    where
+        'me: 'guard,
+        G: RcuGuard<Flavor = F>,
+    {
+        self.as_slice(guard).iter()
+    }
+
+    /// Publishes a new snapshot built from the current one.
+    ///
+    /// Updates are serialized, so `update` always receives the latest snapshot.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn update<U>(&self, update: U) -> Ref<Vec<T>, F>
+    where
+        T: Send,
+        U: FnOnce(&[T]) -> Vec<T>,
+    {
+        self.with_mutex(|| {
+            // SAFETY: The underlying pointer is never null.
+            // SAFETY: The snapshot cannot be replaced while the lock is held.
+            let current = unsafe { self.ptr.load(Ordering::Acquire).as_ref_unchecked() };
+
+            self.publish(update(current))
+        })
+    }
+
+    /// Publishes a new snapshot.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn replace(&self, data: Vec<T>) -> Ref<Vec<T>, F>
+    where
+        T: Send,
+    {
+        self.with_mutex(|| self.publish(data))
+    }
+
+    fn publish(&self, data: Vec<T>) -> Ref<Vec<T>, F>
+    where
+        T: Send,
+    {
+        let new_ptr = Box::into_raw(Box::new(data));
+        let old_ptr = self.ptr.swap(new_ptr, Ordering::AcqRel);
+
+        Ref::new(old_ptr).tracked(&self.refs)
+    }
+
+    fn with_mutex<C, R>(&self, callback: C) -> R
+    where
+        C: FnOnce() -> R,
+    {
+        // A snapshot is published after the update returns, so the current snapshot is
+        // untouched if a writer panics while holding the lock.
+        let guard = self.mutex.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = callback();
+        drop(guard);
+        result
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuVec`] can be used to send `T` to another thread.
+unsafe impl<T, F> Send for RcuVec<T, F> where T: Send {}
+
+/// #### Safety
+///
+/// An [`RcuVec`] can be used to share `T` between threads.
+unsafe impl<T, F> Sync for RcuVec<T, F> where T: Sync {}
+
+impl<T, F> Drop for RcuVec<T, F> {
+    fn drop(&mut self) {
+        self.refs.report();
+
+        // SAFETY: The underlying pointer is never null.
+        drop(unsafe { Box::from_raw(self.ptr.load(Ordering::Relaxed)) });
+    }
+}
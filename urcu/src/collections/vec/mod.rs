@@ -0,0 +1,32 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::vec::container::RcuVec;
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::utility::asserts::*;
+
+    mod rcu_vec {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuVec<NotSendNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuVec<NotSendNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuVec<SendButNotSync, RcuDefaultFlavor>: Send);
+        assert_not_impl_all!(RcuVec<SendButNotSync, RcuDefaultFlavor>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuVec<NotSendButSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuVec<NotSendButSync, RcuDefaultFlavor>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuVec<SendAndSync, RcuDefaultFlavor>: Send);
+        assert_impl_all!(RcuVec<SendAndSync, RcuDefaultFlavor>: Sync);
+    }
+}
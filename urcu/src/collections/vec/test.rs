@@ -0,0 +1,33 @@
+use crate::collections::vec::container::RcuVec;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::reference::RcuRef;
+
+#[test]
+fn simple() {
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let vec = RcuVec::<u32>::new(vec![10, 20]);
+    let guard = context.rcu_read_lock();
+
+    let snapshot = vec.as_slice(&guard);
+    assert_eq!(vec.len(&guard), 2);
+    assert_eq!(vec.get(1, &guard), Some(&20));
+    assert_eq!(vec.get(2, &guard), None);
+
+    let old = vec.update(|data| data.iter().copied().chain([30]).collect());
+
+    assert_eq!(snapshot, &[10, 20]);
+    assert_eq!(vec.iter(&guard).collect::<Vec<_>>(), vec![&10, &20, &30]);
+
+    let older = vec.replace(Vec::new());
+    assert!(vec.is_empty(&guard));
+    drop(guard);
+
+    assert_eq!(*old.take_ownership(&mut context), vec![10, 20]);
+    assert_eq!(*older.take_ownership(&mut context), vec![10, 20, 30]);
+}
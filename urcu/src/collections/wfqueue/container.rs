@@ -0,0 +1,144 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::collections::wfqueue::raw::{RawNode, RawQueue};
+use crate::utility::*;
+
+/// Defines the outcome of [`WfQueue::try_dequeue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dequeue<T> {
+    /// The front element was removed.
+    Item(T),
+    /// The queue has no element.
+    Empty,
+    /// A concurrent producer has not finished linking the front element.
+    Busy,
+}
+
+impl<T> Dequeue<T> {
+    pub(crate) fn map<U, C>(self, callback: C) -> Dequeue<U>
+    where
+        C: FnOnce(T) -> U,
+    {
+        match self {
+            Self::Item(data) => Dequeue::Item(callback(data)),
+            Self::Empty => Dequeue::Empty,
+            Self::Busy => Dequeue::Busy,
+        }
+    }
+}
+
+/// Defines a wait-free queue without RCU requirements.
+///
+/// This queue supports multiple concurrent producers and consumers. Unlike [`RcuQueue`],
+/// the elements are removed under an internal lock, so they are owned as soon as they are
+/// dequeued and no RCU grace period is needed. It can be used from threads that are not
+/// registered with RCU.
+///
+/// # Limitations
+///
+/// ##### Blocking
+///
+/// Adding an element never blocks. Removing an element takes an internal lock and may
+/// busy-wait for a concurrent producer to finish linking its element. Use
+/// [`WfQueue::try_dequeue`] to avoid the busy-wait.
+///
+/// ##### References
+///
+/// This queue does not offer a way to peek or iterate over its elements.
+///
+/// [`RcuQueue`]: crate::collections::queue::container::RcuQueue
+pub struct WfQueue<T> {
+    raw: RawQueue<T>,
+    _unsend: PhantomUnsend,
+    _unsync: PhantomUnsync,
+}
+
+impl<T> WfQueue<T> {
+    /// Creates a new wait-free queue.
+    pub fn new() -> Arc<Self> {
+        let mut queue = Arc::new(WfQueue {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawQueue::new() },
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw queue is in a stable memory location.
+        unsafe { Arc::<Self>::get_mut(&mut queue).unwrap().raw.init() };
+
+        queue
+    }
+
+    /// Adds an element to the back of queue.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn enqueue(&self, data: T)
+    where
+        T: Send,
+    {
+        self.raw.enqueue(RawNode::new(data));
+    }
+
+    /// Removes an element from the front of the queue, if any.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn dequeue_blocking(&self) -> Option<T>
+    where
+        T: Send,
+    {
+        self.raw.dequeue_blocking().map(|node| node.into_data())
+    }
+
+    /// Removes an element from the front of the queue without waiting for producers.
+    ///
+    /// Returns [`Dequeue::Busy`] if a concurrent producer has not finished linking the
+    /// front element, in which case the call can be retried.
+    ///
+    /// #### Note
+    ///
+    /// This operation only blocks on the internal lock.
+    pub fn try_dequeue(&self) -> Dequeue<T>
+    where
+        T: Send,
+    {
+        self.raw.dequeue_nonblocking().map(|node| node.into_data())
+    }
+
+    /// Moves all the elements of `other` to the back of this queue.
+    ///
+    /// Returns `false` if `other` was empty or if both queues are the same.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block on the internal lock of `other`.
+    pub fn splice(&self, other: &Self) -> bool
+    where
+        T: Send,
+    {
+        if std::ptr::eq(self, other) {
+            return false;
+        }
+
+        self.raw.splice_blocking(&other.raw)
+    }
+
+    /// Returns `true` if there is no element in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+}
+
+/// #### Safety
+///
+/// An [`WfQueue`] can be used to send `T` to another thread.
+unsafe impl<T> Send for WfQueue<T> where T: Send {}
+
+/// #### Safety
+///
+/// An [`WfQueue`] can be used to share `T` between threads through removals.
+unsafe impl<T> Sync for WfQueue<T> where T: Send {}
@@ -0,0 +1,34 @@
+pub(crate) mod container;
+pub(crate) mod raw;
+
+#[cfg(test)]
+mod test;
+
+pub use crate::collections::wfqueue::container::Dequeue;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::wfqueue::container::WfQueue;
+    use crate::utility::asserts::*;
+
+    mod wf_queue {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(WfQueue<NotSendNotSync>: Send);
+        assert_not_impl_all!(WfQueue<NotSendNotSync>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(WfQueue<SendButNotSync>: Send);
+        assert_impl_all!(WfQueue<SendButNotSync>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(WfQueue<NotSendButSync>: Send);
+        assert_not_impl_all!(WfQueue<NotSendButSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(WfQueue<SendAndSync>: Send);
+        assert_impl_all!(WfQueue<SendAndSync>: Sync);
+    }
+}
@@ -0,0 +1,164 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use container_of::container_of;
+use urcu_cds_sys::wfcq;
+
+use crate::collections::wfqueue::container::Dequeue;
+use crate::utility::*;
+
+/// Defines the value returned by a non-blocking dequeue when it would block.
+const WOULDBLOCK: *mut wfcq::Node = -1isize as *mut wfcq::Node;
+
+#[repr(C)]
+pub struct RawNode<T> {
+    handle: wfcq::Node,
+    data: T,
+}
+
+impl<T> RawNode<T> {
+    pub fn new(data: T) -> Box<Self> {
+        let mut handle = MaybeUninit::<wfcq::Node>::uninit();
+
+        // SAFETY: The pointer is valid for writes.
+        unsafe { wfcq::node_init(handle.as_mut_ptr()) };
+
+        Box::new(Self {
+            // SAFETY: Data has been initialised by `wfcq::node_init`.
+            handle: unsafe { handle.assume_init() },
+            data,
+        })
+    }
+
+    fn into_handle(self: Box<Self>) -> *mut wfcq::Node {
+        let node_ptr = Box::into_raw(self);
+        let node = unsafe { node_ptr.as_mut_unchecked() };
+        &mut node.handle
+    }
+
+    /// #### Safety
+    ///
+    /// The handle must be non-null and owned by the caller.
+    unsafe fn from_handle(handle: *mut wfcq::Node) -> Box<Self> {
+        // SAFETY: The node was allocated by `RawNode::new`.
+        unsafe { Box::from_raw(container_of!(handle, Self, handle)) }
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+pub struct RawQueue<T> {
+    head: wfcq::Head,
+    tail: wfcq::Tail,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawQueue<T> {
+    /// #### Safety
+    ///
+    /// The caller must call [`RawQueue::init`] once [`RawQueue`] is in a stable memory location.
+    pub unsafe fn new() -> Self {
+        Self {
+            head: Default::default(),
+            tail: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must ensure [`RawQueue`] is in a stable memory location.
+    pub unsafe fn init(&mut self) {
+        // SAFETY: The pointers are non-null.
+        unsafe { wfcq::init(&mut self.head, &mut self.tail) };
+    }
+
+    fn head_ptr(&self) -> *mut wfcq::Head {
+        &self.head as *const wfcq::Head as *mut wfcq::Head
+    }
+
+    fn tail_ptr(&self) -> *mut wfcq::Tail {
+        &self.tail as *const wfcq::Tail as *mut wfcq::Tail
+    }
+
+    pub fn empty(&self) -> bool {
+        // SAFETY: The C call safely reads the state shared between threads.
+        unsafe { wfcq::empty(wfcq::HeadPtr { h: self.head_ptr() }, self.tail_ptr()) }
+    }
+
+    pub fn enqueue(&self, node: Box<RawNode<T>>) {
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe {
+            wfcq::enqueue(
+                wfcq::HeadPtr { h: self.head_ptr() },
+                self.tail_ptr(),
+                node.into_handle(),
+            )
+        };
+    }
+
+    pub fn dequeue_blocking(&self) -> Option<Box<RawNode<T>>> {
+        // SAFETY: The C call takes the dequeue lock.
+        let handle = unsafe { wfcq::dequeue_blocking(self.head_ptr(), self.tail_ptr()) };
+
+        if handle.is_null() {
+            None
+        } else {
+            // SAFETY: The node is removed from the queue, we are the only owner.
+            Some(unsafe { RawNode::from_handle(handle) })
+        }
+    }
+
+    /// Returns [`Dequeue::Busy`] if an enqueuer has not finished linking the first node.
+    pub fn dequeue_nonblocking(&self) -> Dequeue<Box<RawNode<T>>> {
+        // SAFETY: The dequeue lock is held for the duration of the call.
+        let handle = unsafe {
+            wfcq::dequeue_lock(self.head_ptr(), self.tail_ptr());
+            let handle =
+                wfcq::__dequeue_nonblocking(wfcq::HeadPtr { h: self.head_ptr() }, self.tail_ptr());
+            wfcq::dequeue_unlock(self.head_ptr(), self.tail_ptr());
+            handle
+        };
+
+        if handle == WOULDBLOCK {
+            Dequeue::Busy
+        } else if handle.is_null() {
+            Dequeue::Empty
+        } else {
+            // SAFETY: The node is removed from the queue, we are the only owner.
+            Dequeue::Item(unsafe { RawNode::from_handle(handle) })
+        }
+    }
+
+    /// Moves all the nodes of `src` to the back of this queue.
+    ///
+    /// Returns `false` if `src` was empty.
+    pub fn splice_blocking(&self, src: &Self) -> bool {
+        // SAFETY: The C call takes the dequeue lock of the source queue.
+        let ret = unsafe {
+            wfcq::splice_blocking(
+                self.head_ptr(),
+                self.tail_ptr(),
+                src.head_ptr(),
+                src.tail_ptr(),
+            )
+        };
+
+        ret != wfcq::RET_SRC_EMPTY
+    }
+}
+
+impl<T> Drop for RawQueue<T> {
+    fn drop(&mut self) {
+        while let Some(node) = self.dequeue_blocking() {
+            drop(node);
+        }
+
+        // SAFETY: The queue is empty and never used again.
+        unsafe { wfcq::destroy(&mut self.head, &mut self.tail) };
+    }
+}
@@ -0,0 +1,56 @@
+use crate::collections::wfqueue::container::{Dequeue, WfQueue};
+
+#[test]
+fn simple() {
+    let queue = WfQueue::<u32>::new();
+
+    assert!(queue.is_empty());
+    assert_eq!(queue.dequeue_blocking(), None);
+    assert_eq!(queue.try_dequeue(), Dequeue::Empty);
+
+    queue.enqueue(10);
+    queue.enqueue(20);
+    assert!(!queue.is_empty());
+
+    assert_eq!(queue.dequeue_blocking(), Some(10));
+    assert_eq!(queue.try_dequeue(), Dequeue::Item(20));
+    assert_eq!(queue.dequeue_blocking(), None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn splice() {
+    let queue = WfQueue::<u32>::new();
+    let other = WfQueue::<u32>::new();
+
+    assert!(!queue.splice(&other));
+
+    queue.enqueue(10);
+    other.enqueue(20);
+    other.enqueue(30);
+
+    assert!(queue.splice(&other));
+    assert!(!queue.splice(&queue));
+    assert!(other.is_empty());
+
+    assert_eq!(queue.dequeue_blocking(), Some(10));
+    assert_eq!(queue.dequeue_blocking(), Some(20));
+    assert_eq!(queue.dequeue_blocking(), Some(30));
+    assert_eq!(queue.dequeue_blocking(), None);
+}
+
+#[test]
+fn unregistered() {
+    let queue = WfQueue::<u32>::new();
+
+    std::thread::scope(|scope| {
+        for value in 0..4 {
+            let queue = &queue;
+            scope.spawn(move || queue.enqueue(value));
+        }
+    });
+
+    let mut values = std::iter::from_fn(|| queue.dequeue_blocking()).collect::<Vec<_>>();
+    values.sort();
+    assert_eq!(values, vec![0, 1, 2, 3]);
+}
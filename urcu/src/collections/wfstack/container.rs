@@ -0,0 +1,104 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::collections::wfstack::raw::{RawNode, RawStack};
+use crate::utility::*;
+
+/// Defines a wait-free stack without RCU requirements.
+///
+/// This stack supports multiple concurrent producers and consumers. Unlike [`RcuStack`],
+/// the elements are removed under an internal lock, so they are owned as soon as they are
+/// popped and no RCU grace period is needed. It can be used from threads that are not
+/// registered with RCU.
+///
+/// # Limitations
+///
+/// ##### Blocking
+///
+/// Adding an element never blocks. Removing elements takes an internal lock and may
+/// busy-wait for a concurrent producer to finish linking its element.
+///
+/// ##### References
+///
+/// This stack does not offer a way to peek or iterate over its elements.
+///
+/// [`RcuStack`]: crate::collections::stack::container::RcuStack
+pub struct WfStack<T> {
+    raw: RawStack<T>,
+    _unsend: PhantomUnsend,
+    _unsync: PhantomUnsync,
+}
+
+impl<T> WfStack<T> {
+    /// Creates a new wait-free stack.
+    pub fn new() -> Arc<Self> {
+        let mut stack = Arc::new(WfStack {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawStack::new() },
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw stack is in a stable memory location.
+        unsafe { Arc::<Self>::get_mut(&mut stack).unwrap().raw.init() };
+
+        stack
+    }
+
+    /// Adds an element to the top of the stack.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn push(&self, data: T)
+    where
+        T: Send,
+    {
+        self.raw.push(RawNode::new(data));
+    }
+
+    /// Removes the top element of the stack, if any.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_blocking(&self) -> Option<T>
+    where
+        T: Send,
+    {
+        self.raw.pop_blocking().map(|node| node.into_data())
+    }
+
+    /// Removes all the elements of the stack.
+    ///
+    /// The elements are returned from top to bottom.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop_all_blocking(&self) -> Vec<T>
+    where
+        T: Send,
+    {
+        self.raw
+            .pop_all_blocking()
+            .into_iter()
+            .map(|node| node.into_data())
+            .collect()
+    }
+
+    /// Returns `true` if there is no element in the stack.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+}
+
+/// #### Safety
+///
+/// An [`WfStack`] can be used to send `T` to another thread.
+unsafe impl<T> Send for WfStack<T> where T: Send {}
+
+/// #### Safety
+///
+/// An [`WfStack`] can be used to share `T` between threads through removals.
+unsafe impl<T> Sync for WfStack<T> where T: Send {}
@@ -0,0 +1,32 @@
+pub(crate) mod container;
+pub(crate) mod raw;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::wfstack::container::WfStack;
+    use crate::utility::asserts::*;
+
+    mod wf_stack {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(WfStack<NotSendNotSync>: Send);
+        assert_not_impl_all!(WfStack<NotSendNotSync>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(WfStack<SendButNotSync>: Send);
+        assert_impl_all!(WfStack<SendButNotSync>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(WfStack<NotSendButSync>: Send);
+        assert_not_impl_all!(WfStack<NotSendButSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(WfStack<SendAndSync>: Send);
+        assert_impl_all!(WfStack<SendAndSync>: Sync);
+    }
+}
@@ -0,0 +1,142 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use container_of::container_of;
+use urcu_cds_sys::wfs;
+
+use crate::utility::*;
+
+#[repr(C)]
+pub struct RawNode<T> {
+    handle: wfs::Node,
+    data: T,
+}
+
+impl<T> RawNode<T> {
+    pub fn new(data: T) -> Box<Self> {
+        let mut handle = MaybeUninit::<wfs::Node>::uninit();
+
+        // SAFETY: The pointer is valid for writes.
+        unsafe { wfs::node_init(handle.as_mut_ptr()) };
+
+        Box::new(Self {
+            // SAFETY: Data has been initialised by `wfs::node_init`.
+            handle: unsafe { handle.assume_init() },
+            data,
+        })
+    }
+
+    fn into_handle(self: Box<Self>) -> *mut wfs::Node {
+        let node_ptr = Box::into_raw(self);
+        let node = unsafe { node_ptr.as_mut_unchecked() };
+        &mut node.handle
+    }
+
+    /// #### Safety
+    ///
+    /// The handle must be non-null and owned by the caller.
+    unsafe fn from_handle(handle: *mut wfs::Node) -> Box<Self> {
+        // SAFETY: The node was allocated by `RawNode::new`.
+        unsafe { Box::from_raw(container_of!(handle, Self, handle)) }
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+}
+
+pub struct RawStack<T> {
+    handle: wfs::Stack,
+    _unsend: PhantomUnsend<T>,
+    _unsync: PhantomUnsync<T>,
+}
+
+impl<T> RawStack<T> {
+    /// #### Safety
+    ///
+    /// The caller must call [`RawStack::init`] once [`RawStack`] is in a stable memory location.
+    pub unsafe fn new() -> Self {
+        Self {
+            handle: Default::default(),
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        }
+    }
+
+    /// #### Safety
+    ///
+    /// The caller must ensure [`RawStack`] is in a stable memory location.
+    pub unsafe fn init(&mut self) {
+        // SAFETY: The pointer is non-null.
+        unsafe { wfs::init(&mut self.handle) };
+    }
+
+    fn handle_ptr(&self) -> *mut wfs::Stack {
+        &self.handle as *const wfs::Stack as *mut wfs::Stack
+    }
+
+    pub fn empty(&self) -> bool {
+        // SAFETY: The C call safely reads the state shared between threads.
+        unsafe {
+            wfs::empty(wfs::StackPtr {
+                s: self.handle_ptr(),
+            })
+        }
+    }
+
+    pub fn push(&self, node: Box<RawNode<T>>) {
+        // SAFETY: The C call safely mutate the state shared between threads.
+        unsafe {
+            wfs::push(
+                wfs::StackPtr {
+                    s: self.handle_ptr(),
+                },
+                node.into_handle(),
+            )
+        };
+    }
+
+    pub fn pop_blocking(&self) -> Option<Box<RawNode<T>>> {
+        // SAFETY: The C call takes the pop lock.
+        let handle = unsafe { wfs::pop_blocking(self.handle_ptr()) };
+
+        if handle.is_null() {
+            None
+        } else {
+            // SAFETY: The node is removed from the stack, we are the only owner.
+            Some(unsafe { RawNode::from_handle(handle) })
+        }
+    }
+
+    pub fn pop_all_blocking(&self) -> Vec<Box<RawNode<T>>> {
+        // SAFETY: The C call takes the pop lock.
+        let head = unsafe { wfs::pop_all_blocking(self.handle_ptr()) };
+        let mut nodes = Vec::new();
+
+        if head.is_null() {
+            return nodes;
+        }
+
+        // SAFETY: The head pointer is non-null.
+        let mut handle = unsafe { wfs::first(head) };
+        while !handle.is_null() {
+            // SAFETY: The next pointer is read before the node is owned.
+            let next = unsafe { wfs::next_blocking(handle) };
+
+            // SAFETY: The nodes are removed from the stack, we are the only owner.
+            nodes.push(unsafe { RawNode::from_handle(handle) });
+            handle = next;
+        }
+
+        nodes
+    }
+}
+
+impl<T> Drop for RawStack<T> {
+    fn drop(&mut self) {
+        drop(self.pop_all_blocking());
+
+        // SAFETY: The stack is empty and never used again.
+        unsafe { wfs::destroy(&mut self.handle) };
+    }
+}
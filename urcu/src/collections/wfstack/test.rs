@@ -0,0 +1,36 @@
+use crate::collections::wfstack::container::WfStack;
+
+#[test]
+fn simple() {
+    let stack = WfStack::<u32>::new();
+
+    assert!(stack.is_empty());
+    assert_eq!(stack.pop_blocking(), None);
+
+    stack.push(10);
+    stack.push(20);
+    assert!(!stack.is_empty());
+
+    assert_eq!(stack.pop_blocking(), Some(20));
+    assert_eq!(stack.pop_blocking(), Some(10));
+    assert_eq!(stack.pop_blocking(), None);
+    assert!(stack.is_empty());
+}
+
+#[test]
+fn pop_all() {
+    let stack = WfStack::<u32>::new();
+
+    assert!(stack.pop_all_blocking().is_empty());
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            stack.push(10);
+            stack.push(20);
+            stack.push(30);
+        });
+    });
+
+    assert_eq!(stack.pop_all_blocking(), vec![30, 20, 10]);
+    assert!(stack.is_empty());
+}
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::collections::wfqueue::raw::{RawNode, RawQueue};
+use crate::utility::*;
+
+/// Defines a work queue with batch stealing.
+///
+/// Producers add work to the back of the queue without ever blocking. The consumer
+/// owning the queue removes work from the front, while idle consumers steal all the
+/// pending work at once into their own queue with [`RcuWorkQueue::steal_into`].
+///
+/// The work is owned as soon as it is removed, so no RCU grace period is needed and
+/// the queue can be used from threads that are not registered with RCU.
+///
+/// # Limitations
+///
+/// ##### Blocking
+///
+/// Adding work never blocks. Removing or stealing work takes an internal lock and may
+/// busy-wait for a concurrent producer to finish linking its work.
+///
+/// ##### Ordering
+///
+/// Stolen work is appended to the back of the destination queue, after its own pending
+/// work.
+pub struct RcuWorkQueue<T> {
+    raw: RawQueue<T>,
+    _unsend: PhantomUnsend,
+    _unsync: PhantomUnsync,
+}
+
+impl<T> RcuWorkQueue<T> {
+    /// Creates a new work queue.
+    pub fn new() -> Arc<Self> {
+        let mut queue = Arc::new(RcuWorkQueue {
+            // SAFETY: Initialisation is properly called.
+            raw: unsafe { RawQueue::new() },
+            _unsend: PhantomData,
+            _unsync: PhantomData,
+        });
+
+        // SAFETY: Initialisation occurs when raw queue is in a stable memory location.
+        unsafe { Arc::<Self>::get_mut(&mut queue).unwrap().raw.init() };
+
+        queue
+    }
+
+    /// Adds work to the back of the queue.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn push(&self, work: T)
+    where
+        T: Send,
+    {
+        self.raw.enqueue(RawNode::new(work));
+    }
+
+    /// Removes work from the front of the queue, if any.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn pop(&self) -> Option<T>
+    where
+        T: Send,
+    {
+        self.raw.dequeue_blocking().map(|node| node.into_data())
+    }
+
+    /// Moves all the pending work of this queue to the back of another queue.
+    ///
+    /// Returns `false` if there was no work to steal or if both queues are the same.
+    ///
+    /// #### Note
+    ///
+    /// This operation may block.
+    pub fn steal_into(&self, dest: &Self) -> bool
+    where
+        T: Send,
+    {
+        if std::ptr::eq(self, dest) {
+            return false;
+        }
+
+        dest.raw.splice_blocking(&self.raw)
+    }
+
+    /// Returns `true` if there is no pending work in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.raw.empty()
+    }
+}
+
+/// #### Safety
+///
+/// An [`RcuWorkQueue`] can be used to send `T` to another thread.
+unsafe impl<T> Send for RcuWorkQueue<T> where T: Send {}
+
+/// #### Safety
+///
+/// An [`RcuWorkQueue`] only shares `T` between threads by moving it.
+unsafe impl<T> Sync for RcuWorkQueue<T> where T: Send {}
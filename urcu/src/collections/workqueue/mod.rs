@@ -0,0 +1,31 @@
+pub(crate) mod container;
+
+#[cfg(test)]
+mod test;
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::collections::workqueue::container::RcuWorkQueue;
+    use crate::utility::asserts::*;
+
+    mod rcu_work_queue {
+        use super::*;
+
+        // T: !Send + !Sync
+        assert_not_impl_all!(RcuWorkQueue<NotSendNotSync>: Send);
+        assert_not_impl_all!(RcuWorkQueue<NotSendNotSync>: Sync);
+
+        // T: Send + !Sync
+        assert_impl_all!(RcuWorkQueue<SendButNotSync>: Send);
+        assert_impl_all!(RcuWorkQueue<SendButNotSync>: Sync);
+
+        // T: !Send + Sync
+        assert_not_impl_all!(RcuWorkQueue<NotSendButSync>: Send);
+        assert_not_impl_all!(RcuWorkQueue<NotSendButSync>: Sync);
+
+        // T: Send + Sync
+        assert_impl_all!(RcuWorkQueue<SendAndSync>: Send);
+        assert_impl_all!(RcuWorkQueue<SendAndSync>: Sync);
+    }
+}
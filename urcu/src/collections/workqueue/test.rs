@@ -0,0 +1,41 @@
+use crate::collections::workqueue::container::RcuWorkQueue;
+
+#[test]
+fn simple() {
+    let queue = RcuWorkQueue::<u32>::new();
+
+    assert!(queue.is_empty());
+    assert_eq!(queue.pop(), None);
+
+    queue.push(10);
+    queue.push(20);
+
+    assert_eq!(queue.pop(), Some(10));
+    assert_eq!(queue.pop(), Some(20));
+    assert_eq!(queue.pop(), None);
+}
+
+#[test]
+fn steal() {
+    let victim = RcuWorkQueue::<u32>::new();
+    let thief = RcuWorkQueue::<u32>::new();
+
+    assert!(!victim.steal_into(&thief));
+
+    thief.push(10);
+    victim.push(20);
+    victim.push(30);
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            assert!(victim.steal_into(&thief));
+            assert!(!victim.steal_into(&victim));
+        });
+    });
+
+    assert!(victim.is_empty());
+    assert_eq!(thief.pop(), Some(10));
+    assert_eq!(thief.pop(), Some(20));
+    assert_eq!(thief.pop(), Some(30));
+    assert_eq!(thief.pop(), None);
+}
@@ -3,18 +3,49 @@
 
 mod utility;
 
+pub mod channel;
 pub mod collections;
 pub mod rcu;
 
+pub use crate::collections::arc::container::RcuArc;
+pub use crate::collections::bitmap::container::RcuBitmap;
 pub use crate::collections::boxed::container::RcuBox;
+pub use crate::collections::boxed::string::RcuStr;
+pub use crate::collections::cell::container::RcuCell;
+pub use crate::collections::cell::once::RcuOnceCell;
+pub use crate::collections::clock::container::RcuClockCache;
+pub use crate::collections::counter::container::RcuCounter;
 pub use crate::collections::hashmap::container::RcuHashMap;
+pub use crate::collections::hashmap::expiring::RcuExpiringHashMap;
+pub use crate::collections::hashmap::multimap::RcuMultiMap;
+pub use crate::collections::hashset::container::RcuHashSet;
+pub use crate::collections::hlist::container::RcuHList;
+pub use crate::collections::interner::container::RcuInterner;
+pub use crate::collections::intrusive::hashmap::RcuIntrusiveHashMap;
+pub use crate::collections::intrusive::list::RcuIntrusiveList;
+pub use crate::collections::intrusive::stack::RcuIntrusiveStack;
+pub use crate::collections::list::concurrent::RcuConcurrentList;
 pub use crate::collections::list::container::RcuList;
+pub use crate::collections::list::multi::RcuMultiWriterList;
+pub use crate::collections::percpu::container::RcuPerCpu;
+pub use crate::collections::protected::container::RcuProtected;
 pub use crate::collections::queue::container::RcuQueue;
+pub use crate::collections::queue::priority::RcuPriorityQueue;
+pub use crate::collections::radix::container::RcuRadixTree;
+pub use crate::collections::ring::container::RcuRingBuffer;
+pub use crate::collections::skiplist::container::RcuSkipList;
+pub use crate::collections::slab::container::RcuSlab;
 pub use crate::collections::stack::container::RcuStack;
+pub use crate::collections::vec::container::RcuVec;
+pub use crate::collections::wfqueue::container::WfQueue;
+pub use crate::collections::wfstack::container::WfStack;
+pub use crate::collections::workqueue::container::RcuWorkQueue;
 pub use crate::rcu::cleanup::{RcuCleanup, RcuCleanupMut};
-pub use crate::rcu::context::{RcuContext, RcuDeferContext, RcuReadContext};
+pub use crate::rcu::context::{RcuContext, RcuContextDyn, RcuDeferContext, RcuReadContext};
+pub use crate::rcu::field::RcuField;
 pub use crate::rcu::flavor::RcuFlavor;
 pub use crate::rcu::guard::RcuGuard;
+pub use crate::rcu::init::{init, shutdown, FlavorSet};
 pub use crate::rcu::poller::RcuPoller;
 pub use crate::rcu::reference::RcuRef;
 
@@ -24,7 +55,41 @@ pub mod prelude {
 
     pub use crate::{RcuContext, RcuDeferContext, RcuReadContext};
 
-    pub use crate::{RcuBox, RcuHashMap, RcuList, RcuQueue, RcuStack};
+    pub use crate::{
+        RcuArc,
+        RcuBitmap,
+        RcuBox,
+        RcuCell,
+        RcuClockCache,
+        RcuConcurrentList,
+        RcuCounter,
+        RcuExpiringHashMap,
+        RcuHList,
+        RcuHashMap,
+        RcuHashSet,
+        RcuInterner,
+        RcuIntrusiveHashMap,
+        RcuIntrusiveList,
+        RcuIntrusiveStack,
+        RcuList,
+        RcuMultiMap,
+        RcuMultiWriterList,
+        RcuOnceCell,
+        RcuPerCpu,
+        RcuPriorityQueue,
+        RcuProtected,
+        RcuQueue,
+        RcuRadixTree,
+        RcuRingBuffer,
+        RcuSkipList,
+        RcuSlab,
+        RcuStack,
+        RcuStr,
+        RcuVec,
+        RcuWorkQueue,
+        WfQueue,
+        WfStack,
+    };
 
     pub use crate::rcu::default::{
         RcuDefaultContext,
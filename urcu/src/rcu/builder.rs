@@ -1,5 +1,26 @@
+use std::fmt::{Display, Formatter};
 use std::marker::PhantomData;
 
+/// Defines the errors when registering a thread with RCU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterError {
+    /// The thread already has a context of this flavor.
+    AlreadyRegistered,
+    /// The flavor failed to register the thread for defer operations.
+    DeferUnavailable,
+}
+
+impl Display for RegisterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyRegistered => write!(f, "thread is already registered with RCU"),
+            Self::DeferUnavailable => write!(f, "failed to register thread for RCU defer"),
+        }
+    }
+}
+
+impl std::error::Error for RegisterError {}
+
 pub struct RcuContextBuilder<F, const READ: bool = false, const DEFER: bool = false>(
     PhantomData<F>,
 );
@@ -30,7 +51,7 @@ mod bp {
     use crate::rcu::flavor::RcuFlavorBp;
 
     impl<const READ: bool, const DEFER: bool> RcuContextBuilder<RcuFlavorBp, READ, DEFER> {
-        pub fn register_thread(self) -> Option<RcuContextBp<READ, DEFER>> {
+        pub fn register_thread(self) -> Result<RcuContextBp<READ, DEFER>, RegisterError> {
             RcuContextBp::<READ, DEFER>::new()
         }
     }
@@ -44,7 +65,7 @@ mod mb {
     use crate::rcu::flavor::RcuFlavorMb;
 
     impl<const READ: bool, const DEFER: bool> RcuContextBuilder<RcuFlavorMb, READ, DEFER> {
-        pub fn register_thread(self) -> Option<RcuContextMb<READ, DEFER>> {
+        pub fn register_thread(self) -> Result<RcuContextMb<READ, DEFER>, RegisterError> {
             RcuContextMb::<READ, DEFER>::new()
         }
     }
@@ -58,7 +79,7 @@ mod memb {
     use crate::rcu::flavor::RcuFlavorMemb;
 
     impl<const READ: bool, const DEFER: bool> RcuContextBuilder<RcuFlavorMemb, READ, DEFER> {
-        pub fn register_thread(self) -> Option<RcuContextMemb<READ, DEFER>> {
+        pub fn register_thread(self) -> Result<RcuContextMemb<READ, DEFER>, RegisterError> {
             RcuContextMemb::<READ, DEFER>::new()
         }
     }
@@ -72,8 +93,22 @@ mod qsbr {
     use crate::rcu::flavor::RcuFlavorQsbr;
 
     impl<const READ: bool, const DEFER: bool> RcuContextBuilder<RcuFlavorQsbr, READ, DEFER> {
-        pub fn register_thread(self) -> Option<RcuContextQsbr<READ, DEFER>> {
+        pub fn register_thread(self) -> Result<RcuContextQsbr<READ, DEFER>, RegisterError> {
             RcuContextQsbr::<READ, DEFER>::new()
         }
     }
 }
+
+#[cfg(feature = "flavor-rs")]
+mod rs {
+    use super::*;
+
+    use crate::rcu::context::RcuContextRs;
+    use crate::rcu::flavor::RcuFlavorRs;
+
+    impl<const READ: bool, const DEFER: bool> RcuContextBuilder<RcuFlavorRs, READ, DEFER> {
+        pub fn register_thread(self) -> Result<RcuContextRs<READ, DEFER>, RegisterError> {
+            RcuContextRs::<READ, DEFER>::new()
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::rcu::callback::{RcuCall, RcuDefer};
+use crate::rcu::context::{RcuContext, RcuDeferContext, RcuReadContext};
+
+/// Defines the capability to read RCU protected data.
+///
+/// It is obtained from [`RcuReadContext::rcu_read_capability`] and can be passed to
+/// helper functions that only need to start RCU critical sections, without exposing
+/// the rest of the context.
+pub struct RcuReadCapability<'a, C>(&'a C);
+
+impl<'a, C> RcuReadCapability<'a, C>
+where
+    C: RcuReadContext,
+{
+    pub(crate) fn new(context: &'a C) -> Self {
+        Self(context)
+    }
+
+    /// Starts a RCU critical section.
+    ///
+    /// See [`RcuReadContext::rcu_read_lock`].
+    pub fn rcu_read_lock(&self) -> C::Guard<'a> {
+        self.0.rcu_read_lock()
+    }
+
+    /// Configures a callback to be called after the next RCU grace period is finished.
+    ///
+    /// See [`RcuReadContext::rcu_call`].
+    pub fn rcu_call<F>(&self, callback: Box<F>)
+    where
+        F: RcuCall + Send + 'static,
+    {
+        self.0.rcu_call(callback)
+    }
+}
+
+impl<C> Clone for RcuReadCapability<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for RcuReadCapability<'_, C> {}
+
+/// Defines the capability to defer callbacks after a RCU grace period.
+///
+/// It is obtained from [`RcuDeferContext::rcu_defer_capability`] and can be passed to
+/// helper functions expecting a [`RcuDeferContext`], without allowing them to start
+/// RCU critical sections.
+pub struct RcuDeferCapability<'a, C>(&'a mut C);
+
+impl<'a, C> RcuDeferCapability<'a, C>
+where
+    C: RcuDeferContext,
+{
+    pub(crate) fn new(context: &'a mut C) -> Self {
+        Self(context)
+    }
+}
+
+/// #### Safety
+///
+/// Safety is guaranteed by the underlying context.
+unsafe impl<C> RcuContext for RcuDeferCapability<'_, C>
+where
+    C: RcuContext,
+{
+    type Flavor = C::Flavor;
+
+    type Poller<'a>
+        = C::Poller<'a>
+    where
+        Self: 'a,
+        C: 'a;
+
+    fn rcu_synchronize(&mut self) {
+        self.0.rcu_synchronize()
+    }
+
+    fn rcu_synchronize_poller(&self) -> Self::Poller<'_> {
+        self.0.rcu_synchronize_poller()
+    }
+}
+
+/// #### Safety
+///
+/// Safety is guaranteed by the underlying context.
+unsafe impl<C> RcuDeferContext for RcuDeferCapability<'_, C>
+where
+    C: RcuDeferContext,
+{
+    fn rcu_defer<F>(&mut self, callback: Box<F>)
+    where
+        F: RcuDefer,
+    {
+        self.0.rcu_defer(callback)
+    }
+}
@@ -19,7 +19,7 @@ pub type RcuCleanup<C> = Box<dyn FnOnce(&C) + Send + 'static>;
 /// Defines the cleanup callback signature.
 pub type RcuCleanupMut<C> = Box<dyn FnOnce(&mut C) + Send + 'static>;
 
-type ContextFn<C> = Box<dyn FnOnce() -> C + Send>;
+pub(crate) type ContextFn<C> = Box<dyn FnOnce() -> C + Send>;
 
 enum Command<C> {
     Execute(RcuCleanup<C>),
@@ -86,19 +86,27 @@ impl<C> ThreadHandle<C>
 where
     C: RcuReadContext + 'static,
 {
+    fn new(context: ContextFn<C>) -> Self {
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        Self {
+            thread: Some(Thread::start(context, rx)),
+            callbacks: tx,
+        }
+    }
+
+    /// Shuts down the thread without waiting for the remaining commands.
+    fn detach(mut self) {
+        // The thread is not joined when the handle is dropped.
+        drop(self.thread.take());
+    }
+
     fn create(instance: &RwLock<Option<Self>>, context: ContextFn<C>) -> RcuCleaner<C> {
         RcuCleaner(
             instance
                 .write()
                 .unwrap()
-                .get_or_insert_with(|| {
-                    let (tx, rx) = std::sync::mpsc::channel();
-
-                    Self {
-                        thread: Some(Thread::start(context, rx)),
-                        callbacks: tx,
-                    }
-                })
+                .get_or_insert_with(|| Self::new(context))
                 .callbacks
                 .clone(),
         )
@@ -117,7 +125,16 @@ where
     }
 
     fn delete(instance: &RwLock<Option<Self>>) {
-        instance.write().unwrap().take();
+        loop {
+            // The handle is dropped outside of the lock because the remaining
+            // callbacks may start a new cleanup thread.
+            let handle = instance.write().unwrap().take();
+
+            match handle {
+                Some(handle) => drop(handle),
+                None => break,
+            }
+        }
     }
 }
 
@@ -138,6 +155,59 @@ impl<C> Drop for ThreadHandle<C> {
     }
 }
 
+/// Defines a dedicated cleanup thread.
+///
+/// Unlike [`RcuFlavor::rcu_cleanup`], the callbacks are not executed by the cleanup
+/// thread shared by every user of the flavor. It is useful to isolate the reclamation
+/// bursts of a container from the other containers.
+///
+/// #### Note
+///
+/// Dropping the handle blocks until all the callbacks are executed.
+pub struct RcuCleanupThread<F>
+where
+    F: RcuFlavor,
+{
+    handle: ThreadHandle<F::CleanupContext>,
+}
+
+impl<F> RcuCleanupThread<F>
+where
+    F: RcuFlavor + 'static,
+{
+    pub(crate) fn new(context: ContextFn<F::CleanupContext>) -> Self {
+        Self {
+            handle: ThreadHandle::new(context),
+        }
+    }
+
+    fn cleaner(&self) -> RcuCleaner<F::CleanupContext> {
+        RcuCleaner(self.handle.callbacks.clone())
+    }
+
+    /// Shuts down the dedicated cleanup thread without blocking.
+    ///
+    /// The thread executes the remaining callbacks before shutting down.
+    pub(crate) fn detach(self) {
+        self.handle.detach();
+    }
+
+    /// Configures a callback to be executed by the dedicated cleanup thread.
+    ///
+    /// See [`RcuFlavor::rcu_cleanup`] for more details.
+    pub fn rcu_cleanup(&self, callback: RcuCleanupMut<F::CleanupContext>) {
+        self.cleaner().send_mut(callback);
+    }
+
+    /// Configures a callback to be executed by the dedicated cleanup thread and
+    /// waits for its completion.
+    ///
+    /// See [`RcuFlavor::rcu_cleanup_and_block`] for more details.
+    pub fn rcu_cleanup_and_block(&self, callback: RcuCleanup<F::CleanupContext>) {
+        self.cleaner().send(callback).barrier();
+    }
+}
+
 pub struct RcuCleaner<C>(Sender<Command<C>>);
 
 impl<C> RcuCleaner<C> {
@@ -190,15 +260,36 @@ macro_rules! impl_cleanup_for_context {
                     assert_eq!(libc::atexit(Self::delete), 0);
                 });
 
-                let context = Box::new(|| {
+                ThreadHandle::<$context<true, true>>::get(&INSTANCE, Self::context())
+            }
+
+            pub fn shutdown() {
+                // The barriers run before the shutdown command, so the cleanups requested by
+                // the flushed callbacks are executed by this thread instead of a new one.
+                if let Some(cleaner) = ThreadHandle::<$context<true, true>>::try_get(&INSTANCE) {
+                    cleaner
+                        .send(Box::new(|_| {
+                            // SAFETY: The thread is registered for both kinds of callbacks.
+                            // SAFETY: The thread is not inside a RCU critical section.
+                            unsafe {
+                                $flavor::unchecked_rcu_defer_barrier();
+                                $flavor::unchecked_rcu_call_barrier();
+                            }
+                        }))
+                        .barrier();
+                }
+
+                ThreadHandle::<$context<true, true>>::delete(&INSTANCE);
+            }
+
+            pub fn context() -> ContextFn<$context<true, true>> {
+                Box::new(|| {
                     $flavor::rcu_context_builder()
                         .with_read_context()
                         .with_defer_context()
                         .register_thread()
                         .unwrap()
-                });
-
-                ThreadHandle::<$context<true, true>>::get(&INSTANCE, context)
+                })
             }
         }
     };
@@ -243,3 +334,13 @@ mod qsbr {
 
     impl_cleanup_for_context!(RcuFlavorQsbr, RcuContextQsbr);
 }
+
+#[cfg(feature = "flavor-rs")]
+mod rs {
+    use super::*;
+
+    use crate::rcu::context::RcuContextRs;
+    use crate::rcu::flavor::RcuFlavorRs;
+
+    impl_cleanup_for_context!(RcuFlavorRs, RcuContextRs);
+}
@@ -1,10 +1,12 @@
 use std::cell::Cell;
 use std::marker::PhantomData;
 
+use crate::rcu::builder::RegisterError;
 use crate::rcu::callback::{RcuCall, RcuDefer};
+use crate::rcu::capability::{RcuDeferCapability, RcuReadCapability};
 use crate::rcu::flavor::RcuFlavor;
 use crate::rcu::guard::RcuGuard;
-use crate::rcu::poller::RcuPoller;
+use crate::rcu::poller::{RcuPollHandle, RcuPoller};
 use crate::utility::{PhantomUnsend, PhantomUnsync};
 
 /// This trait defines the per-thread RCU context.
@@ -50,6 +52,48 @@ pub unsafe trait RcuContext {
     ///
     /// It may be called in a RCU critical section.
     fn rcu_synchronize_poller(&self) -> Self::Poller<'_>;
+
+    /// Creates a RCU grace period poll handle.
+    ///
+    /// Unlike [`RcuContext::rcu_synchronize_poller`], the handle can be sent to another
+    /// thread, which checks its completion with its own context.
+    ///
+    /// #### Note
+    ///
+    /// It may be called in a RCU critical section.
+    fn rcu_poll_handle(&self) -> RcuPollHandle<Self::Flavor> {
+        // SAFETY: The thread is initialized at context's creation.
+        RcuPollHandle::new(unsafe { Self::Flavor::unchecked_rcu_poll_start() })
+    }
+}
+
+/// This trait defines a dyn-compatible subset of [`RcuContext`].
+///
+/// It is implemented for every [`RcuContext`] and allows contexts of different
+/// flavors to be stored as `Box<dyn RcuContextDyn>`.
+pub trait RcuContextDyn {
+    /// Waits until the RCU grace period is over.
+    ///
+    /// See [`RcuContext::rcu_synchronize`].
+    fn rcu_synchronize(&mut self);
+
+    /// Creates a RCU grace period poller.
+    ///
+    /// See [`RcuContext::rcu_synchronize_poller`].
+    fn rcu_synchronize_poller(&self) -> Box<dyn RcuPoller + '_>;
+}
+
+impl<C> RcuContextDyn for C
+where
+    C: RcuContext,
+{
+    fn rcu_synchronize(&mut self) {
+        RcuContext::rcu_synchronize(self)
+    }
+
+    fn rcu_synchronize_poller(&self) -> Box<dyn RcuPoller + '_> {
+        Box::new(RcuContext::rcu_synchronize_poller(self))
+    }
 }
 
 /// This trait defines the per-thread RCU read context.
@@ -103,6 +147,18 @@ pub unsafe trait RcuReadContext: RcuContext {
     where
         Self: Sized,
         F: FnOnce(&mut RcuOfflineContext<Self>) -> T;
+
+    /// Returns the capability to read RCU protected data.
+    ///
+    /// #### Note
+    ///
+    /// The capability can be shared with functions that should not synchronize.
+    fn rcu_read_capability(&self) -> RcuReadCapability<'_, Self>
+    where
+        Self: Sized,
+    {
+        RcuReadCapability::new(self)
+    }
 }
 
 /// This trait defines the per-thread RCU defer context.
@@ -123,6 +179,18 @@ pub unsafe trait RcuDeferContext: RcuContext {
     fn rcu_defer<F>(&mut self, callback: Box<F>)
     where
         F: RcuDefer;
+
+    /// Returns the capability to defer callbacks.
+    ///
+    /// #### Note
+    ///
+    /// The capability can be shared with functions that should not read RCU protected data.
+    fn rcu_defer_capability(&mut self) -> RcuDeferCapability<'_, Self>
+    where
+        Self: Sized,
+    {
+        RcuDeferCapability::new(self)
+    }
 }
 
 /// Defines a RCU context that is offline from reading RCU protected data.
@@ -204,7 +272,7 @@ macro_rules! define_rcu_context {
         ///
         /// #### Note
         ///
-        /// There can only be 1 instance per thread.
+        /// There can only be 1 instance per thread at any time.
         /// The thread will be registered upon creation.
         /// It will be unregistered upon dropping.
         pub struct $context<const READ: bool = false, const DEFER: bool = false>(
@@ -212,17 +280,19 @@ macro_rules! define_rcu_context {
             PhantomUnsync,
         );
 
+        thread_local! {static RCU_CONTEXT: Cell<bool> = const { Cell::new(false) }}
+        thread_local! {static RCU_INIT: Cell<bool> = const { Cell::new(false) }}
+
         impl<const READ: bool, const DEFER: bool> $context<READ, DEFER> {
             /// Creates the context instance.
             ///
             /// Only the first call will return a context.
-            /// Subsequent calls on the same thread will return nothing.
-            pub(crate) fn new() -> Option<Self> {
-                thread_local! {static RCU_CONTEXT: Cell<bool> = Cell::new(false)};
-
+            /// Subsequent calls on the same thread will return an error until
+            /// the context is dropped.
+            pub(crate) fn new() -> Result<Self, RegisterError> {
                 RCU_CONTEXT.with(|initialized| {
                     if initialized.replace(true) {
-                        return None;
+                        return Err(RegisterError::AlreadyRegistered);
                     }
 
                     log::info!(
@@ -232,15 +302,22 @@ macro_rules! define_rcu_context {
                         stringify!($kind),
                     );
 
-                    // SAFETY: Can only be called once per thread.
-                    // SAFETY: It is the first RCU call for a thread.
-                    unsafe { $flavor::unchecked_rcu_init() };
+                    // A thread registered again after dropping its context is already
+                    // initialized.
+                    if !RCU_INIT.with(|init| init.replace(true)) {
+                        // SAFETY: Can only be called once per thread.
+                        // SAFETY: It is the first RCU call for a thread.
+                        unsafe { $flavor::unchecked_rcu_init() };
+                    }
 
                     if DEFER {
                         // SAFETY: The thread is initialized.
                         // SAFETY: The thread is not defer-registered.
                         // SAFETY: The thread is defer-unregistered at context's drop.
-                        unsafe { $flavor::unchecked_rcu_defer_register_thread() };
+                        if unsafe { $flavor::unchecked_rcu_defer_register_thread() } != 0 {
+                            initialized.set(false);
+                            return Err(RegisterError::DeferUnavailable);
+                        }
                     }
 
                     if READ {
@@ -250,7 +327,7 @@ macro_rules! define_rcu_context {
                         unsafe { $flavor::unchecked_rcu_read_register_thread() };
                     }
 
-                    Some(Self(PhantomData, PhantomData))
+                    Ok(Self(PhantomData, PhantomData))
                 })
             }
         }
@@ -284,6 +361,9 @@ macro_rules! define_rcu_context {
                     // SAFETY: The thread is defer-registered at context's creation.
                     unsafe { $flavor::unchecked_rcu_defer_unregister_thread() };
                 }
+
+                // The thread-local storage might already be destroyed if the thread is exiting.
+                let _ = RCU_CONTEXT.try_with(|initialized| initialized.set(false));
             }
         }
 
@@ -437,6 +517,17 @@ mod qsbr {
     );
 }
 
+#[cfg(feature = "flavor-rs")]
+mod rs {
+    use super::*;
+
+    use crate::rcu::flavor::RcuFlavorRs;
+    use crate::rcu::guard::RcuGuardRs;
+    use crate::rcu::poller::RcuPollerRs;
+
+    define_rcu_context!(rs, RcuContextRs, RcuFlavorRs, RcuGuardRs, RcuPollerRs);
+}
+
 #[cfg(feature = "flavor-bp")]
 pub use bp::*;
 
@@ -449,6 +540,9 @@ pub use memb::*;
 #[cfg(feature = "flavor-qsbr")]
 pub use qsbr::*;
 
+#[cfg(feature = "flavor-rs")]
+pub use rs::*;
+
 mod asserts {
     use static_assertions::assert_not_impl_all;
 
@@ -491,4 +585,14 @@ mod asserts {
         assert_not_impl_all!(RcuContextQsbr: Send);
         assert_not_impl_all!(RcuContextQsbr: Sync);
     }
+
+    #[cfg(feature = "flavor-rs")]
+    mod rs {
+        use super::*;
+
+        use crate::rcu::context::rs::RcuContextRs;
+
+        assert_not_impl_all!(RcuContextRs: Send);
+        assert_not_impl_all!(RcuContextRs: Sync);
+    }
 }
@@ -0,0 +1,133 @@
+use std::marker::PhantomData;
+
+use crate::rcu::callback::RcuCallFn;
+use crate::rcu::context::{RcuContext, RcuReadContext};
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::flavor::RcuFlavor;
+
+type DeferredFn = Box<dyn FnOnce() + Send + 'static>;
+
+/// Defines a queue of callbacks executed after the next RCU grace period.
+///
+/// Callbacks queued with [`RcuDeferContext::rcu_defer`] are stored in the per-thread
+/// queue of `liburcu`, which cannot be transferred to another thread. A thread exiting
+/// with pending callbacks must wait for a grace period when its context is dropped.
+///
+/// Callbacks queued in this type can instead be handed off to the RCU cleanup thread
+/// of the flavor with [`RcuDeferQueue::handoff`]. This is useful for short-lived worker
+/// threads that should not block on exit.
+///
+/// #### Note
+///
+/// Dropping a non-empty queue hands off its callbacks.
+///
+/// [`RcuDeferContext::rcu_defer`]: crate::rcu::context::RcuDeferContext::rcu_defer
+pub struct RcuDeferQueue<F = RcuDefaultFlavor>
+where
+    F: RcuFlavor + 'static,
+{
+    callbacks: Vec<DeferredFn>,
+    _flavor: PhantomData<F>,
+}
+
+impl<F> RcuDeferQueue<F>
+where
+    F: RcuFlavor,
+{
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self {
+            callbacks: Vec::new(),
+            _flavor: PhantomData,
+        }
+    }
+
+    /// Queues a callback.
+    pub fn defer<C>(&mut self, callback: C)
+    where
+        C: FnOnce() + Send + 'static,
+    {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    /// Returns the amount of pending callbacks.
+    pub fn len(&self) -> usize {
+        self.callbacks.len()
+    }
+
+    /// Returns `true` if there are no pending callbacks.
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+
+    /// Waits for a RCU grace period and executes the pending callbacks.
+    ///
+    /// #### Note
+    ///
+    /// This operation blocks if there are pending callbacks.
+    pub fn flush<C>(&mut self, context: &mut C)
+    where
+        C: RcuContext<Flavor = F>,
+    {
+        if self.callbacks.is_empty() {
+            return;
+        }
+
+        context.rcu_synchronize();
+        Self::execute(std::mem::take(&mut self.callbacks));
+    }
+
+    /// Hands off the pending callbacks to the RCU cleanup thread.
+    ///
+    /// The cleanup thread queues them with `call_rcu`, so they are executed after a RCU
+    /// grace period without blocking the cleanup thread.
+    ///
+    /// #### Note
+    ///
+    /// This operation never blocks.
+    pub fn handoff(&mut self) {
+        if self.callbacks.is_empty() {
+            return;
+        }
+
+        let callbacks = std::mem::take(&mut self.callbacks);
+
+        F::rcu_cleanup(Box::new(move |context| {
+            context.rcu_call(RcuCallFn::new(move || Self::execute(callbacks)));
+        }));
+    }
+
+    fn execute(callbacks: Vec<DeferredFn>) {
+        for callback in callbacks {
+            callback();
+        }
+    }
+}
+
+impl<F> Default for RcuDeferQueue<F>
+where
+    F: RcuFlavor,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> Drop for RcuDeferQueue<F>
+where
+    F: RcuFlavor + 'static,
+{
+    fn drop(&mut self) {
+        self.handoff();
+    }
+}
+
+mod asserts {
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::rcu::deferred::RcuDeferQueue;
+
+    assert_impl_all!(RcuDeferQueue<RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuDeferQueue<RcuDefaultFlavor>: Sync);
+}
@@ -0,0 +1,232 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicI16,
+    AtomicI32,
+    AtomicI64,
+    AtomicI8,
+    AtomicIsize,
+    AtomicU16,
+    AtomicU32,
+    AtomicU64,
+    AtomicU8,
+    AtomicUsize,
+    Ordering,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// This trait defines a scalar type that can be stored in a [`RcuField`].
+///
+/// It is implemented for [`bool`] and all primitive integers with native atomics.
+pub trait RcuFieldValue: Copy + private::Sealed {
+    #[doc(hidden)]
+    type Atomic: Send + Sync;
+
+    #[doc(hidden)]
+    fn atomic_new(value: Self) -> Self::Atomic;
+
+    #[doc(hidden)]
+    fn atomic_into_inner(atomic: Self::Atomic) -> Self;
+
+    #[doc(hidden)]
+    fn atomic_load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    #[doc(hidden)]
+    fn atomic_store(atomic: &Self::Atomic, value: Self, order: Ordering);
+
+    #[doc(hidden)]
+    fn atomic_swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self;
+
+    #[doc(hidden)]
+    fn atomic_compare_exchange_weak(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_field_value {
+    ($($value:ty => $atomic:ty),* $(,)?) => {
+        $(
+            impl private::Sealed for $value {}
+
+            impl RcuFieldValue for $value {
+                type Atomic = $atomic;
+
+                fn atomic_new(value: Self) -> Self::Atomic {
+                    <$atomic>::new(value)
+                }
+
+                fn atomic_into_inner(atomic: Self::Atomic) -> Self {
+                    atomic.into_inner()
+                }
+
+                fn atomic_load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+
+                fn atomic_store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                    atomic.store(value, order)
+                }
+
+                fn atomic_swap(atomic: &Self::Atomic, value: Self, order: Ordering) -> Self {
+                    atomic.swap(value, order)
+                }
+
+                fn atomic_compare_exchange_weak(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic.compare_exchange_weak(current, new, success, failure)
+                }
+            }
+        )*
+    };
+}
+
+impl_field_value!(
+    bool => AtomicBool,
+    i8 => AtomicI8,
+    i16 => AtomicI16,
+    i32 => AtomicI32,
+    i64 => AtomicI64,
+    isize => AtomicIsize,
+    u8 => AtomicU8,
+    u16 => AtomicU16,
+    u32 => AtomicU32,
+    u64 => AtomicU64,
+    usize => AtomicUsize,
+);
+
+/// Defines an atomic scalar field inside a RCU protected value.
+///
+/// Values stored in RCU containers can only be borrowed immutably. This type provides
+/// interior mutability for scalar fields without locking, so that readers never block.
+///
+/// #### Ordering
+///
+/// * Loads use [`Ordering::Acquire`].
+/// * Stores use [`Ordering::Release`].
+/// * Read-modify-write operations use [`Ordering::AcqRel`].
+///
+/// A reader observing a stored value also observes every write made by the writer
+/// before the store.
+pub struct RcuField<T: RcuFieldValue>(T::Atomic);
+
+impl<T: RcuFieldValue> RcuField<T> {
+    /// Creates a new field.
+    pub fn new(value: T) -> Self {
+        Self(T::atomic_new(value))
+    }
+
+    /// Returns the current value.
+    pub fn load(&self) -> T {
+        T::atomic_load(&self.0, Ordering::Acquire)
+    }
+
+    /// Stores a new value.
+    pub fn store(&self, value: T) {
+        T::atomic_store(&self.0, value, Ordering::Release)
+    }
+
+    /// Stores a new value, returning the previous value.
+    pub fn swap(&self, value: T) -> T {
+        T::atomic_swap(&self.0, value, Ordering::AcqRel)
+    }
+
+    /// Stores a new value if the current value is `current`.
+    ///
+    /// On success, the previous value is returned. On failure, the current value is returned.
+    pub fn compare_exchange(&self, current: T, new: T) -> Result<T, T>
+    where
+        T: PartialEq,
+    {
+        loop {
+            match T::atomic_compare_exchange_weak(
+                &self.0,
+                current,
+                new,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(previous) => return Ok(previous),
+                Err(actual) if actual == current => continue,
+                Err(actual) => return Err(actual),
+            }
+        }
+    }
+
+    /// Applies a function to the current value, returning the previous value.
+    ///
+    /// #### Note
+    ///
+    /// The function may be called multiple times if the value is concurrently modified.
+    pub fn update<C>(&self, mut func: C) -> T
+    where
+        C: FnMut(T) -> T,
+    {
+        let mut current = self.load();
+
+        loop {
+            match T::atomic_compare_exchange_weak(
+                &self.0,
+                current,
+                func(current),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(previous) => return previous,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Consumes the field, returning the value.
+    pub fn into_inner(self) -> T {
+        T::atomic_into_inner(self.0)
+    }
+}
+
+impl<T> Default for RcuField<T>
+where
+    T: RcuFieldValue + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> From<T> for RcuField<T>
+where
+    T: RcuFieldValue,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T> Debug for RcuField<T>
+where
+    T: RcuFieldValue + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("RcuField").field(&self.load()).finish()
+    }
+}
+
+mod asserts {
+    use static_assertions::assert_impl_all;
+
+    use crate::rcu::field::RcuField;
+
+    assert_impl_all!(RcuField<u64>: Send);
+    assert_impl_all!(RcuField<u64>: Sync);
+}
@@ -1,9 +1,9 @@
-use std::ffi::c_void;
+use std::ffi::{c_int, c_void};
 
 use urcu_sys::{RcuFlavorApi, RcuHead, RcuPollState};
 
 use crate::rcu::builder::RcuContextBuilder;
-use crate::rcu::cleanup::{RcuCleaner, RcuCleanup, RcuCleanupMut};
+use crate::rcu::cleanup::{RcuCleaner, RcuCleanup, RcuCleanupMut, RcuCleanupThread};
 use crate::rcu::context::{RcuContext, RcuDeferContext, RcuReadContext};
 
 /// This trait defines the API from the C library.
@@ -53,12 +53,14 @@ pub trait RcuFlavor {
 
     /// Registers a defer-enabled RCU thread.
     ///
+    /// Returns a non-zero value if the registration failed.
+    ///
     /// #### Safety
     ///
     /// * The thread must be initialized with [`RcuFlavor::unchecked_rcu_init`].
     /// * The thread must not be registered with [`RcuFlavor::unchecked_rcu_defer_register_thread`].
     /// * The thread must unregister with [`RcuFlavor::unchecked_rcu_defer_unregister_thread`].
-    unsafe fn unchecked_rcu_defer_register_thread();
+    unsafe fn unchecked_rcu_defer_register_thread() -> c_int;
 
     /// Unregisters a defer-enabled RCU thread.
     ///
@@ -183,6 +185,29 @@ pub trait RcuFlavor {
     /// * The thread have the same requirements as the other functions when using this API.
     unsafe fn unchecked_rcu_api() -> &'static RcuFlavorApi;
 
+    /// Returns a pointer to the API list for this RCU flavor.
+    ///
+    /// This pointer can be handed to other C libraries using `liburcu` (e.g. a custom
+    /// `liburcu-cds` user) so they share the same RCU flavor as the Rust side.
+    ///
+    /// ```rust
+    /// use urcu::prelude::*;
+    /// use urcu::rcu::RcuFlavorApi;
+    ///
+    /// # unsafe extern "C" fn my_library_init(_api: *const RcuFlavorApi) {}
+    /// // `my_library_init` is provided by the C library.
+    /// unsafe { my_library_init(RcuDefaultFlavor::flavor_api_ptr()) };
+    /// ```
+    ///
+    /// #### Note
+    ///
+    /// The C library using this pointer must respect the same requirements as
+    /// [`RcuFlavor::unchecked_rcu_api`] when calling the API.
+    fn flavor_api_ptr() -> *const RcuFlavorApi {
+        // SAFETY: Creating the pointer does not call any function of the API.
+        unsafe { Self::unchecked_rcu_api() }
+    }
+
     /// Defines the context used in cleanup calls.
     type CleanupContext: RcuContext + RcuReadContext + RcuDeferContext;
 
@@ -208,6 +233,14 @@ pub trait RcuFlavor {
     /// The callback does not receive a mutable context in order to prevent deadlock.
     fn rcu_cleanup_and_block(callback: RcuCleanup<Self::CleanupContext>);
 
+    /// Creates a dedicated cleanup thread for this flavor.
+    ///
+    /// The callbacks sent to this thread are isolated from the ones sent with
+    /// [`RcuFlavor::rcu_cleanup`] and [`RcuFlavor::rcu_cleanup_and_block`].
+    fn rcu_cleanup_thread() -> RcuCleanupThread<Self>
+    where
+        Self: Sized + 'static;
+
     /// Creates a builder for a context of this flavor.
     fn rcu_context_builder() -> RcuContextBuilder<Self>
     where
@@ -224,7 +257,19 @@ macro_rules! urcu_func {
 
 macro_rules! define_flavor {
     ($name:ident, $flavor:ident, $context:ident) => {
-        #[doc = concat!("Defines a RCU flavor (`liburcu-", stringify!($flavor), "`).")]
+        define_flavor!(
+            $name,
+            $flavor,
+            $context,
+            concat!(
+                "Defines a RCU flavor (`liburcu-",
+                stringify!($flavor),
+                "`)."
+            )
+        );
+    };
+    ($name:ident, $flavor:ident, $context:ident, $doc:expr) => {
+        #[doc = $doc]
         pub struct $name;
 
         impl RcuFlavor for $name {
@@ -248,8 +293,8 @@ macro_rules! define_flavor {
                 urcu_func!($flavor, read_unlock)()
             }
 
-            unsafe fn unchecked_rcu_defer_register_thread() {
-                urcu_func!($flavor, defer_register_thread)();
+            unsafe fn unchecked_rcu_defer_register_thread() -> c_int {
+                urcu_func!($flavor, defer_register_thread)()
             }
 
             unsafe fn unchecked_rcu_defer_unregister_thread() {
@@ -316,6 +361,10 @@ macro_rules! define_flavor {
                 RcuCleaner::<Self>::get().send(callback).barrier();
             }
 
+            fn rcu_cleanup_thread() -> RcuCleanupThread<Self> {
+                RcuCleanupThread::new(RcuCleaner::<Self>::context())
+            }
+
             fn rcu_context_builder() -> RcuContextBuilder<Self>
             where
                 Self: Sized,
@@ -467,6 +516,40 @@ pub(crate) mod qsbr {
     define_flavor!(RcuFlavorQsbr, qsbr, RcuContextQsbr);
 }
 
+#[cfg(feature = "flavor-rs")]
+pub(crate) mod rs {
+    use super::*;
+
+    use crate::rcu::context::RcuContextRs;
+    use crate::rcu::native::{
+        urcu_rs_barrier,
+        urcu_rs_call_rcu,
+        urcu_rs_defer_barrier,
+        urcu_rs_defer_rcu,
+        urcu_rs_defer_register_thread,
+        urcu_rs_defer_unregister_thread,
+        urcu_rs_init,
+        urcu_rs_poll_state_synchronize_rcu,
+        urcu_rs_quiescent_state,
+        urcu_rs_read_lock,
+        urcu_rs_read_unlock,
+        urcu_rs_register_thread,
+        urcu_rs_start_poll_synchronize_rcu,
+        urcu_rs_synchronize_rcu,
+        urcu_rs_thread_offline,
+        urcu_rs_thread_online,
+        urcu_rs_unregister_thread,
+        RCU_API,
+    };
+
+    define_flavor!(
+        RcuFlavorRs,
+        rs,
+        RcuContextRs,
+        "Defines a RCU flavor implemented in Rust (QSBR)."
+    );
+}
+
 #[cfg(feature = "flavor-bp")]
 pub use bp::*;
 
@@ -478,3 +561,6 @@ pub use memb::*;
 
 #[cfg(feature = "flavor-qsbr")]
 pub use qsbr::*;
+
+#[cfg(feature = "flavor-rs")]
+pub use rs::*;
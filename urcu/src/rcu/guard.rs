@@ -10,6 +10,51 @@ pub trait RcuGuard {
     type Flavor: RcuFlavor;
 }
 
+/// This trait converts a guard to and from a raw token.
+///
+/// It is separate from [`RcuGuard`] so that guards implemented outside of this crate
+/// are not required to support it.
+pub trait RcuGuardRaw: RcuGuard {
+    /// Converts the guard into a raw token without leaving the RCU critical section.
+    ///
+    /// #### Note
+    ///
+    /// The critical section stays locked until the token is converted back with
+    /// [`RcuGuardRaw::from_raw`] and the resulting guard is dropped.
+    fn into_raw(self) -> RcuRawGuard<Self::Flavor>
+    where
+        Self: Sized;
+
+    /// Converts a raw token back into a guard.
+    ///
+    /// This is useful for code that crosses a FFI boundary and re-enters Rust
+    /// while still inside the same RCU critical section.
+    ///
+    /// #### Safety
+    ///
+    /// * The token must come from [`RcuGuardRaw::into_raw`] on the current thread.
+    /// * The RCU critical section of the token must still be locked.
+    /// * The RCU context of the original guard must outlive the returned guard.
+    unsafe fn from_raw(raw: RcuRawGuard<Self::Flavor>) -> Self
+    where
+        Self: Sized;
+}
+
+/// Defines a raw token for a locked RCU critical section.
+///
+/// #### Note
+///
+/// Dropping the token without converting it back into a guard will leave the
+/// RCU critical section locked forever.
+#[must_use]
+pub struct RcuRawGuard<F>(PhantomData<F>, PhantomUnsend, PhantomUnsync);
+
+impl<F> RcuRawGuard<F> {
+    fn new() -> Self {
+        Self(PhantomData, PhantomData, PhantomData)
+    }
+}
+
 macro_rules! define_rcu_guard {
     ($kind:ident, $guard:ident, $flavor:ident) => {
         #[doc = concat!("Defines a guard for a RCU critical section (`liburcu-", stringify!($kind), "`).")]
@@ -33,6 +78,21 @@ macro_rules! define_rcu_guard {
             type Flavor = $flavor;
         }
 
+        impl<'a> RcuGuardRaw for $guard<'a> {
+            fn into_raw(self) -> RcuRawGuard<Self::Flavor> {
+                // The critical section is unlocked when the token is converted back.
+                std::mem::forget(self);
+
+                RcuRawGuard::new()
+            }
+
+            unsafe fn from_raw(raw: RcuRawGuard<Self::Flavor>) -> Self {
+                let _ = raw;
+
+                Self(PhantomData, PhantomData)
+            }
+        }
+
         impl<'a> Drop for $guard<'a> {
             fn drop(&mut self) {
                 // SAFETY: The thread is initialized at context's creation.
@@ -80,6 +140,15 @@ mod qsbr {
     define_rcu_guard!(qsbr, RcuGuardQsbr, RcuFlavorQsbr);
 }
 
+#[cfg(feature = "flavor-rs")]
+mod rs {
+    use super::*;
+
+    use crate::rcu::flavor::RcuFlavorRs;
+
+    define_rcu_guard!(rs, RcuGuardRs, RcuFlavorRs);
+}
+
 #[cfg(feature = "flavor-bp")]
 pub use bp::*;
 
@@ -92,9 +161,18 @@ pub use memb::*;
 #[cfg(feature = "flavor-qsbr")]
 pub use qsbr::*;
 
+#[cfg(feature = "flavor-rs")]
+pub use rs::*;
+
 mod asserts {
     use static_assertions::assert_not_impl_all;
 
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::rcu::guard::RcuRawGuard;
+
+    assert_not_impl_all!(RcuRawGuard<RcuDefaultFlavor>: Send);
+    assert_not_impl_all!(RcuRawGuard<RcuDefaultFlavor>: Sync);
+
     #[cfg(feature = "flavor-bp")]
     mod bp {
         use super::*;
@@ -134,4 +212,14 @@ mod asserts {
         assert_not_impl_all!(RcuGuardQsbr: Send);
         assert_not_impl_all!(RcuGuardQsbr: Sync);
     }
+
+    #[cfg(feature = "flavor-rs")]
+    mod rs {
+        use super::*;
+
+        use crate::rcu::guard::RcuGuardRs;
+
+        assert_not_impl_all!(RcuGuardRs: Send);
+        assert_not_impl_all!(RcuGuardRs: Sync);
+    }
 }
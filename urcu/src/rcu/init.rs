@@ -0,0 +1,224 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::rcu::callback::RcuCallFn;
+use crate::rcu::cleanup::RcuCleaner;
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::flavor::RcuFlavor;
+
+const FLAVOR_BP: u8 = 1 << 0;
+const FLAVOR_MB: u8 = 1 << 1;
+const FLAVOR_MEMB: u8 = 1 << 2;
+const FLAVOR_QSBR: u8 = 1 << 3;
+const FLAVOR_RS: u8 = 1 << 4;
+
+/// Defines a set of RCU flavors to initialize with [`init`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlavorSet(u8);
+
+impl FlavorSet {
+    /// Defines an empty set.
+    pub const NONE: Self = Self(0);
+
+    /// Defines the `liburcu-bp` flavor.
+    #[cfg(feature = "flavor-bp")]
+    pub const BP: Self = Self(FLAVOR_BP);
+
+    /// Defines the `liburcu-mb` flavor.
+    #[cfg(feature = "flavor-mb")]
+    pub const MB: Self = Self(FLAVOR_MB);
+
+    /// Defines the `liburcu-memb` flavor.
+    #[cfg(feature = "flavor-memb")]
+    pub const MEMB: Self = Self(FLAVOR_MEMB);
+
+    /// Defines the `liburcu-qsbr` flavor.
+    #[cfg(feature = "flavor-qsbr")]
+    pub const QSBR: Self = Self(FLAVOR_QSBR);
+
+    /// Defines the pure Rust flavor.
+    #[cfg(feature = "flavor-rs")]
+    pub const RS: Self = Self(FLAVOR_RS);
+
+    /// Defines the default flavor.
+    pub const DEFAULT: Self = if cfg!(feature = "flavor-memb") {
+        Self(FLAVOR_MEMB)
+    } else if cfg!(feature = "flavor-mb") {
+        Self(FLAVOR_MB)
+    } else if cfg!(feature = "flavor-bp") {
+        Self(FLAVOR_BP)
+    } else if cfg!(feature = "flavor-qsbr") {
+        Self(FLAVOR_QSBR)
+    } else {
+        Self(FLAVOR_RS)
+    };
+
+    /// Defines every enabled flavor.
+    pub const ALL: Self = Self(
+        (if cfg!(feature = "flavor-bp") {
+            FLAVOR_BP
+        } else {
+            0
+        }) | (if cfg!(feature = "flavor-mb") {
+            FLAVOR_MB
+        } else {
+            0
+        }) | (if cfg!(feature = "flavor-memb") {
+            FLAVOR_MEMB
+        } else {
+            0
+        }) | (if cfg!(feature = "flavor-qsbr") {
+            FLAVOR_QSBR
+        } else {
+            0
+        }) | (if cfg!(feature = "flavor-rs") {
+            FLAVOR_RS
+        } else {
+            0
+        }),
+    );
+
+    /// Returns the union of both sets.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns `true` if every flavor of `other` is in the set.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Default for FlavorSet {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl BitOr for FlavorSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for FlavorSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl Debug for FlavorSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (FLAVOR_BP, "bp"),
+            (FLAVOR_MB, "mb"),
+            (FLAVOR_MEMB, "memb"),
+            (FLAVOR_QSBR, "qsbr"),
+            (FLAVOR_RS, "rs"),
+        ];
+
+        f.debug_set()
+            .entries(
+                names
+                    .iter()
+                    .filter(|(flavor, _)| self.0 & flavor != 0)
+                    .map(|(_, name)| name),
+            )
+            .finish()
+    }
+}
+
+/// Eagerly initializes the RCU flavors.
+///
+/// For every flavor of the set, the shared RCU cleanup thread is spawned and registered,
+/// and its `call_rcu` worker is started. This function blocks until all of them are
+/// ready, so that the first [`RcuRef`] cleanup in a latency-critical path does not pay
+/// for the thread creation and registration.
+///
+/// #### Note
+///
+/// Calling this function is optional and calling it more than once is harmless. The
+/// helper threads are otherwise lazily started on first use.
+///
+/// [`RcuRef`]: crate::rcu::reference::RcuRef
+pub fn init(flavors: FlavorSet) {
+    #[cfg(feature = "flavor-bp")]
+    if flavors.contains(FlavorSet::BP) {
+        init_flavor::<crate::rcu::flavor::RcuFlavorBp>();
+    }
+
+    #[cfg(feature = "flavor-mb")]
+    if flavors.contains(FlavorSet::MB) {
+        init_flavor::<crate::rcu::flavor::RcuFlavorMb>();
+    }
+
+    #[cfg(feature = "flavor-memb")]
+    if flavors.contains(FlavorSet::MEMB) {
+        init_flavor::<crate::rcu::flavor::RcuFlavorMemb>();
+    }
+
+    #[cfg(feature = "flavor-qsbr")]
+    if flavors.contains(FlavorSet::QSBR) {
+        init_flavor::<crate::rcu::flavor::RcuFlavorQsbr>();
+    }
+
+    #[cfg(feature = "flavor-rs")]
+    if flavors.contains(FlavorSet::RS) {
+        init_flavor::<crate::rcu::flavor::RcuFlavorRs>();
+    }
+}
+
+fn init_flavor<F>()
+where
+    F: RcuFlavor + 'static,
+{
+    log::debug!("initializing RCU flavor {}", std::any::type_name::<F>());
+
+    // The cleanup thread is registered before executing its first callback.
+    F::rcu_cleanup_and_block(Box::new(|context| {
+        // The first call spawns the `call_rcu` worker of the flavor.
+        context.rcu_call(RcuCallFn::new(|| {}));
+    }));
+}
+
+/// Flushes and stops the RCU helper threads of every enabled flavor.
+///
+/// For every flavor with a running cleanup thread, the thread runs the `defer_rcu` and
+/// `call_rcu` barriers of the flavor, executes the pending cleanup callbacks and exits.
+/// This function blocks until all of them are joined.
+///
+/// It is useful for a deterministic teardown, e.g. before unloading a plugin or to get
+/// clean reports from memory checkers.
+///
+/// #### Note
+///
+/// The callbacks deferred by other threads are only flushed when their contexts are
+/// dropped. The dedicated cleanup threads of the containers are not affected.
+///
+/// The helper threads are lazily started again if they are used after this call.
+///
+/// The barriers wait for RCU grace periods, so a QSBR thread must be offline when calling
+/// this function. It must not be called from a cleanup callback.
+pub fn shutdown() {
+    #[cfg(feature = "flavor-bp")]
+    RcuCleaner::<crate::rcu::flavor::RcuFlavorBp>::shutdown();
+
+    #[cfg(feature = "flavor-mb")]
+    RcuCleaner::<crate::rcu::flavor::RcuFlavorMb>::shutdown();
+
+    #[cfg(feature = "flavor-memb")]
+    RcuCleaner::<crate::rcu::flavor::RcuFlavorMemb>::shutdown();
+
+    #[cfg(feature = "flavor-qsbr")]
+    RcuCleaner::<crate::rcu::flavor::RcuFlavorQsbr>::shutdown();
+
+    #[cfg(feature = "flavor-rs")]
+    RcuCleaner::<crate::rcu::flavor::RcuFlavorRs>::shutdown();
+}
@@ -0,0 +1,119 @@
+//! This module implements reclamation metrics.
+//!
+//! When the `reclamation-metrics` feature is enabled, the time between the removal
+//! of an element from a container and the reclamation of its memory is recorded in
+//! a global histogram. Otherwise, the recording compiles down to nothing.
+
+#[cfg(feature = "reclamation-metrics")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "reclamation-metrics")]
+use std::time::{Duration, Instant};
+
+/// Defines the moment an element was removed from a container.
+#[derive(Clone, Copy)]
+pub(crate) struct RcuRemoval {
+    #[cfg(feature = "reclamation-metrics")]
+    instant: Instant,
+}
+
+impl RcuRemoval {
+    pub(crate) fn now() -> Self {
+        Self {
+            #[cfg(feature = "reclamation-metrics")]
+            instant: Instant::now(),
+        }
+    }
+
+    /// Records that the memory of the removed element is reclaimed.
+    pub(crate) fn reclaimed(self) {
+        #[cfg(feature = "reclamation-metrics")]
+        HISTOGRAM.record(self.instant.elapsed());
+    }
+}
+
+/// Defines the amount of buckets in a [`RcuReclamationHistogram`].
+#[cfg(feature = "reclamation-metrics")]
+pub const RCU_RECLAMATION_BUCKETS: usize = 64;
+
+#[cfg(feature = "reclamation-metrics")]
+struct AtomicHistogram([AtomicU64; RCU_RECLAMATION_BUCKETS]);
+
+#[cfg(feature = "reclamation-metrics")]
+impl AtomicHistogram {
+    const fn new() -> Self {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const ZERO: AtomicU64 = AtomicU64::new(0);
+
+        Self([ZERO; RCU_RECLAMATION_BUCKETS])
+    }
+
+    fn record(&self, latency: Duration) {
+        let nanos = u64::try_from(latency.as_nanos()).unwrap_or(u64::MAX);
+        let bucket = (u64::BITS - nanos.leading_zeros()).saturating_sub(1) as usize;
+
+        self.0[bucket].fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "reclamation-metrics")]
+static HISTOGRAM: AtomicHistogram = AtomicHistogram::new();
+
+/// Defines a snapshot of the reclamation latency histogram.
+///
+/// The bucket `i` counts the reclamations that took between `2^i` and `2^(i+1)`
+/// nanoseconds, except the bucket `0` which also counts instant reclamations.
+#[cfg(feature = "reclamation-metrics")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RcuReclamationHistogram {
+    buckets: [u64; RCU_RECLAMATION_BUCKETS],
+}
+
+#[cfg(feature = "reclamation-metrics")]
+impl RcuReclamationHistogram {
+    /// Takes a snapshot of the global reclamation latency histogram.
+    pub fn snapshot() -> Self {
+        Self {
+            buckets: std::array::from_fn(|i| HISTOGRAM.0[i].load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Takes a snapshot of the global reclamation latency histogram and resets it.
+    pub fn take() -> Self {
+        Self {
+            buckets: std::array::from_fn(|i| HISTOGRAM.0[i].swap(0, Ordering::Relaxed)),
+        }
+    }
+
+    /// Returns the amount of reclamations for each bucket.
+    pub fn buckets(&self) -> &[u64; RCU_RECLAMATION_BUCKETS] {
+        &self.buckets
+    }
+
+    /// Returns the total amount of recorded reclamations.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// Returns an upper bound of the latency below which `quantile` of the
+    /// reclamations were executed.
+    ///
+    /// #### Note
+    ///
+    /// The `quantile` is clamped between `0.0` and `1.0`. If no reclamation was
+    /// recorded, [`None`] is returned.
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+
+        let target = ((count as f64) * quantile.clamp(0.0, 1.0)).ceil().max(1.0) as u64;
+        let mut total = 0;
+
+        self.buckets.iter().enumerate().find_map(|(i, amount)| {
+            total += amount;
+            (total >= target)
+                .then(|| Duration::from_nanos(1u64.checked_shl(i as u32 + 1).unwrap_or(u64::MAX)))
+        })
+    }
+}
@@ -2,17 +2,40 @@
 
 pub(crate) mod builder;
 pub(crate) mod callback;
+pub(crate) mod capability;
 pub(crate) mod cleanup;
 pub(crate) mod context;
+pub(crate) mod deferred;
+pub(crate) mod field;
 pub(crate) mod flavor;
 pub(crate) mod guard;
+pub(crate) mod init;
+pub(crate) mod metrics;
+#[cfg(feature = "flavor-rs")]
+pub(crate) mod native;
 pub(crate) mod poller;
 pub(crate) mod reference;
+pub(crate) mod tracker;
 
+#[cfg(test)]
+mod test;
+
+pub use crate::rcu::builder::RegisterError;
 pub use crate::rcu::callback::{RcuCall, RcuCallFn, RcuDefer, RcuDeferFn};
+pub use crate::rcu::capability::{RcuDeferCapability, RcuReadCapability};
+pub use crate::rcu::cleanup::RcuCleanupThread;
 pub use crate::rcu::context::RcuOfflineContext;
+pub use crate::rcu::deferred::RcuDeferQueue;
+pub use crate::rcu::field::{RcuField, RcuFieldValue};
+pub use crate::rcu::guard::{RcuGuardRaw, RcuRawGuard};
+pub use crate::rcu::init::FlavorSet;
+#[cfg(feature = "reclamation-metrics")]
+pub use crate::rcu::metrics::{RcuReclamationHistogram, RCU_RECLAMATION_BUCKETS};
+pub use crate::rcu::poller::RcuPollHandle;
 pub use crate::rcu::reference::RcuRefBox;
 
+pub use urcu_sys::RcuFlavorApi;
+
 /// Returns an immutable RCU-protected pointer.
 ///
 /// > It does not actually dereference the pointer, instead, it protects the pointer
@@ -24,7 +47,7 @@ pub use crate::rcu::reference::RcuRefBox;
 /// * The thread must be inside a RCU critical section.
 pub unsafe fn dereference<T>(pointer: *const T) -> *const T {
     // SAFETY: It is safe to cast the pointer to a void*.
-    unsafe { urcu_sys::rcu_dereference(pointer as *mut std::ffi::c_void) as *const T }
+    unsafe { protect(pointer as *mut std::ffi::c_void) as *const T }
 }
 
 /// Returns a mutable RCU-protected pointer.
@@ -38,7 +61,30 @@ pub unsafe fn dereference<T>(pointer: *const T) -> *const T {
 /// * The thread must be inside a RCU critical section.
 pub unsafe fn dereference_mut<T>(pointer: *mut T) -> *mut T {
     // SAFETY: It is safe to cast the pointer to a void*.
-    unsafe { urcu_sys::rcu_dereference(pointer as *mut std::ffi::c_void) as *mut T }
+    unsafe { protect(pointer as *mut std::ffi::c_void) as *mut T }
+}
+
+#[cfg(any(
+    feature = "flavor-bp",
+    feature = "flavor-mb",
+    feature = "flavor-memb",
+    feature = "flavor-qsbr"
+))]
+unsafe fn protect(pointer: *mut std::ffi::c_void) -> *mut std::ffi::c_void {
+    // SAFETY: The caller enforces the RCU critical section.
+    unsafe { urcu_sys::rcu_dereference(pointer) }
+}
+
+/// The `liburcu` library is not linked when only the Rust flavor is enabled.
+#[cfg(not(any(
+    feature = "flavor-bp",
+    feature = "flavor-mb",
+    feature = "flavor-memb",
+    feature = "flavor-qsbr"
+)))]
+unsafe fn protect(pointer: *mut std::ffi::c_void) -> *mut std::ffi::c_void {
+    std::sync::atomic::fence(std::sync::atomic::Ordering::Acquire);
+    pointer
 }
 
 /// Defines flavor-specific types for `liburcu-bp`.
@@ -77,6 +123,15 @@ pub mod qsbr {
     pub use crate::rcu::poller::RcuPollerQsbr;
 }
 
+/// Defines flavor-specific types for the pure Rust flavor.
+#[cfg(feature = "flavor-rs")]
+pub mod rs {
+    pub use crate::rcu::context::RcuContextRs;
+    pub use crate::rcu::flavor::RcuFlavorRs;
+    pub use crate::rcu::guard::RcuGuardRs;
+    pub use crate::rcu::poller::RcuPollerRs;
+}
+
 /// Defines flavor-specific types for the default flavor.
 pub mod default {
     #[cfg(feature = "flavor-memb")]
@@ -152,6 +207,28 @@ pub mod default {
             crate::rcu::context::RcuContextQsbr<READ, DEFER>;
     }
 
+    #[cfg(all(
+        not(feature = "flavor-memb"),
+        not(feature = "flavor-mb"),
+        not(feature = "flavor-bp"),
+        not(feature = "flavor-qsbr"),
+        feature = "flavor-rs"
+    ))]
+    mod rs {
+        /// Defines the default RCU flavor.
+        pub type RcuDefaultFlavor = crate::rcu::flavor::RcuFlavorRs;
+
+        /// Defines the default RCU guard.
+        pub type RcuDefaultGuard<'a> = crate::rcu::guard::RcuGuardRs<'a>;
+
+        /// Defines the default RCU poller.
+        pub type RcuDefaultPoller<'a> = crate::rcu::poller::RcuPollerRs<'a>;
+
+        /// Defines the default RCU context.
+        pub type RcuDefaultContext<const READ: bool = false, const DEFER: bool = false> =
+            crate::rcu::context::RcuContextRs<READ, DEFER>;
+    }
+
     #[cfg(feature = "flavor-memb")]
     pub use memb::*;
 
@@ -172,4 +249,13 @@ pub mod default {
         feature = "flavor-qsbr"
     ))]
     pub use qsbr::*;
+
+    #[cfg(all(
+        not(feature = "flavor-memb"),
+        not(feature = "flavor-mb"),
+        not(feature = "flavor-bp"),
+        not(feature = "flavor-qsbr"),
+        feature = "flavor-rs"
+    ))]
+    pub use rs::*;
 }
@@ -0,0 +1,391 @@
+//! Pure Rust implementation of a QSBR RCU flavor.
+//!
+//! The functions mirror the API of the `liburcu` flavor libraries so that the flavor
+//! can be defined with the same macros as the C flavors.
+//!
+//! * Each registered thread owns a counter holding the last grace period it observed.
+//! * A grace period increments a global counter and waits for every online thread to
+//!   report a quiescent state past it.
+//! * Callbacks from `call_rcu` are executed in batches by a lazily spawned worker thread.
+//! * Callbacks from `defer_rcu` are queued per thread and flushed on a barrier.
+
+use std::cell::{Cell, RefCell};
+use std::ffi::{c_int, c_ulong, c_void};
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+use urcu_sys::{RcuAtFork, RcuFlavorApi, RcuHead, RcuPollState};
+
+type CallFn = unsafe extern "C" fn(*mut RcuHead);
+type DeferFn = unsafe extern "C" fn(*mut c_void);
+
+/// Defines the counter value of an offline thread.
+const OFFLINE: u64 = 0;
+
+/// Defines the amount of deferred callbacks before a thread flushes its queue.
+const DEFER_THRESHOLD: usize = 4096;
+
+/// Defines the amount of busy-waiting iterations before yielding.
+const SPIN_LIMIT: u32 = 128;
+
+/// Defines the amount of iterations before sleeping.
+const YIELD_LIMIT: u32 = 1024;
+
+/// Defines the current grace period.
+static GRACE_PERIOD: AtomicU64 = AtomicU64::new(OFFLINE + 1);
+
+/// Defines the last completed grace period.
+static COMPLETED: AtomicU64 = AtomicU64::new(OFFLINE);
+
+/// Serializes the grace periods.
+static WRITERS: Mutex<()> = Mutex::new(());
+
+/// Defines the counters of the registered threads.
+static READERS: Mutex<Vec<Arc<AtomicU64>>> = Mutex::new(Vec::new());
+
+/// Defines the channel to the `call_rcu` worker thread.
+static WORKER: OnceLock<Sender<Command>> = OnceLock::new();
+
+thread_local! {
+    static READER: RefCell<Option<Arc<AtomicU64>>> = const { RefCell::new(None) };
+    static NESTING: Cell<usize> = const { Cell::new(0) };
+    static DEFERRED: RefCell<Vec<(DeferFn, *mut c_void)>> = const { RefCell::new(Vec::new()) };
+}
+
+enum Command {
+    Call(CallFn, *mut RcuHead),
+    Barrier(Sender<()>),
+    Synchronize,
+}
+
+/// #### Safety
+///
+/// The callers of `call_rcu` guarantee that the heads can be reclaimed by any thread.
+unsafe impl Send for Command {}
+
+fn online(counter: &AtomicU64) {
+    counter.store(GRACE_PERIOD.load(Ordering::SeqCst), Ordering::SeqCst);
+    fence(Ordering::SeqCst);
+}
+
+fn offline(counter: &AtomicU64) {
+    fence(Ordering::SeqCst);
+    counter.store(OFFLINE, Ordering::SeqCst);
+}
+
+fn current() -> Option<Arc<AtomicU64>> {
+    READER.with_borrow(Clone::clone)
+}
+
+/// Executes a blocking function while the current thread is offline.
+fn while_offline<R>(func: impl FnOnce() -> R) -> R {
+    let reader = current().filter(|counter| counter.load(Ordering::SeqCst) != OFFLINE);
+
+    if let Some(counter) = &reader {
+        offline(counter);
+    }
+
+    let value = func();
+
+    if let Some(counter) = &reader {
+        online(counter);
+    }
+
+    value
+}
+
+fn wait_for_reader(counter: &AtomicU64, target: u64) {
+    let mut attempts = 0u32;
+
+    loop {
+        let value = counter.load(Ordering::SeqCst);
+        if value == OFFLINE || value >= target {
+            return;
+        }
+
+        if attempts < SPIN_LIMIT {
+            std::hint::spin_loop();
+        } else if attempts < SPIN_LIMIT + YIELD_LIMIT {
+            std::thread::yield_now();
+        } else {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        attempts = attempts.saturating_add(1);
+    }
+}
+
+/// Waits for a grace period, the current thread must be offline.
+fn wait_grace_period() {
+    let _writer = WRITERS.lock().unwrap_or_else(PoisonError::into_inner);
+
+    fence(Ordering::SeqCst);
+    let target = GRACE_PERIOD.fetch_add(1, Ordering::SeqCst) + 1;
+    fence(Ordering::SeqCst);
+
+    let readers = READERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    for counter in &readers {
+        wait_for_reader(counter, target);
+    }
+
+    fence(Ordering::SeqCst);
+    COMPLETED.store(target, Ordering::Release);
+}
+
+fn worker() -> &'static Sender<Command> {
+    WORKER.get_or_init(|| {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        std::thread::Builder::new()
+            .name(String::from("urcu-rs-call"))
+            .spawn(move || call_rcu_worker(receiver))
+            .expect("failed to spawn RCU callback thread");
+
+        sender
+    })
+}
+
+fn call_rcu_worker(receiver: Receiver<Command>) {
+    // SAFETY: The worker thread is never unregistered.
+    unsafe { urcu_rs_register_thread() };
+
+    let counter = current().unwrap();
+    offline(&counter);
+
+    while let Ok(command) = receiver.recv() {
+        let commands = std::iter::once(command)
+            .chain(receiver.try_iter())
+            .collect::<Vec<_>>();
+
+        wait_grace_period();
+        online(&counter);
+
+        for command in commands {
+            match command {
+                // SAFETY: A grace period elapsed since the callback was queued.
+                Command::Call(func, head) => unsafe { func(head) },
+                Command::Barrier(sender) => {
+                    let _ = sender.send(());
+                }
+                Command::Synchronize => {}
+            }
+        }
+
+        offline(&counter);
+    }
+}
+
+/// #### Safety
+///
+/// Always safe to call, provided for parity with the C flavors.
+pub(crate) unsafe extern "C" fn urcu_rs_init() {}
+
+/// #### Safety
+///
+/// The thread must not be registered.
+pub(crate) unsafe extern "C" fn urcu_rs_register_thread() {
+    let counter = Arc::new(AtomicU64::new(OFFLINE));
+
+    READERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(counter.clone());
+
+    online(&counter);
+    READER.set(Some(counter));
+}
+
+/// #### Safety
+///
+/// The thread must not be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_unregister_thread() {
+    if let Some(counter) = READER.take() {
+        offline(&counter);
+
+        READERS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|reader| !Arc::ptr_eq(reader, &counter));
+    }
+}
+
+/// #### Safety
+///
+/// The thread must be registered.
+pub(crate) unsafe extern "C" fn urcu_rs_read_lock() {
+    NESTING.set(NESTING.get() + 1);
+}
+
+/// #### Safety
+///
+/// The thread must be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_read_unlock() {
+    NESTING.set(NESTING.get() - 1);
+}
+
+/// #### Safety
+///
+/// Always safe to call.
+pub(crate) unsafe extern "C" fn urcu_rs_read_ongoing() -> c_int {
+    c_int::from(NESTING.get() > 0)
+}
+
+/// #### Safety
+///
+/// The thread must not hold references to RCU protected data.
+pub(crate) unsafe extern "C" fn urcu_rs_quiescent_state() {
+    if let Some(counter) = current() {
+        fence(Ordering::SeqCst);
+        online(&counter);
+    }
+}
+
+/// #### Safety
+///
+/// The thread must not hold references to RCU protected data.
+pub(crate) unsafe extern "C" fn urcu_rs_thread_offline() {
+    if let Some(counter) = current() {
+        offline(&counter);
+    }
+}
+
+/// #### Safety
+///
+/// The thread must be registered.
+pub(crate) unsafe extern "C" fn urcu_rs_thread_online() {
+    if let Some(counter) = current() {
+        online(&counter);
+    }
+}
+
+/// #### Safety
+///
+/// The thread must not be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_synchronize_rcu() {
+    while_offline(wait_grace_period);
+}
+
+/// #### Safety
+///
+/// The callback must be safe to call from another thread after a grace period.
+pub(crate) unsafe extern "C" fn urcu_rs_call_rcu(head: *mut RcuHead, func: Option<CallFn>) {
+    if let Some(func) = func {
+        let _ = worker().send(Command::Call(func, head));
+    }
+}
+
+/// #### Safety
+///
+/// The thread must not be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_barrier() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    if worker().send(Command::Barrier(sender)).is_ok() {
+        while_offline(|| receiver.recv().ok());
+    }
+}
+
+/// #### Safety
+///
+/// The thread must not be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_defer_rcu(fct: Option<DeferFn>, p: *mut c_void) {
+    let Some(fct) = fct else {
+        return;
+    };
+
+    let full = DEFERRED.with_borrow_mut(|queue| {
+        queue.push((fct, p));
+        queue.len() >= DEFER_THRESHOLD
+    });
+
+    if full {
+        urcu_rs_defer_barrier();
+    }
+}
+
+/// #### Safety
+///
+/// The thread must not be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_defer_barrier() {
+    let callbacks = DEFERRED.with_borrow_mut(std::mem::take);
+    if callbacks.is_empty() {
+        return;
+    }
+
+    urcu_rs_synchronize_rcu();
+
+    for (fct, p) in callbacks {
+        fct(p);
+    }
+}
+
+/// #### Safety
+///
+/// Always safe to call, provided for parity with the C flavors.
+pub(crate) unsafe extern "C" fn urcu_rs_defer_register_thread() -> c_int {
+    0
+}
+
+/// #### Safety
+///
+/// The thread must not be in a RCU critical section.
+pub(crate) unsafe extern "C" fn urcu_rs_defer_unregister_thread() {
+    urcu_rs_defer_barrier();
+}
+
+/// #### Safety
+///
+/// Always safe to call.
+pub(crate) unsafe extern "C" fn urcu_rs_start_poll_synchronize_rcu() -> RcuPollState {
+    fence(Ordering::SeqCst);
+    let target = GRACE_PERIOD.load(Ordering::SeqCst) + 1;
+    let _ = worker().send(Command::Synchronize);
+
+    // SAFETY: `urcu_gp_poll_state` only wraps an `unsigned long`.
+    unsafe { std::mem::transmute::<c_ulong, RcuPollState>(target as c_ulong) }
+}
+
+/// #### Safety
+///
+/// The state must come from [`urcu_rs_start_poll_synchronize_rcu`].
+pub(crate) unsafe extern "C" fn urcu_rs_poll_state_synchronize_rcu(state: RcuPollState) -> bool {
+    // SAFETY: `urcu_gp_poll_state` only wraps an `unsigned long`.
+    let target = unsafe { std::mem::transmute::<RcuPollState, c_ulong>(state) };
+
+    COMPLETED.load(Ordering::Acquire) >= target as u64
+}
+
+/// #### Safety
+///
+/// Always safe to call, the flavor has no fork handlers.
+pub(crate) unsafe extern "C" fn urcu_rs_register_rculfhash_atfork(_atfork: *mut RcuAtFork) {}
+
+/// #### Safety
+///
+/// Always safe to call, the flavor has no fork handlers.
+pub(crate) unsafe extern "C" fn urcu_rs_unregister_rculfhash_atfork(_atfork: *mut RcuAtFork) {}
+
+pub(crate) static RCU_API: RcuFlavorApi = RcuFlavorApi {
+    read_lock: Some(urcu_rs_read_lock),
+    read_unlock: Some(urcu_rs_read_unlock),
+    read_ongoing: Some(urcu_rs_read_ongoing),
+    read_quiescent_state: Some(urcu_rs_quiescent_state),
+    update_call_rcu: Some(urcu_rs_call_rcu),
+    update_synchronize_rcu: Some(urcu_rs_synchronize_rcu),
+    update_defer_rcu: Some(urcu_rs_defer_rcu),
+    thread_offline: Some(urcu_rs_thread_offline),
+    thread_online: Some(urcu_rs_thread_online),
+    register_thread: Some(urcu_rs_register_thread),
+    unregister_thread: Some(urcu_rs_unregister_thread),
+    barrier: Some(urcu_rs_barrier),
+    register_rculfhash_atfork: Some(urcu_rs_register_rculfhash_atfork),
+    unregister_rculfhash_atfork: Some(urcu_rs_unregister_rculfhash_atfork),
+    update_start_poll_synchronize_rcu: Some(urcu_rs_start_poll_synchronize_rcu),
+    update_poll_state_synchronize_rcu: Some(urcu_rs_poll_state_synchronize_rcu),
+};
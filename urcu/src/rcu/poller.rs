@@ -10,6 +10,41 @@ pub trait RcuPoller {
     fn grace_period_finished(&self) -> bool;
 }
 
+/// Defines a grace period poll state that can be sent to another thread.
+///
+/// It is obtained from [`RcuContext::rcu_poll_handle`]. Unlike a poller, it can be
+/// checked by any registered thread of the same flavor, so one thread can start the
+/// poll and another thread can check its completion.
+#[derive(Clone, Copy)]
+pub struct RcuPollHandle<F> {
+    state: urcu_sys::RcuPollState,
+    _flavor: PhantomData<F>,
+}
+
+impl<F> RcuPollHandle<F>
+where
+    F: RcuFlavor,
+{
+    pub(crate) fn new(state: urcu_sys::RcuPollState) -> Self {
+        Self {
+            state,
+            _flavor: PhantomData,
+        }
+    }
+
+    /// Checks if the grace period is over for this handle.
+    pub fn grace_period_finished<C>(&self, context: &C) -> bool
+    where
+        C: RcuContext<Flavor = F>,
+    {
+        let _ = context;
+
+        // SAFETY: The thread is initialized at context's creation.
+        // SAFETY: The state is created by a context of the same flavor.
+        unsafe { F::unchecked_rcu_poll_check(self.state) }
+    }
+}
+
 macro_rules! define_rcu_poller {
     ($kind:ident, $poller:ident, $flavor:ident) => {
         #[doc = concat!("Defines a grace period poller (`liburcu-", stringify!($kind), "`).")]
@@ -79,6 +114,15 @@ mod qsbr {
     define_rcu_poller!(qsbr, RcuPollerQsbr, RcuFlavorQsbr);
 }
 
+#[cfg(feature = "flavor-rs")]
+mod rs {
+    use super::*;
+
+    use crate::rcu::flavor::RcuFlavorRs;
+
+    define_rcu_poller!(rs, RcuPollerRs, RcuFlavorRs);
+}
+
 #[cfg(feature = "flavor-bp")]
 pub use bp::*;
 
@@ -91,8 +135,17 @@ pub use memb::*;
 #[cfg(feature = "flavor-qsbr")]
 pub use qsbr::*;
 
+#[cfg(feature = "flavor-rs")]
+pub use rs::*;
+
 mod asserts {
-    use static_assertions::assert_not_impl_all;
+    use static_assertions::{assert_impl_all, assert_not_impl_all};
+
+    use crate::rcu::default::RcuDefaultFlavor;
+    use crate::rcu::poller::RcuPollHandle;
+
+    assert_impl_all!(RcuPollHandle<RcuDefaultFlavor>: Send);
+    assert_impl_all!(RcuPollHandle<RcuDefaultFlavor>: Sync);
 
     #[cfg(feature = "flavor-bp")]
     mod bp {
@@ -133,4 +186,14 @@ mod asserts {
         assert_not_impl_all!(RcuPollerQsbr: Send);
         assert_not_impl_all!(RcuPollerQsbr: Sync);
     }
+
+    #[cfg(feature = "flavor-rs")]
+    mod rs {
+        use super::*;
+
+        use crate::rcu::poller::RcuPollerRs;
+
+        assert_not_impl_all!(RcuPollerRs: Send);
+        assert_not_impl_all!(RcuPollerRs: Sync);
+    }
 }
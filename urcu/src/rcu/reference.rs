@@ -1,10 +1,15 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
 use std::ptr::NonNull;
 
 use crate::rcu::callback::{RcuCallFn, RcuDeferFn};
 use crate::rcu::context::{RcuContext, RcuDeferContext, RcuReadContext};
 use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::metrics::RcuRemoval;
+use crate::rcu::tracker::{RcuRefTrack, RcuRefTracker};
 use crate::utility::*;
 
 /// This trait defines a RCU reference that can be owned after a RCU grace period.
@@ -185,6 +190,27 @@ impl_rcu_ref_for_tuple!(0, 1, 2, 3, 4, 5, 6);
 /// An owned RCU reference to a element removed from a container.
 pub struct BoxRefOwned<T>(Box<T>);
 
+impl<T> BoxRefOwned<T> {
+    pub(crate) fn into_box(self) -> Box<T> {
+        self.0
+    }
+
+    pub(crate) fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns a pinned mutable reference to the element.
+    ///
+    /// The element is never moved out of the reference, it is dropped in place.
+    pub fn as_pin_mut(&mut self) -> Pin<&mut T::Target>
+    where
+        T: DerefMut,
+    {
+        // SAFETY: The element is boxed and never moved until it is dropped.
+        unsafe { Pin::new_unchecked(self.0.deref_mut().deref_mut()) }
+    }
+}
+
 impl<T> Deref for BoxRefOwned<T>
 where
     T: Deref,
@@ -196,6 +222,53 @@ where
     }
 }
 
+impl<T> PartialEq for BoxRefOwned<T>
+where
+    T: Deref,
+    T::Target: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.deref() == other.deref()
+    }
+}
+
+impl<T> Eq for BoxRefOwned<T>
+where
+    T: Deref,
+    T::Target: Eq,
+{
+}
+
+impl<T> PartialOrd for BoxRefOwned<T>
+where
+    T: Deref,
+    T::Target: PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<T> Ord for BoxRefOwned<T>
+where
+    T: Deref,
+    T::Target: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<T> Hash for BoxRefOwned<T>
+where
+    T: Deref,
+    T::Target: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
 /// #### Safety
 ///
 /// It is safe to send to another thread if the underlying `T` is `Send`.
@@ -213,6 +286,8 @@ where
     F: RcuFlavor + 'static,
 {
     ptr: *mut T,
+    removal: RcuRemoval,
+    track: RcuRefTrack,
     _unsend: PhantomUnsend<(T, F)>,
     _unsync: PhantomUnsync<(T, F)>,
 }
@@ -225,10 +300,18 @@ where
     pub(crate) fn new(ptr: NonNull<T>) -> Self {
         Self {
             ptr: ptr.as_ptr(),
+            removal: RcuRemoval::now(),
+            track: RcuRefTrack::default(),
             _unsend: PhantomData,
             _unsync: PhantomData,
         }
     }
+
+    /// Accounts the reference in the tracker of the container that produced it.
+    pub(crate) fn tracked(mut self, tracker: &RcuRefTracker) -> Self {
+        self.track = tracker.track();
+        self
+    }
 }
 
 /// #### Safety
@@ -249,6 +332,8 @@ where
 
         // SAFETY: We don't want to cleanup when dropping `self`.
         self.ptr = std::ptr::null_mut();
+        self.removal.reclaimed();
+        self.track.release();
 
         output
     }
@@ -271,7 +356,14 @@ where
 {
     fn drop(&mut self) {
         if let Some(ptr) = NonNull::new(self.ptr) {
-            Self::new(ptr).safe_cleanup();
+            Self {
+                ptr: ptr.as_ptr(),
+                removal: self.removal,
+                track: std::mem::take(&mut self.track),
+                _unsend: PhantomData,
+                _unsync: PhantomData,
+            }
+            .safe_cleanup();
         }
     }
 }
@@ -0,0 +1,368 @@
+use urcu_cds_sys::lfht;
+
+use crate::rcu::builder::RegisterError;
+use crate::rcu::context::RcuContextDyn;
+use crate::rcu::default::RcuDefaultFlavor;
+use crate::rcu::default::RcuDefaultGuard;
+use crate::rcu::flavor::RcuFlavor;
+use crate::rcu::guard::RcuGuardRaw;
+use crate::rcu::init::FlavorSet;
+use crate::RcuReadContext;
+
+#[test]
+fn flavor_api_ptr() {
+    let _context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let api = RcuDefaultFlavor::flavor_api_ptr();
+    assert!(!api.is_null());
+    assert!(std::ptr::eq(api, unsafe {
+        RcuDefaultFlavor::unchecked_rcu_api()
+    }));
+
+    // SAFETY: The thread is a registered RCU read-side thread.
+    let handle = unsafe { lfht::new_flavor(1, 1, 0, 0, api, std::ptr::null_mut()) };
+    assert!(!handle.is_null());
+
+    // SAFETY: The thread is not in a RCU critical section.
+    assert_eq!(unsafe { lfht::destroy(handle, std::ptr::null_mut()) }, 0);
+}
+
+#[test]
+fn init() {
+    crate::init(FlavorSet::ALL);
+    crate::init(FlavorSet::DEFAULT | FlavorSet::NONE);
+
+    assert!(FlavorSet::ALL.contains(FlavorSet::DEFAULT));
+    assert!(!FlavorSet::ALL.is_empty());
+    assert!(FlavorSet::NONE.is_empty());
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    RcuDefaultFlavor::rcu_cleanup_and_block(Box::new(move |_| tx.send(()).unwrap()));
+    rx.try_recv().unwrap();
+}
+
+#[test]
+fn defer_queue() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::rcu::deferred::RcuDeferQueue;
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let executed = Arc::new(AtomicUsize::new(0));
+    let mut queue = RcuDeferQueue::<RcuDefaultFlavor>::new();
+
+    for _ in 0..3 {
+        let executed = executed.clone();
+        queue.defer(move || {
+            executed.fetch_add(1, Ordering::Relaxed);
+        });
+    }
+
+    assert_eq!(queue.len(), 3);
+    queue.flush(&mut context);
+    assert!(queue.is_empty());
+    assert_eq!(executed.load(Ordering::Relaxed), 3);
+
+    let handle = std::thread::spawn({
+        let executed = executed.clone();
+
+        move || {
+            let mut queue = RcuDeferQueue::<RcuDefaultFlavor>::new();
+            queue.defer(move || {
+                executed.fetch_add(1, Ordering::Relaxed);
+            });
+            queue.handoff();
+        }
+    });
+
+    handle.join().unwrap();
+    RcuDefaultFlavor::rcu_cleanup_and_block(Box::new(|_| {}));
+    assert_eq!(executed.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn register_twice() {
+    let _context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    assert_eq!(
+        RcuDefaultFlavor::rcu_context_builder()
+            .with_read_context()
+            .register_thread()
+            .err(),
+        Some(RegisterError::AlreadyRegistered)
+    );
+}
+
+#[test]
+fn register_again() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    drop(context);
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .with_defer_context()
+        .register_thread();
+
+    assert!(context.is_ok());
+}
+
+#[test]
+fn guard_into_raw() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let guard = context.rcu_read_lock();
+    let raw = guard.into_raw();
+
+    // SAFETY: The token comes from the current thread and the context is still alive.
+    let guard = unsafe { RcuDefaultGuard::from_raw(raw) };
+    drop(guard);
+}
+
+#[test]
+fn capabilities() {
+    use crate::rcu::capability::{RcuDeferCapability, RcuReadCapability};
+    use crate::rcu::context::RcuDeferContext;
+    use crate::rcu::default::RcuDefaultContext;
+    use crate::rcu::reference::RcuRef;
+    use crate::RcuStack;
+
+    fn peek(
+        stack: &RcuStack<u32>,
+        read: RcuReadCapability<'_, RcuDefaultContext<true, true>>,
+    ) -> u32 {
+        *stack.peek(&read.rcu_read_lock()).unwrap()
+    }
+
+    fn cleanup<R, C>(reference: R, mut defer: RcuDeferCapability<'_, C>)
+    where
+        R: RcuRef<RcuDefaultFlavor> + Send,
+        C: RcuDeferContext<Flavor = RcuDefaultFlavor>,
+    {
+        reference.defer_cleanup(&mut defer);
+    }
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .with_defer_context()
+        .register_thread()
+        .unwrap();
+
+    let stack = RcuStack::<u32>::new();
+    stack.push(4291);
+    stack.push(8302);
+
+    assert_eq!(peek(&stack, context.rcu_read_capability()), 8302);
+
+    let read = context.rcu_read_capability();
+    let reference = stack.pop(&read.rcu_read_lock());
+    cleanup(reference, context.rcu_defer_capability());
+
+    assert_eq!(peek(&stack, context.rcu_read_capability()), 4291);
+}
+
+#[cfg(feature = "reclamation-metrics")]
+#[test]
+fn reclamation_histogram() {
+    use crate::rcu::metrics::RcuReclamationHistogram;
+    use crate::rcu::reference::RcuRef;
+    use crate::RcuStack;
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let before = RcuReclamationHistogram::snapshot();
+
+    let stack = RcuStack::<u32>::new();
+    stack.push(7524);
+
+    let guard = context.rcu_read_lock();
+    let reference = stack.pop(&guard).unwrap();
+    drop(guard);
+
+    let _ = reference.take_ownership(&mut context);
+
+    let after = RcuReclamationHistogram::snapshot();
+    assert!(after.count() > before.count());
+    assert!(after.quantile(1.0).is_some());
+}
+
+#[test]
+fn context_dyn() {
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let mut context: Box<dyn RcuContextDyn> = Box::new(context);
+
+    let poller = context.rcu_synchronize_poller();
+    while !poller.grace_period_finished() {
+        std::thread::yield_now();
+    }
+    drop(poller);
+
+    context.rcu_synchronize();
+}
+
+#[test]
+fn poll_handle() {
+    use crate::rcu::context::RcuContext;
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let handle = context.rcu_poll_handle();
+
+    std::thread::spawn(move || {
+        let context = RcuDefaultFlavor::rcu_context_builder()
+            .with_read_context()
+            .register_thread()
+            .unwrap();
+
+        while !handle.grace_period_finished(&context) {
+            std::thread::yield_now();
+        }
+    })
+    .join()
+    .unwrap();
+}
+
+#[test]
+fn field() {
+    use crate::rcu::field::RcuField;
+    use crate::RcuBox;
+
+    struct Node {
+        hits: RcuField<u32>,
+        enabled: RcuField<bool>,
+    }
+
+    let context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let value = RcuBox::<Node>::new(Node {
+        hits: RcuField::new(0),
+        enabled: RcuField::default(),
+    });
+
+    let guard = context.rcu_read_lock();
+    let node = value.get(&guard);
+
+    assert_eq!(node.hits.update(|hits| hits + 2), 0);
+    assert_eq!(node.hits.swap(5), 2);
+    assert_eq!(node.hits.compare_exchange(4, 6), Err(5));
+    assert_eq!(node.hits.compare_exchange(5, 6), Ok(5));
+    assert_eq!(node.hits.load(), 6);
+
+    node.enabled.store(true);
+    assert!(node.enabled.load());
+    drop(guard);
+}
+
+#[cfg(feature = "flavor-rs")]
+#[test]
+fn flavor_rs() {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use crate::rcu::flavor::RcuFlavorRs;
+    use crate::rcu::reference::RcuRef;
+    use crate::RcuHashMap;
+
+    let mut context = RcuFlavorRs::rcu_context_builder()
+        .with_read_context()
+        .with_defer_context()
+        .register_thread()
+        .unwrap();
+
+    let map = RcuHashMap::<u32, u32, RcuFlavorRs>::new().unwrap();
+    let running = Arc::new(AtomicBool::new(true));
+
+    let reader = std::thread::spawn({
+        let map = map.clone();
+        let running = running.clone();
+
+        move || {
+            let mut context = RcuFlavorRs::rcu_context_builder()
+                .with_read_context()
+                .register_thread()
+                .unwrap();
+
+            while running.load(Ordering::Relaxed) {
+                let guard = context.rcu_read_lock();
+                let _ = map.get(&10, &guard).copied();
+                drop(guard);
+
+                context.rcu_quiescent_state();
+            }
+        }
+    });
+
+    let guard = context.rcu_read_lock();
+    assert!(map.insert(10, 20, &guard).is_none());
+    let old = map.insert(10, 30, &guard).unwrap();
+    drop(guard);
+
+    assert_eq!(*old.take_ownership(&mut context).value(), 20);
+
+    running.store(false, Ordering::Relaxed);
+    reader.join().unwrap();
+
+    let guard = context.rcu_read_lock();
+    assert_eq!(map.get(&10, &guard), Some(&30));
+    drop(guard);
+}
+
+#[cfg(feature = "debug-checks")]
+#[test]
+fn ref_tracker() {
+    use std::ptr::NonNull;
+
+    use crate::rcu::reference::{RcuRef, RcuRefBox};
+    use crate::rcu::tracker::RcuRefTracker;
+
+    let mut context = RcuDefaultFlavor::rcu_context_builder()
+        .with_read_context()
+        .register_thread()
+        .unwrap();
+
+    let tracker = RcuRefTracker::new::<RcuDefaultFlavor>("test");
+    let produce = || {
+        let ptr = NonNull::from(Box::leak(Box::new(Box::new(4096u32))));
+        RcuRefBox::<Box<u32>, RcuDefaultFlavor>::new(ptr).tracked(&tracker)
+    };
+
+    let cleaned = produce();
+    let leaked = produce();
+    assert_eq!(tracker.outstanding(), 2);
+
+    drop(cleaned.take_ownership(&mut context));
+    assert_eq!(tracker.outstanding(), 1);
+
+    std::mem::forget(leaked);
+    assert_eq!(tracker.outstanding(), 1);
+}
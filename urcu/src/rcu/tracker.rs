@@ -0,0 +1,121 @@
+//! This module implements the detection of leaked references.
+//!
+//! When the `debug-checks` feature is enabled, containers count the references they
+//! produced that were not cleaned up yet. When a container is dropped, the remaining
+//! references are reported after a grace period, so that the cleanups already requested
+//! through [`RcuRef::safe_cleanup`] are not reported.
+//! Otherwise, the tracking compiles down to nothing.
+//!
+//! #### Note
+//!
+//! References still held by the application after the container is dropped are also
+//! reported, as well as references queued with [`RcuRef::defer_cleanup`] on a thread
+//! that did not flush its queue yet.
+//!
+//! [`RcuRef::safe_cleanup`]: crate::rcu::reference::RcuRef::safe_cleanup
+//! [`RcuRef::defer_cleanup`]: crate::rcu::reference::RcuRef::defer_cleanup
+
+#[cfg(feature = "debug-checks")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "debug-checks")]
+use std::sync::Arc;
+
+#[cfg(feature = "debug-checks")]
+use crate::rcu::callback::RcuCallFn;
+#[cfg(feature = "debug-checks")]
+use crate::rcu::context::RcuReadContext;
+use crate::rcu::flavor::RcuFlavor;
+
+/// Defines the counter of the references produced by a container.
+#[derive(Clone)]
+pub(crate) struct RcuRefTracker {
+    #[cfg(feature = "debug-checks")]
+    name: &'static str,
+    #[cfg(feature = "debug-checks")]
+    outstanding: Arc<AtomicUsize>,
+    #[cfg(feature = "debug-checks")]
+    report: fn(&'static str, Arc<AtomicUsize>),
+}
+
+impl RcuRefTracker {
+    #[cfg_attr(
+        not(feature = "debug-checks"),
+        allow(clippy::extra_unused_type_parameters)
+    )]
+    pub(crate) fn new<F>(name: &'static str) -> Self
+    where
+        F: RcuFlavor,
+    {
+        let _ = name;
+
+        Self {
+            #[cfg(feature = "debug-checks")]
+            name,
+            #[cfg(feature = "debug-checks")]
+            outstanding: Arc::new(AtomicUsize::new(0)),
+            #[cfg(feature = "debug-checks")]
+            report: report::<F>,
+        }
+    }
+
+    /// Returns a handle accounting a new reference.
+    pub(crate) fn track(&self) -> RcuRefTrack {
+        #[cfg(feature = "debug-checks")]
+        self.outstanding.fetch_add(1, Ordering::Relaxed);
+
+        RcuRefTrack {
+            #[cfg(feature = "debug-checks")]
+            outstanding: Some(self.outstanding.clone()),
+        }
+    }
+
+    /// Returns the amount of references that were not cleaned up yet.
+    #[cfg(feature = "debug-checks")]
+    pub(crate) fn outstanding(&self) -> usize {
+        self.outstanding.load(Ordering::Acquire)
+    }
+
+    /// Reports the references that are not cleaned up after a grace period.
+    ///
+    /// It must be called when the container is dropped.
+    pub(crate) fn report(&self) {
+        #[cfg(feature = "debug-checks")]
+        if self.outstanding() > 0 {
+            (self.report)(self.name, self.outstanding.clone());
+        }
+    }
+}
+
+#[cfg(feature = "debug-checks")]
+fn report<F>(name: &'static str, outstanding: Arc<AtomicUsize>)
+where
+    F: RcuFlavor,
+{
+    // The cleanup thread executes the callbacks in order, so the safe cleanups requested
+    // before the drop are done once this callback is executed.
+    F::rcu_cleanup(Box::new(move |context| {
+        context.rcu_call(RcuCallFn::new(move || {
+            let count = outstanding.load(Ordering::Acquire);
+            if count > 0 {
+                log::error!("{name} was dropped with {count} references never cleaned up");
+            }
+        }));
+    }));
+}
+
+/// Defines the accounting of a reference produced by a container.
+#[derive(Default)]
+pub(crate) struct RcuRefTrack {
+    #[cfg(feature = "debug-checks")]
+    outstanding: Option<Arc<AtomicUsize>>,
+}
+
+impl RcuRefTrack {
+    /// Records that the reference is cleaned up.
+    pub(crate) fn release(&mut self) {
+        #[cfg(feature = "debug-checks")]
+        if let Some(outstanding) = self.outstanding.take() {
+            outstanding.fetch_sub(1, Ordering::Release);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::marker::PhantomData;
 
 #[allow(dead_code)]
@@ -12,6 +13,18 @@ pub type PhantomUnsync<T = ()> = PhantomData<UnSync<T>>;
 
 pub type PhantomUnsend<T = ()> = PhantomData<UnSend<T>>;
 
+/// Implements [`fmt::Debug`] with a closure.
+pub struct DebugWith<C>(pub C);
+
+impl<C> fmt::Debug for DebugWith<C>
+where
+    C: Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
 #[allow(dead_code)]
 pub mod asserts {
     use super::*;